@@ -9,12 +9,76 @@ export const issueContract = async (
 ): Promise<IssueResponse> =>
   JSON.parse(await BMC.issue_contract(nostrHexSk, request));
 
+export const previewIssueContract = async (
+  request: IssueRequest
+): Promise<IssueResponse> =>
+  JSON.parse(await BMC.preview_issue_contract(request));
+
+export const issueCustomContract = async (
+  nostrHexSk: string,
+  request: IssueCustomRequest
+): Promise<IssueCustomResponse> =>
+  JSON.parse(await BMC.issue_custom_contract(nostrHexSk, request));
+
+export const inflateContract = async (
+  nostrHexSk: string,
+  request: InflateRequest
+): Promise<InflateResponse> =>
+  JSON.parse(await BMC.inflate_contract(nostrHexSk, request));
+
+// Only contracts issued with an inflation allowance (IFA) support burn,
+// and the burned supply is checked by bitmask only (not by the schema)
+export const burnAsset = async (
+  nostrHexSk: string,
+  request: BurnRequest
+): Promise<BurnResponse> =>
+  JSON.parse(await BMC.burn_asset(nostrHexSk, request));
+
+export const consolidateAllocations = async (
+  nostrHexSk: string,
+  request: ConsolidateRequest
+): Promise<ConsolidateResponse> =>
+  JSON.parse(await BMC.consolidate_allocations(nostrHexSk, request));
+
+export const splitAllocation = async (
+  nostrHexSk: string,
+  request: SplitRequest
+): Promise<SplitResponse> =>
+  JSON.parse(await BMC.split_allocation(nostrHexSk, request));
+
+export const bumpTransferCpfp = async (
+  nostrHexSk: string,
+  request: CpfpRequest
+): Promise<CpfpResponse> =>
+  JSON.parse(await BMC.bump_transfer_cpfp(nostrHexSk, request));
+
 export const createInvoice = async (
   nostrHexSk: string,
   request: InvoiceRequest
 ): Promise<InvoiceResponse> =>
   JSON.parse(await BMC.rgb_create_invoice(nostrHexSk, request));
 
+export const createWitnessInvoice = async (
+  nostrHexSk: string,
+  request: WitnessInvoiceRequest
+): Promise<InvoiceResponse> =>
+  JSON.parse(await BMC.rgb_create_witness_invoice(nostrHexSk, request));
+
+export const createUnifiedUri = async (
+  request: UnifiedUriRequest
+): Promise<UnifiedUriResponse> =>
+  JSON.parse(await BMC.create_unified_uri(request));
+
+export const decodeUnifiedUri = async (
+  uri: string
+): Promise<UnifiedUriResponse> =>
+  JSON.parse(await BMC.decode_unified_uri(uri));
+
+export const listInvoices = async (
+  nostrHexSk: string
+): Promise<RgbInvoicesResponse> =>
+  JSON.parse(await BMC.list_invoices(nostrHexSk));
+
 export const createPsbt = async (
   nostrHexSk: string,
   request: PsbtRequest
@@ -45,18 +109,47 @@ export const fullTransferAsset = async (
 ): Promise<RgbTransferResponse> =>
   JSON.parse(await BMC.full_transfer_asset(nostrHexSk, request));
 
+export const exportTransferPsbt = async (
+  nostrHexSk: string,
+  request: FullRgbTransferRequest
+): Promise<RgbTransferBundle> =>
+  JSON.parse(await BMC.export_transfer_psbt(nostrHexSk, request));
+
+export const importTransferPsbt = async (
+  nostrHexSk: string,
+  request: RgbSignedTransferRequest
+): Promise<RgbTransferResponse> =>
+  JSON.parse(await BMC.import_transfer_psbt(nostrHexSk, request));
+
 export const importContract = async (
   nostrHexSk: string,
   request: ImportRequest
 ): Promise<ImportResponse> =>
   JSON.parse(await BMC.import_contract(nostrHexSk, request));
 
+export const importSchema = async (
+  nostrHexSk: string,
+  request: ImportSchemaRequest
+): Promise<ImportSchemaResponse> =>
+  JSON.parse(await BMC.import_schema(nostrHexSk, request));
+
 export const acceptTransfer = async (
   nostrHexSk: string,
   request: AcceptRequest
 ): Promise<AcceptResponse> =>
   JSON.parse(await BMC.accept_transfer(nostrHexSk, request));
 
+export const acceptTransfers = async (
+  nostrHexSk: string,
+  request: AcceptRequest[]
+): Promise<BatchAcceptResponse> =>
+  JSON.parse(await BMC.accept_transfers(nostrHexSk, request));
+
+export const validateConsignment = async (
+  request: ValidateConsignmentRequest
+): Promise<ConsignmentValidationReport> =>
+  JSON.parse(await BMC.validate_consignment(request));
+
 export const listContracts = async (
   nostrHexSk: string
 ): Promise<ContractsResponse> =>
@@ -130,6 +223,12 @@ export const listTransfers = async (
 ): Promise<RgbTransfersResponse> =>
   JSON.parse(await BMC.list_transfers(nostrHexSk, contractId));
 
+export const transferHistory = async (
+  nostrHexSk: string,
+  request: RgbTransferHistoryRequest
+): Promise<RgbTransferHistoryResponse> =>
+  JSON.parse(await BMC.transfer_history(nostrHexSk, request));
+
 export const saveTransfer = async (
   nostrHexSk: string,
   request: RgbSaveTransferRequest
@@ -157,6 +256,12 @@ export const createOffer = async (
 ): Promise<RgbOfferResponse> =>
   JSON.parse(await BMC.create_offer(nostrHexSk, request));
 
+export const cancelOffer = async (
+  nostrHexSk: string,
+  request: RgbOfferCancelRequest
+): Promise<RgbOfferCancelResponse> =>
+  JSON.parse(await BMC.cancel_offer(nostrHexSk, request));
+
 export const createBid = async (
   nostrHexSk: string,
   request: RgbBidRequest
@@ -245,12 +350,215 @@ export interface IssueRequest {
   supply: bigint;
   /// Precision of the asset
   precision: number;
-  /// Seal of the initial owner
+  /// Seal of the initial owner (receives the supply not listed in allocations)
   seal: string;
+  /// Additional genesis allocations (only RGB20 and RGB25)
+  allocations?: IssueAllocationRequest[];
   /// The name of the iface (ex: RGB20)
   iface: string;
-  /// contract metadata (only RGB21/UDA)
+  /// contract metadata (RGB21/UDA, or the contract media of RGB20 and RGB25/CFA)
   meta?: IssueMediaRequest;
+  /// The ricardian contract terms (by default, the description)
+  terms?: string;
+  /// Document attached to the contract, ex: prospectus (only RGB20 and RGB25/CFA)
+  attachment?: MediaItemRequest;
+  /// inflation right (only RGB20)
+  inflation?: IssueInflationRequest;
+  /// tokens of the collection, each one allocated to its own seal (only RGB21)
+  collection?: IssueTokenRequest[];
+}
+
+export interface IssueTokenRequest {
+  /// Seal of the token owner
+  seal: string;
+  /// Preview of the token (base64)
+  preview?: MediaInfo;
+  /// Media of the token (digest)
+  media?: MediaInfo;
+  /// Attachments of the token (digest)
+  attachments?: MediaInfo[];
+}
+
+export interface IssueAllocationRequest {
+  /// Seal of the allocation owner
+  seal: string;
+  /// Amount of the asset assigned to the seal
+  amount: bigint;
+}
+
+export interface IssueInflationRequest {
+  /// Seal of the inflation right owner
+  seal: string;
+  /// Maximum amount that can be issued later
+  amount: bigint;
+}
+
+export interface InflateRequest {
+  /// The contract id
+  contractId: string;
+  /// Amount of the asset to be issued
+  amount: bigint;
+  /// Seal of the new supply owner
+  seal: string;
+  /// Asset Descriptor
+  descriptor: string;
+  /// Asset Terminal Change
+  changeTerminal: string;
+  /// Bitcoin Fee
+  fee: PsbtFeeRequest;
+  /// Seal Close Method (default: tapret1st)
+  closeMethod?: SealCloseMethod;
+}
+
+export interface InflateResponse {
+  /// The contract id
+  contractId: string;
+  /// Consignment ID
+  consigId: string;
+  /// Consignment encoded (in hexadecimal)
+  consig: string;
+  /// PSBT File Information with tapret (in hexadecimal)
+  psbt: string;
+  /// Tapret Commitment (used to spend output)
+  commit: string;
+  /// Inflation Bitcoin L1 transaction id
+  txid: string;
+  /// Issued supply (after inflation)
+  supply: bigint;
+  /// Issued supply plus the remaining inflation allowance (not a consensus limit)
+  maxSupply: bigint;
+  /// Effective fee rate (in sat/vB)
+  feeRate: number;
+}
+
+export interface BurnRequest {
+  /// The contract id (IFA contracts only)
+  contractId: string;
+  /// Amount of the asset to be burned
+  amount: bigint;
+  /// Asset Descriptor
+  descriptor: string;
+  /// Asset Terminal Change
+  changeTerminal: string;
+  /// Bitcoin Fee
+  fee: PsbtFeeRequest;
+  /// Seal Close Method (default: tapret1st)
+  closeMethod?: SealCloseMethod;
+}
+
+export interface BurnResponse {
+  /// The contract id
+  contractId: string;
+  /// Consignment ID
+  consigId: string;
+  /// Consignment encoded (in hexadecimal)
+  consig: string;
+  /// PSBT File Information with tapret (in hexadecimal)
+  psbt: string;
+  /// Tapret Commitment (used to spend output)
+  commit: string;
+  /// Burn Bitcoin L1 transaction id (on-chain proof of the burn)
+  txid: string;
+  /// Supply destroyed by burn operations (after burn, checked by bitmask only)
+  burnedSupply: bigint;
+  /// Circulating supply of the asset (after burn)
+  circulatingSupply: bigint;
+  /// Effective fee rate (in sat/vB)
+  feeRate: number;
+}
+
+export interface ConsolidateRequest {
+  /// The contract ids (all their allocations are merged into a single UTXO)
+  contractIds: string[];
+  /// Asset Descriptor
+  descriptor: string;
+  /// Bitcoin Terminal Change
+  changeTerminal: string;
+  /// Bitcoin Fee
+  fee: PsbtFeeRequest;
+  /// Seal Close Method (default: tapret1st)
+  closeMethod?: SealCloseMethod;
+}
+
+export interface ConsolidateResponse {
+  /// Watcher addresses of the new UTXOs, by contract
+  addresses: { [key: string]: string };
+  /// Number of UTXOs merged
+  utxos: number;
+  /// Amount consolidated, by contract
+  amounts: { [key: string]: bigint };
+  /// Consignment ID
+  consigId: string;
+  /// Consignment encoded (in hexadecimal)
+  consig: string;
+  /// Strict Consignments of the other contracts, by contract and beneficiary (in hexadecimal)
+  consigs: { [key: string]: string };
+  /// PSBT File Information with tapret (in hexadecimal)
+  psbt: string;
+  /// Tapret Commitment (used to spend output)
+  commit: string;
+  /// Consolidation Bitcoin L1 transaction id
+  txid: string;
+  /// Effective fee rate (in sat/vB)
+  feeRate: number;
+}
+
+export interface SplitRequest {
+  /// The contract id
+  contractId: string;
+  /// The allocation UTXO to be split
+  utxo: string;
+  /// Amounts of the new allocations (one output per amount)
+  amounts: bigint[];
+  /// Asset Descriptor
+  descriptor: string;
+  /// Asset Terminal Change
+  changeTerminal: string;
+  /// Bitcoin Fee
+  fee: PsbtFeeRequest;
+  /// Seal Close Method (default: tapret1st)
+  closeMethod?: SealCloseMethod;
+}
+
+export interface SplitResponse {
+  /// New allocations (format: {address}:{amount})
+  allocations: string[];
+  /// Consignment ID
+  consigId: string;
+  /// Consignment encoded (in hexadecimal)
+  consig: string;
+  /// Strict Consignments of the other allocations, by contract and beneficiary (in hexadecimal)
+  consigs: { [key: string]: string };
+  /// PSBT File Information with tapret (in hexadecimal)
+  psbt: string;
+  /// Tapret Commitment (used to spend output)
+  commit: string;
+  /// Split Bitcoin L1 transaction id
+  txid: string;
+  /// Effective fee rate (in sat/vB)
+  feeRate: number;
+}
+
+export interface CpfpRequest {
+  /// The unconfirmed RGB transfer transaction id
+  txid: string;
+  /// Target fee rate of the parent and child package (in sat/vB)
+  feeRate: number;
+  /// Bitcoin Descriptor
+  descriptor: string;
+}
+
+export interface CpfpResponse {
+  /// The unconfirmed RGB transfer transaction id
+  parentTxid: string;
+  /// Outputs spent by the child transaction (format: {txid}:{vout})
+  inputs: string[];
+  /// Child transaction fee (in sats)
+  fee: bigint;
+  /// Fee rate of the parent and child package (in sat/vB)
+  packageFeeRate: number;
+  /// Child PSBT File Information (in hexadecimal)
+  psbt: string;
 }
 
 export interface NewCollectible {
@@ -273,6 +581,8 @@ export interface UDADetail {
   name: string;
   /// Description of the uda
   description: string;
+  /// Preview of the uda
+  preview?: MediaInfo;
   /// Media of the uda
   media: MediaInfo[];
   /// The user contract balance
@@ -300,6 +610,8 @@ export interface ContractMediaDetail {
   media?: MediaInfo;
   /// Attachments of the uda
   attachments: MediaInfo[];
+  /// The ricardian contract terms
+  terms: string;
 }
 
 /**
@@ -335,6 +647,73 @@ export interface IssueResponse {
   genesis: GenesisFormats;
   /// attachments and media (only RGB21/UDA)
   meta?: ContractMediaDetail;
+  /// tokens of the collection (only RGB21 with many tokens)
+  tokens: UDADetail[];
+}
+
+export interface IssueCustomRequest {
+  /// The schema id (the schema must be available in the stock)
+  schemaId: string;
+  /// The name of the iface implemented by the schema
+  iface: string;
+  /// Global state of the genesis
+  globals: IssueGlobalStateRequest[];
+  /// Owned state of the genesis
+  assignments: IssueAssignmentRequest[];
+}
+
+export interface IssueGlobalStateRequest {
+  /// The global state name (as defined by the iface)
+  name: string;
+  /// The global state value (typed JSON, checked against the schema type)
+  value: unknown;
+}
+
+export interface IssueAssignmentRequest {
+  /// The owned state name (as defined by the iface)
+  name: string;
+  /// Seal of the owner
+  seal: string;
+  /// Amount of the fungible state
+  amount?: bigint;
+  /// Value of the structured state (typed JSON, checked against the schema type)
+  data?: unknown;
+}
+
+export interface IssueCustomResponse {
+  /// The contract id
+  contractId: string;
+  /// The schema id
+  schemaId: string;
+  /// The contract impl id
+  iimplId: string;
+  /// The contract interface
+  iface: string;
+  /// The contract state (multiple formats)
+  contract: ContractFormats;
+  /// Genesis
+  genesis: GenesisFormats;
+}
+
+export interface ImportSchemaRequest {
+  /// The type data
+  /// enum SchemaImportType {
+  ///     "schema",
+  ///     "interface",
+  ///     "implementation"
+  /// }
+  import: string;
+  /// The payload data (armored or strict encoded in hexadecimal)
+  data: string;
+}
+
+export interface ImportSchemaResponse {
+  /// The type data (schema, interface or implementation)
+  import: string;
+  /// The schema, interface or implementation id
+  id: string;
+  /// The interface name (only interface)
+  name?: string;
 }
 
 export interface ImportRequest {
@@ -361,8 +740,14 @@ export interface ImportResponse {
   name: string;
   /// Description of the asset
   description: string;
-  /// Amount of the asset
+  /// Issued supply of the asset
   supply: bigint;
+  /// Issued supply plus the remaining inflation allowance (not a consensus limit)
+  maxSupply: bigint;
+  /// Supply destroyed by burn operations
+  burnedSupply: bigint;
+  /// Circulating supply of the asset
+  circulatingSupply: bigint;
   /// Precision of the asset
   precision: number;
   /// The user contract balance
@@ -377,6 +762,8 @@ export interface ImportResponse {
   genesis: GenesisFormats;
   /// attachments and media (only RGB21/UDA)
   meta?: ContractMediaDetail;
+  /// tokens of the collection (only RGB21 with many tokens)
+  tokens: UDADetail[];
 }
 
 export interface MediaInfo {
@@ -407,6 +794,27 @@ export interface InvoiceRequest {
   seal: string;
   /// Query parameters
   params: { [key: string]: string };
+  /// Expiration date (unix timestamp)
+  expiry?: number;
+  /// Transport endpoints (default: configured RGB transport)
+  transports?: string[];
+}
+
+export interface WitnessInvoiceRequest {
+  /// The contract id
+  contractId: string;
+  /// The contract interface
+  iface: string;
+  /// Amount of the asset
+  amount: string;
+  /// Beneficiary address (default: next address of the watcher)
+  address?: string;
+  /// Query parameters
+  params: { [key: string]: string };
+  /// Expiration date (unix timestamp)
+  expiry?: number;
+  /// Transport endpoints (default: configured RGB transport)
+  transports?: string[];
 }
 
 export interface InvoiceResponse {
@@ -429,7 +837,27 @@ export interface PsbtRequest {
   fee: PsbtFeeRequest;
   /// Allow RBF
   rbf: boolean;
-}
+  /// Seal Close Method (default: tapret1st)
+  close_method?: SealCloseMethod;
+  /// Coin Selection Strategy (bitcoin inputs are candidates when it is not the default)
+  coin_selection?: CoinSelectionStrategy;
+}
+
+export type SealCloseMethod = "tapret1st" | "opret1st";
+
+export type CoinSelectionStrategy =
+  /// UTXOs in wallet order
+  | "default"
+  /// Biggest UTXOs first (fewer inputs)
+  | "largestFirst"
+  /// Smallest UTXOs first (consolidates dust UTXOs)
+  | "smallestFirst"
+  /// UTXOs matching the target amount (avoids change), fallback to largest first
+  | "branchAndBound"
+  /// Single UTXO when possible and never mixes contracts in the same transaction
+  | "privacy"
+  /// Only the given outpoints (format: {txid}:{vout})
+  | { manual: string[] };
 
 interface PsbtInputRequest {
   /// Asset or Bitcoin Descriptor
@@ -464,6 +892,8 @@ interface PsbtSigHashRequest {
 interface PsbtFeeRequest {
   value?: number;
   feeRate?: number;
+  /// Fee rate estimated by the explorer to confirm within the target blocks
+  target?: { blocks: number };
 }
 
 export interface PsbtResponse {
@@ -471,6 +901,8 @@ export interface PsbtResponse {
   psbt: string;
   /// Asset UTXO Terminal (ex. /0/0)
   terminal: string;
+  /// Effective fee rate (in sat/vB)
+  feeRate: number;
 }
 
 export interface SignPsbtRequest {
@@ -506,6 +938,8 @@ export interface RgbTransferRequest {
   psbt: string;
   /// Asset UTXO Terminal (ex. /0/0)
   terminal: string;
+  /// Other RGB Invoices paid in the same transaction
+  otherInvoices?: string[];
 }
 
 export interface FullRgbTransferRequest {
@@ -513,7 +947,7 @@ export interface FullRgbTransferRequest {
   contractId: string;
   /// The contract interface
   iface: string;
-  /// RGB Invoice
+  /// RGB Invoice or BIP-21 URI with an RGB invoice
   rgbInvoice: string;
   /// Asset or Bitcoin Descriptor
   descriptor: string;
@@ -523,6 +957,12 @@ export interface FullRgbTransferRequest {
   fee: PsbtFeeRequest;
   /// Bitcoin Change Addresses (format: {address}:{amount})
   bitcoinChanges: string[];
+  /// Other RGB Invoices paid in the same transaction
+  otherInvoices?: string[];
+  /// Coin Selection Strategy
+  coinSelection?: CoinSelectionStrategy;
+  /// Seal Close Method (default: tapret1st)
+  closeMethod?: SealCloseMethod;
 }
 
 export interface RgbTransferResponse {
@@ -536,6 +976,44 @@ export interface RgbTransferResponse {
   commit: string;
   /// Transfer Bitcoin L1 transaction id
   txid: string;
+  /// Strict Consignments of the other invoices, by contract and beneficiary (in hexadecimal)
+  consigs: { [key: string]: string };
+  /// Effective fee rate (in sat/vB)
+  feeRate: number;
+}
+
+export interface RgbTransferBundle {
+  /// Consignment ID
+  consigId: string;
+  /// Consignment encoded (in hexadecimal)
+  consig: string;
+  /// Strict Consignments of the other invoices, by contract and beneficiary (in hexadecimal)
+  consigs: { [key: string]: string };
+  /// The name of the iface (ex: RGB20)
+  iface: string;
+  /// Beneficiary of the RGB invoice
+  beneficiary: string;
+  /// Unsigned PSBT File Information with tapret (in hexadecimal)
+  psbt: string;
+  /// Outpoint (used to spend output)
+  outpoint: string;
+  /// Outpoint Amount (used to spend output)
+  amount: bigint;
+  /// Tapret Commitment (used to spend output)
+  commit: string;
+  /// Asset UTXO Terminal (ex. /0/0)
+  terminal: string;
+  /// Transfer Bitcoin L1 transaction id
+  txid: string;
+  /// Effective fee rate (in sat/vB)
+  feeRate: number;
+}
+
+export interface RgbSignedTransferRequest {
+  /// Signed PSBT File Information (in hexadecimal)
+  psbt: string;
+  /// Transfer bundle exported with the unsigned PSBT
+  bundle: RgbTransferBundle;
 }
 
 export interface AcceptRequest {
@@ -552,6 +1030,80 @@ export interface AcceptResponse {
   contractId: string;
   /// Transfer accept status
   valid: boolean;
+  /// Consignment validation report
+  report: ConsignmentValidationReport;
+}
+
+export interface BatchAcceptResponse {
+  /// Accept result of each consignment (in the request order)
+  transfers: BatchAcceptItem[];
+}
+
+export interface BatchAcceptItem {
+  /// Position of the consignment in the request
+  index: number;
+  /// Accept response (when the consignment was accepted)
+  accept?: AcceptResponse;
+  /// Reason of the rejection (when the consignment was not accepted)
+  error?: string;
+}
+
+export interface ValidateConsignmentRequest {
+  /// Consignment encoded in hexadecimal
+  consignment: string;
+}
+
+export interface ConsignmentValidationReport {
+  /// Transfer ID
+  transferId: string;
+  /// Contract ID
+  contractId: string;
+  /// Consignment validity
+  validity: ConsignmentValidity;
+  /// Consignment validation status
+  valid: boolean;
+  /// Validation failures
+  failures: ConsignmentValidationItem[];
+  /// Validation warnings
+  warnings: ConsignmentValidationItem[];
+  /// Validation info
+  info: ConsignmentValidationItem[];
+  /// Transactions that the resolver could not retrieve
+  unresolvedTxids: string[];
+  /// Terminal transactions not mined yet
+  unminedTerminals: string[];
+  /// State transitions of the transfer (operation IDs)
+  operations: string[];
+  /// Terminal seals of the transfer
+  seals: string[];
+  /// Anchor transactions of the consignment
+  txids: string[];
+  /// Anchor transactions of the transfer (witness transactions)
+  anchors: ConsignmentAnchorStatus[];
+  /// All anchor transactions of the transfer are mined
+  anchorMined: boolean;
+}
+
+export type ConsignmentValidity =
+  | "valid"
+  | "unresolvedTransactions"
+  | "validExceptEndpoints"
+  | "invalid";
+
+export interface ConsignmentValidationItem {
+  /// Kind of the failure, warning or info (ex: SealNoWitnessTx)
+  code: string;
+  /// Description
+  message: string;
+  /// Operation IDs, seals and txids of the consignment involved
+  involved: string[];
+}
+
+export interface ConsignmentAnchorStatus {
+  /// Anchor transaction id
+  txid: string;
+  /// Anchor transaction status
+  status: TxStatus;
 }
 
 export interface ContractsResponse {
@@ -639,6 +1191,8 @@ export interface WatcherDetail {
   contractId: string;
   /// Allocations
   allocations: AllocationDetail[];
+  /// Supply destroyed by burn operations
+  burnedSupply: bigint;
 }
 
 export interface UDAPosition {
@@ -731,6 +1285,71 @@ export interface RgbTransferDetail {
   type: TransferType;
 }
 
+export interface RgbTransferHistoryRequest {
+  /// The contract id
+  contractId: string;
+  /// Number of transfers to skip (newest first)
+  offset?: number;
+  /// Max. number of transfers returned
+  limit: number;
+}
+
+export interface RgbTransferHistoryResponse {
+  /// The contract id
+  contractId: string;
+  /// Total of transfers of the contract
+  total: number;
+  /// Number of transfers skipped
+  offset: number;
+  /// Max. number of transfers returned
+  limit: number;
+  /// Transfers of the page (newest first)
+  transfers: RgbTransferHistoryItem[];
+}
+
+export interface RgbTransferHistoryItem {
+  /// Consignment ID
+  consigId: string;
+  /// Bitcoin L1 transaction id
+  txid: string;
+  /// The name of the iface (ex: RGB20)
+  iface: string;
+  type: TransferType;
+  /// Amount moved (for UDA, the number of tokens)
+  amount: bigint;
+  /// Counterparty beneficiaries (sended transfers)
+  beneficiaries: RgbTransferBeneficiary[];
+  /// Bitcoin fee paid (sended transfers, in sats)
+  fee?: bigint;
+  /// Transaction status
+  status: TxStatus;
+  /// Block height
+  blockHeight?: number;
+  /// Block time (unix timestamp)
+  blockTime?: number;
+  /// Number of confirmations
+  confirmations: number;
+  /// Transfer can be replaced by fee (RBF)
+  rbf: boolean;
+  /// Consignments replaced by this transfer (oldest first)
+  replaces: string[];
+  /// Consignment that replaced this transfer
+  replacedBy?: string;
+  /// Invoice paid by this transfer
+  invoice?: string;
+  /// Swap offer filled by this transfer
+  offerId?: string;
+  /// Swap bid filled by this transfer
+  bidId?: string;
+}
+
+export interface RgbTransferBeneficiary {
+  /// Blinded UTXO or witness address
+  beneficiary: string;
+  /// Amount assigned to the beneficiary
+  amount?: bigint;
+}
+
 export interface TxStatus {
   not_found?: any;
   error?: string;
@@ -747,10 +1366,69 @@ export interface TransferType {
 export interface RgbInvoiceResponse {
   contractId: string;
   amount: bigint;
+  /// Expiration date (unix timestamp)
+  expiry?: number;
+  /// Transport endpoints
+  transports: string[];
+}
+
+export interface UnifiedUriRequest {
+  /// RGB Invoice
+  rgbInvoice: string;
+  /// On-chain fallback address
+  address?: string;
+  /// On-chain fallback amount (in sats)
+  amount?: bigint;
+  /// Lightning fallback invoice (BOLT11)
+  lightning?: string;
+}
+
+export interface UnifiedUriResponse {
+  /// BIP-21 URI (bitcoin:{address}?amount={btc}&rgb={invoice}&lightning={bolt11})
+  uri: string;
+  /// RGB Invoice
+  rgbInvoice?: string;
+  /// On-chain fallback address
+  address?: string;
+  /// On-chain fallback amount (in sats)
+  amount?: bigint;
+  /// Lightning fallback invoice (BOLT11)
+  lightning?: string;
+}
+
+export type InvoiceStatus = "open" | "partially_paid" | "paid" | "expired";
+
+export interface RgbInvoiceStatusDetail {
+  /// The RGB invoice
+  invoice: string;
+  /// The contract id
+  contractId: string;
+  /// The contract interface
+  iface: string;
+  /// Requested amount (in atomic units)
+  amount: bigint;
+  /// Received amount (in atomic units)
+  received: bigint;
+  /// Blinded seal or witness address of the invoice
+  beneficiary: string;
+  /// Creation date (unix timestamp)
+  created: number;
+  /// Expiration date (unix timestamp)
+  expiry?: number;
+  /// Transfers which paid the invoice
+  transfers: string[];
+  /// Payment status
+  status: InvoiceStatus;
+}
+
+export interface RgbInvoicesResponse {
+  invoices: RgbInvoiceStatusDetail[];
 }
 
 export interface BatchRgbTransferResponse {
   transfers: BatchRgbTransferItem[];
+  /// Accepted transfers whose anchor block was reorged out (pending again)
+  reorgs: BatchRgbTransferItem[];
 }
 
 export interface BatchRgbTransferItem {
@@ -779,6 +1457,10 @@ export interface RgbOfferRequest {
   bitcoinChanges: string[];
   presig: boolean;
   expire_at?: number;
+  /// Coin Selection Strategy
+  coinSelection?: CoinSelectionStrategy;
+  /// Seal Close Method (default: tapret1st)
+  closeMethod?: SealCloseMethod;
 }
 
 export interface RgbOfferResponse {
@@ -796,6 +1478,24 @@ export interface RgbOfferResponse {
   sellerPsbt: string;
 }
 
+export interface RgbOfferCancelRequest {
+  /// The Contract ID
+  contractId: string;
+  /// The Offer ID
+  offerId: string;
+}
+
+export interface RgbOfferCancelResponse {
+  /// The Contract ID
+  contractId: string;
+  /// The Offer ID
+  offerId: string;
+  /// Cancelled?
+  cancelled: boolean;
+  /// Outpoints released by the offer
+  released: string[];
+}
+
 export interface RgbBidRequest {
   /// The Offer ID
   offerId: string;
@@ -807,6 +1507,8 @@ export interface RgbBidRequest {
   changeTerminal: string;
   /// Bitcoin Fee
   fee: PsbtFeeRequest;
+  /// Seal Close Method of the offer (default: tapret1st)
+  closeMethod?: SealCloseMethod;
 }
 
 export interface RgbBidResponse {
@@ -820,6 +1522,8 @@ export interface RgbBidResponse {
   swapPsbt: string;
   /// Fee Value
   feeValue: bigint;
+  /// Effective fee rate (in sat/vB)
+  feeRate: number;
 }
 
 export interface RgbSwapRequest {
@@ -30,24 +30,32 @@ use bitmask_core::{
         proxy_media_data_store, proxy_media_retrieve, proxy_metadata_retrieve,
     },
     rgb::{
-        accept_transfer, clear_watcher as rgb_clear_watcher, create_invoice, create_psbt,
-        create_watcher, full_transfer_asset, get_contract, import as rgb_import, issue_contract,
-        list_contracts, list_interfaces, list_schemas, list_transfers as list_rgb_transfers,
-        reissue_contract, remove_transfer as remove_rgb_transfer,
-        save_transfer as save_rgb_transfer,
+        accept_transfer, accept_transfers, bump_transfer_cpfp, burn_asset,
+        clear_watcher as rgb_clear_watcher, consolidate_allocations, create_invoice, create_psbt,
+        create_unified_uri, create_watcher, create_witness_invoice, export_transfer_psbt,
+        full_transfer_asset, get_contract, import as rgb_import,
+        import_schema as rgb_import_schema, import_transfer_psbt, inflate_contract, issue_contract,
+        issue_custom_contract, list_contracts, list_interfaces, list_invoices, list_schemas,
+        list_transfers as list_rgb_transfers, preview_issue_contract, reissue_contract,
+        remove_transfer as remove_rgb_transfer, save_transfer as save_rgb_transfer,
+        split_allocation,
         structs::{
             RgbProxyConsigCarbonadoReq, RgbProxyConsigFileReq, RgbProxyConsigUpload,
             RgbProxyMediaCarbonadoReq, RgbProxyMediaFileReq,
         },
-        transfer_asset, watcher_address, watcher_details as rgb_watcher_details,
-        watcher_next_address, watcher_next_utxo, watcher_utxo,
+        transfer_asset, transfer_history, validate_consignment, watcher_address,
+        watcher_details as rgb_watcher_details, watcher_next_address, watcher_next_utxo,
+        watcher_utxo,
     },
     structs::{
-        AcceptRequest, FileMetadata, FullRgbTransferRequest, ImportRequest, InvoiceRequest,
-        IssueRequest, MediaEncode, MediaExtractRequest, MediaItemRequest, PsbtFeeRequest,
-        PsbtRequest, ReIssueRequest, RgbRemoveTransferRequest, RgbSaveTransferRequest,
+        AcceptRequest, BurnRequest, ConsolidateRequest, CpfpRequest, FileMetadata,
+        FullRgbTransferRequest, ImportRequest, ImportSchemaRequest, InflateRequest, InvoiceRequest,
+        IssueCustomRequest, IssueRequest, MediaEncode, MediaExtractRequest, MediaItemRequest,
+        PsbtFeeRequest, PsbtRequest, ReIssueRequest, RgbRemoveTransferRequest,
+        RgbSaveTransferRequest, RgbSignedTransferRequest, RgbTransferHistoryRequest,
         RgbTransferRequest, SecretString, SelfFullRgbTransferRequest, SelfInvoiceRequest,
-        SelfIssueRequest, SignPsbtRequest, WatcherRequest,
+        SelfIssueRequest, SignPsbtRequest, SplitRequest, UnifiedUriRequest,
+        ValidateConsignmentRequest, WatcherRequest, WitnessInvoiceRequest,
     },
 };
 use log::{debug, error, info};
@@ -65,6 +73,24 @@ async fn issue(
     Ok((StatusCode::OK, Json(issue_res)))
 }
 
+async fn preview_issue(Json(request): Json<IssueRequest>) -> Result<impl IntoResponse, AppError> {
+    info!("POST /issue/preview {request:?}");
+
+    let issue_res = preview_issue_contract(request).await?;
+    Ok((StatusCode::OK, Json(issue_res)))
+}
+
+async fn issue_custom(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(request): Json<IssueCustomRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /issue/custom {request:?}");
+
+    let nostr_hex_sk = auth.token();
+    let issue_res = issue_custom_contract(nostr_hex_sk, request).await?;
+    Ok((StatusCode::OK, Json(issue_res)))
+}
+
 async fn reissue(
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
     Json(request): Json<ReIssueRequest>,
@@ -76,6 +102,61 @@ async fn reissue(
     Ok((StatusCode::OK, Json(issue_res)))
 }
 
+async fn inflate(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(request): Json<InflateRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /inflate {request:?}");
+
+    let nostr_hex_sk = auth.token();
+    let inflate_res = inflate_contract(nostr_hex_sk, request).await?;
+    Ok((StatusCode::OK, Json(inflate_res)))
+}
+
+async fn burn(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(request): Json<BurnRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /burn {request:?}");
+
+    let nostr_hex_sk = auth.token();
+    let burn_res = burn_asset(nostr_hex_sk, request).await?;
+    Ok((StatusCode::OK, Json(burn_res)))
+}
+
+async fn consolidate(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(request): Json<ConsolidateRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /consolidate {request:?}");
+
+    let nostr_hex_sk = auth.token();
+    let consolidate_res = consolidate_allocations(nostr_hex_sk, request).await?;
+    Ok((StatusCode::OK, Json(consolidate_res)))
+}
+
+async fn split(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(request): Json<SplitRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /split {request:?}");
+
+    let nostr_hex_sk = auth.token();
+    let split_res = split_allocation(nostr_hex_sk, request).await?;
+    Ok((StatusCode::OK, Json(split_res)))
+}
+
+async fn cpfp(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(request): Json<CpfpRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /cpfp {request:?}");
+
+    let nostr_hex_sk = auth.token();
+    let cpfp_res = bump_transfer_cpfp(nostr_hex_sk, request).await?;
+    Ok((StatusCode::OK, Json(cpfp_res)))
+}
+
 async fn self_issue(Json(issue): Json<SelfIssueRequest>) -> Result<impl IntoResponse, AppError> {
     info!("POST /self_issue {issue:?}");
     let issuer_keys = save_mnemonic(
@@ -95,6 +176,11 @@ async fn self_issue(Json(issue): Json<SelfIssueRequest>) -> Result<impl IntoResp
         seal: issue_seal.to_owned(),
         iface: "RGB21".to_string(),
         meta: issue.meta,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let issue_res = issue_contract(sk, request).await?;
@@ -114,6 +200,39 @@ async fn invoice(
     Ok((StatusCode::OK, Json(invoice_res)))
 }
 
+async fn witness_invoice(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(invoice): Json<WitnessInvoiceRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /witness-invoice {invoice:?}");
+
+    let nostr_hex_sk = auth.token();
+    let invoice_res = create_witness_invoice(nostr_hex_sk, invoice).await?;
+
+    Ok((StatusCode::OK, Json(invoice_res)))
+}
+
+async fn unified_uri(
+    Json(request): Json<UnifiedUriRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /uri {request:?}");
+
+    let uri_res = create_unified_uri(request).await?;
+
+    Ok((StatusCode::OK, Json(uri_res)))
+}
+
+async fn invoices(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("GET /invoices");
+
+    let nostr_hex_sk = auth.token();
+    let invoices_res = list_invoices(nostr_hex_sk).await?;
+
+    Ok((StatusCode::OK, Json(invoices_res)))
+}
+
 async fn self_invoice(
     Json(self_invoice): Json<SelfInvoiceRequest>,
 ) -> Result<impl IntoResponse, AppError> {
@@ -134,6 +253,8 @@ async fn self_invoice(
         amount: "1".to_string(),
         seal: invoice_seal.to_owned(),
         params: self_invoice.params,
+        expiry: None,
+        transports: None,
     };
     let invoice_res = create_invoice(nostr_hex_sk, invoice).await?;
 
@@ -163,6 +284,30 @@ async fn _sign_psbt(
     Ok((StatusCode::OK, Json(psbt_res)))
 }
 
+async fn pay_export(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(pay_req): Json<FullRgbTransferRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /pay/export {pay_req:?}");
+
+    let nostr_hex_sk = auth.token();
+    let bundle_res = export_transfer_psbt(nostr_hex_sk, pay_req).await?;
+
+    Ok((StatusCode::OK, Json(bundle_res)))
+}
+
+async fn pay_import(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(request): Json<RgbSignedTransferRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /pay/import {request:?}");
+
+    let nostr_hex_sk = auth.token();
+    let transfer_res = import_transfer_psbt(nostr_hex_sk, request).await?;
+
+    Ok((StatusCode::OK, Json(transfer_res)))
+}
+
 #[axum_macros::debug_handler]
 async fn pay(
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
@@ -203,6 +348,9 @@ async fn self_pay(
         fee,
         change_terminal: self_pay_req.terminal,
         bitcoin_changes: self_pay_req.bitcoin_changes,
+        other_invoices: vec![],
+        coin_selection: default!(),
+        close_method: default!(),
     };
 
     let transfer_res = full_transfer_asset(nostr_hex_sk, request).await?;
@@ -222,6 +370,27 @@ async fn accept(
     Ok((StatusCode::OK, Json(transfer_res)))
 }
 
+async fn accept_batch(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(accept_reqs): Json<Vec<AcceptRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /accept/batch {} consignments", accept_reqs.len());
+
+    let nostr_hex_sk = auth.token();
+    let transfers_res = accept_transfers(nostr_hex_sk, accept_reqs).await?;
+
+    Ok((StatusCode::OK, Json(transfers_res)))
+}
+
+async fn validate(
+    Json(request): Json<ValidateConsignmentRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /validate {request:?}");
+
+    let validate_res = validate_consignment(request).await?;
+    Ok((StatusCode::OK, Json(validate_res)))
+}
+
 async fn self_accept(Json(accept_req): Json<AcceptRequest>) -> Result<impl IntoResponse, AppError> {
     info!("POST /self_accept {accept_req:?}");
 
@@ -300,6 +469,18 @@ async fn import(
     Ok((StatusCode::OK, Json(import_res)))
 }
 
+async fn import_schema(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(import_req): Json<ImportSchemaRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /schemas/import {import_req:?}");
+
+    let nostr_hex_sk = auth.token();
+    let import_res = rgb_import_schema(nostr_hex_sk, import_req).await?;
+
+    Ok((StatusCode::OK, Json(import_res)))
+}
+
 async fn watcher(
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
     Json(request): Json<WatcherRequest>,
@@ -397,6 +578,18 @@ async fn list_transfers(
     Ok((StatusCode::OK, Json(transfers_res)))
 }
 
+async fn history_transfers(
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(request): Json<RgbTransferHistoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    info!("POST /transfers/history {request:?}");
+
+    let nostr_hex_sk = auth.token();
+    let history_res = transfer_history(nostr_hex_sk, request).await?;
+
+    Ok((StatusCode::OK, Json(history_res)))
+}
+
 async fn save_transfer(
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
     Json(request): Json<RgbSaveTransferRequest>,
@@ -763,21 +956,36 @@ async fn main() -> Result<()> {
 
     let mut app = Router::new()
         .route("/issue", post(issue))
+        .route("/issue/preview", post(preview_issue))
+        .route("/issue/custom", post(issue_custom))
         .route("/reissue", post(reissue))
+        .route("/inflate", post(inflate))
+        .route("/burn", post(burn))
+        .route("/consolidate", post(consolidate))
+        .route("/split", post(split))
+        .route("/cpfp", post(cpfp))
         .route("/selfissue", post(self_issue))
         .route("/invoice", post(invoice))
+        .route("/witness-invoice", post(witness_invoice))
+        .route("/invoices", get(invoices))
+        .route("/uri", post(unified_uri))
         .route("/selfinvoice", post(self_invoice))
         // .route("/psbt", post(psbt))
         // .route("/sign", post(sign_psbt))
         .route("/pay", post(pay))
+        .route("/pay/export", post(pay_export))
+        .route("/pay/import", post(pay_import))
         .route("/selfpay", post(self_pay))
         .route("/accept", post(accept))
+        .route("/accept/batch", post(accept_batch))
+        .route("/validate", post(validate))
         .route("/selfaccept", post(self_accept))
         .route("/contracts", get(contracts))
         .route("/contracts/:id", get(contract_detail))
         .route("/interfaces", get(interfaces))
         .route("/schemas", get(schemas))
         .route("/import", post(import))
+        .route("/schemas/import", post(import_schema))
         .route("/watcher", post(watcher))
         .route("/watcher/:name", get(watcher_details))
         .route("/watcher/:name/:asset/address", get(next_address))
@@ -789,6 +997,7 @@ async fn main() -> Result<()> {
         .route("/watcher/:name/:asset/utxo/:utxo", put(register_utxo))
         .route("/watcher/:name", delete(clear_watcher))
         .route("/transfers/:id", get(list_transfers))
+        .route("/transfers/history", post(history_transfers))
         .route("/transfers/", post(save_transfer))
         .route("/transfers/", delete(remove_transfer))
         .route("/key/:pk", get(key))
@@ -154,6 +154,10 @@ pub static BITMASK_ENDPOINT: Lazy<RwLock<String>> =
 pub static RGB_PROXY_ENDPOINT: Lazy<RwLock<String>> =
     Lazy::new(|| RwLock::new(dot_env("RGB_PROXY_ENDPOINT")));
 
+// rgb consignment transport (advertised in the invoices)
+pub static RGB_TRANSPORT_ENDPOINT: Lazy<RwLock<String>> =
+    Lazy::new(|| RwLock::new(dot_env("RGB_TRANSPORT_ENDPOINT")));
+
 // carbonado
 pub static CARBONADO_ENDPOINT: Lazy<RwLock<String>> =
     Lazy::new(|| RwLock::new(dot_env("CARBONADO_ENDPOINT")));
@@ -163,6 +167,7 @@ pub async fn get_env(key: &str) -> String {
         "LNDHUB_ENDPOINT" => LNDHUB_ENDPOINT.read().await.to_string(),
         "BITMASK_ENDPOINT" => BITMASK_ENDPOINT.read().await.to_string(),
         "CARBONADO_ENDPOINT" => CARBONADO_ENDPOINT.read().await.to_string(),
+        "RGB_TRANSPORT_ENDPOINT" => RGB_TRANSPORT_ENDPOINT.read().await.to_string(),
         "BITCOIN_EXPLORER_API_MAINNET" => BITCOIN_EXPLORER_API_MAINNET.read().await.to_string(),
         "BITCOIN_EXPLORER_API_TESTNET" => BITCOIN_EXPLORER_API_TESTNET.read().await.to_string(),
         "BITCOIN_EXPLORER_API_SIGNET" => BITCOIN_EXPLORER_API_SIGNET.read().await.to_string(),
@@ -183,6 +188,7 @@ pub async fn set_env(key: &str, value: &str) {
         "LNDHUB_ENDPOINT" => *LNDHUB_ENDPOINT.write().await = value.to_owned(),
         "BITMASK_ENDPOINT" => *BITMASK_ENDPOINT.write().await = value.to_owned(),
         "CARBONADO_ENDPOINT" => *CARBONADO_ENDPOINT.write().await = value.to_owned(),
+        "RGB_TRANSPORT_ENDPOINT" => *RGB_TRANSPORT_ENDPOINT.write().await = value.to_owned(),
         "BITCOIN_EXPLORER_API_MAINNET" => {
             *BITCOIN_EXPLORER_API_MAINNET.write().await = value.to_owned()
         }
@@ -217,8 +223,13 @@ pub mod storage_keys {
     pub const ASSETS_STOCK: &str = "bitmask-fungible_assets_stock.c15";
     pub const ASSETS_WALLETS: &str = "bitmask-fungible_assets_wallets.c15";
     pub const ASSETS_TRANSFERS: &str = "bitmask_assets_transfers.c15";
+    pub const ASSETS_TRANSFERS_ARCHIVE: &str = "bitmask-asset_transfers_archive.c15";
     pub const ASSETS_OFFERS: &str = "bitmask-asset_offers.c15";
     pub const ASSETS_BIDS: &str = "bitmask-asset_bids.c15";
+    pub const ASSETS_INVOICES: &str = "bitmask-asset_invoices.c15";
+    pub const ASSETS_LOCKS: &str = "bitmask-asset_locks.c15";
+    pub const ASSETS_RESERVATIONS: &str = "bitmask-asset_reservations.c15";
+    pub const ASSETS_ANCHORS: &str = "bitmask-asset_anchors.c15";
     pub const MARKETPLACE_OFFERS: &str = "bitmask-marketplace_public_offers.c15";
     pub const MARKETPLACE_BIDS: &str = "bitmask-marketplace_public_bids.c15";
 }
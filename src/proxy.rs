@@ -1,3 +1,11 @@
+use amplify::hex::ToHex;
+use bitcoin_hashes::{sha256, Hash};
+
+use crate::{
+    rgb::structs::MediaMetadata,
+    structs::{MediaEncode, MediaItemRequest},
+};
+
 #[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
 #[display(doc_comments)]
 pub enum ProxyServerError {
@@ -11,6 +19,59 @@ pub enum ProxyServerError {
     AllEndpointsFailed,
 }
 
+/// Compute the metadata of a media (same digest stored by the proxy), without uploading it
+pub async fn media_data_digest(
+    media: MediaItemRequest,
+    encode: MediaEncode,
+) -> Result<MediaMetadata, ProxyServerError> {
+    let content = retrieve_data(&media.uri)
+        .await
+        .ok_or(ProxyServerError::IO("Media not found".to_string()))?;
+    let (id, source) = media_digest(&content, encode);
+    Ok(MediaMetadata::new(&id, &media.ty, &media.uri, &source))
+}
+
+fn media_digest(content: &[u8], encode: MediaEncode) -> (String, String) {
+    match encode {
+        MediaEncode::Base64 => {
+            let source = base64::encode(content);
+            let id = blake3::hash(content).to_hex().to_string();
+            (id, source)
+        }
+        MediaEncode::Sha2 => {
+            let source = sha256::Hash::hash(content);
+            let id = source.to_hex().to_string();
+            (id, source.to_hex())
+        }
+        MediaEncode::Blake3 => {
+            let source = blake3::hash(content).to_hex().to_string();
+            let id = source.clone();
+            (id, source)
+        }
+    }
+}
+
+async fn retrieve_data(url: &str) -> Option<Vec<u8>> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(url)
+        .header("Accept", "application/octet-stream")
+        .header("Cache-Control", "no-cache")
+        .send()
+        .await;
+
+    if let Ok(response) = response {
+        let status_code = response.status().as_u16();
+        if status_code == 200 {
+            if let Ok(bytes) = response.bytes().await {
+                return Some(bytes.to_vec());
+            }
+        }
+    }
+
+    None
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub use server::{
     handle_file, proxy_consig_retrieve, proxy_consig_store, proxy_media_data_store,
@@ -19,7 +80,6 @@ pub use server::{
 #[cfg(not(target_arch = "wasm32"))]
 mod server {
     use amplify::hex::ToHex;
-    use bitcoin_hashes::{sha256, Hash};
     use postcard::to_allocvec;
     use reqwest::multipart::{self, Part};
     use std::path::PathBuf;
@@ -37,7 +97,7 @@ mod server {
         util::{post_data, upload_data},
     };
 
-    use super::ProxyServerError;
+    use super::{media_digest, retrieve_data, ProxyServerError};
 
     pub async fn proxy_consig_store(
         request: RgbProxyConsigFileReq,
@@ -92,23 +152,7 @@ mod server {
         encode: MediaEncode,
     ) -> Result<MediaMetadata, ProxyServerError> {
         if let Some(content) = retrieve_data(&media.uri).await {
-            let (id, source) = match encode {
-                MediaEncode::Base64 => {
-                    let source = base64::encode(&content);
-                    let id = blake3::hash(&content).to_hex().to_string();
-                    (id, source)
-                }
-                MediaEncode::Sha2 => {
-                    let source = sha256::Hash::hash(&content);
-                    let id = source.to_hex().to_string();
-                    (id, source.to_hex())
-                }
-                MediaEncode::Blake3 => {
-                    let source = blake3::hash(&content).to_hex().to_string();
-                    let id = source.clone();
-                    (id, source)
-                }
-            };
+            let (id, source) = media_digest(&content, encode);
 
             let metadata = MediaMetadata::new(&id, &media.ty, &media.uri, &source);
 
@@ -311,27 +355,6 @@ mod server {
 
         Ok(resp)
     }
-
-    async fn retrieve_data(url: &str) -> Option<Vec<u8>> {
-        let client = reqwest::Client::new();
-        let response = client
-            .get(url)
-            .header("Accept", "application/octet-stream")
-            .header("Cache-Control", "no-cache")
-            .send()
-            .await;
-
-        if let Ok(response) = response {
-            let status_code = response.status().as_u16();
-            if status_code == 200 {
-                if let Ok(bytes) = response.bytes().await {
-                    return Some(bytes.to_vec());
-                }
-            }
-        }
-
-        None
-    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -5,21 +5,22 @@ use autosurgeon::reconcile;
 use bitcoin::{psbt::PartiallySignedTransaction, Network, Txid};
 use bitcoin_30::bip32::ExtendedPubKey;
 use bitcoin_scripts::address::AddressNetwork;
+use bp::seals::txout::CloseMethod;
 use futures::TryFutureExt;
 use garde::Validate;
 
 use miniscript_crate::DescriptorPublicKey;
-use rgb::{RgbDescr, RgbWallet};
+use rgb::{RgbDescr, RgbWallet, TerminalPath};
 use rgbstd::{
-    containers::BindleContent,
+    containers::{Bindle, BindleContent, Transfer},
     contract::ContractId,
     interface::TypedState,
     persistence::{Inventory, Stash, Stock},
-    validation::Validity,
+    validation::{Status, Validity},
 };
-use rgbwallet::{psbt::DbcPsbtError, RgbInvoice};
+use rgbwallet::{psbt::DbcPsbtError, RgbInvoice, RgbTransport};
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     ops::Sub,
     str::FromStr,
 };
@@ -41,76 +42,115 @@ pub mod prefetch;
 pub mod proxy;
 pub mod psbt;
 pub mod resolvers;
+pub mod schemata;
+pub mod selection;
 pub mod structs;
 pub mod swap;
 pub mod transfer;
+pub mod uri;
 pub mod wallet;
 
 use crate::{
-    constants::{get_network, BITCOIN_EXPLORER_API, NETWORK},
+    bitcoin::publish_psbt_file,
+    constants::{get_network, BITCOIN_EXPLORER_API, NETWORK, RGB_TRANSPORT_ENDPOINT},
     rgb::{
-        issue::{issue_contract as create_contract, IssueContractError},
+        issue::{
+            inflate_contract as inflate_rgb_contract, issue_contract as create_contract,
+            issue_custom_contract as create_custom_contract, IssueContractError,
+        },
         psbt::{create_psbt as create_rgb_psbt, extract_output_commit},
         resolvers::ExplorerResolver,
         transfer::{
-            accept_transfer as accept_rgb_transfer, create_invoice as create_rgb_invoice,
-            pay_invoice,
+            accept_transfer as accept_rgb_transfer, burn_asset as burn_rgb_asset,
+            create_invoice as create_rgb_invoice,
+            create_witness_invoice as create_rgb_witness_invoice, extract_anchor_txids,
+            extract_beneficiary_amounts, extract_validation_report, extract_witness_outpoints,
+            pay_invoice, validate_transfer, NewInvoiceOptions,
         },
-        wallet::list_allocations,
+        uri::{extract_rgb_invoice, UnifiedUri, UnifiedUriError},
+        wallet::{contract_allocations, list_allocations},
     },
     structs::{
-        AcceptRequest, AcceptResponse, AssetType, BatchRgbTransferItem, BatchRgbTransferResponse,
-        ContractHiddenResponse, ContractResponse, ContractsResponse, FullRgbTransferRequest,
-        ImportRequest, InterfaceDetail, InterfacesResponse, InvoiceRequest, InvoiceResponse,
-        IssueMediaRequest, IssueRequest, IssueResponse, MediaEncode, MediaRequest, MediaResponse,
-        MediaView, NextAddressResponse, NextUtxoResponse, NextUtxosResponse, PsbtFeeRequest,
-        PsbtRequest, PsbtResponse, PublicRgbBidResponse, PublicRgbOfferResponse,
-        PublicRgbOffersResponse, ReIssueRequest, ReIssueResponse, RgbBidDetail, RgbBidRequest,
+        AcceptRequest, AcceptResponse, AllocationValue, AssetType, BatchAcceptItem,
+        BatchAcceptResponse, BatchRgbTransferItem, BatchRgbTransferResponse, BurnRequest,
+        BurnResponse, CoinSelectionStrategy, ConsignmentValidationReport, ConsolidateRequest,
+        ConsolidateResponse, ContractHiddenResponse, ContractResponse, ContractsResponse,
+        CpfpRequest, CpfpResponse, FullRgbTransferRequest, ImportRequest, ImportSchemaRequest,
+        ImportSchemaResponse, InflateRequest, InflateResponse, InterfaceDetail, InterfacesResponse,
+        InvoiceRequest, InvoiceResponse, IssueAllocationRequest, IssueCustomRequest,
+        IssueCustomResponse, IssueInflationRequest, IssueMediaRequest, IssueRequest, IssueResponse,
+        MediaEncode, MediaInfo, MediaRequest, MediaResponse, MediaView, NextAddressResponse,
+        NextUtxoResponse, NextUtxosResponse, PsbtFeeRequest, PsbtInputRequest, PsbtRequest,
+        PsbtResponse, PublicRgbBidResponse, PublicRgbOfferResponse, PublicRgbOffersResponse,
+        PublishPsbtRequest, ReIssueRequest, ReIssueResponse, RgbBidDetail, RgbBidRequest,
         RgbBidResponse, RgbBidsResponse, RgbInternalSaveTransferRequest,
-        RgbInternalTransferResponse, RgbInvoiceResponse, RgbOfferBidsResponse, RgbOfferDetail,
-        RgbOfferRequest, RgbOfferResponse, RgbOfferUpdateRequest, RgbOfferUpdateResponse,
-        RgbOffersResponse, RgbRemoveTransferRequest, RgbReplaceResponse, RgbSaveTransferRequest,
-        RgbSwapRequest, RgbSwapResponse, RgbTransferDetail, RgbTransferRequest,
+        RgbInternalTransferResponse, RgbInvoiceResponse, RgbInvoiceStatusDetail,
+        RgbInvoicesResponse, RgbOfferBidsResponse, RgbOfferCancelRequest, RgbOfferCancelResponse,
+        RgbOfferDetail, RgbOfferRequest, RgbOfferResponse, RgbOfferUpdateRequest,
+        RgbOfferUpdateResponse, RgbOffersResponse, RgbRemoveTransferRequest, RgbReplaceResponse,
+        RgbSaveTransferRequest, RgbSignedTransferRequest, RgbSwapRequest, RgbSwapResponse,
+        RgbTransferBeneficiary, RgbTransferBundle, RgbTransferDetail, RgbTransferHistoryItem,
+        RgbTransferHistoryRequest, RgbTransferHistoryResponse, RgbTransferRequest,
         RgbTransferResponse, RgbTransferStatusResponse, RgbTransfersResponse, SchemaDetail,
-        SchemasResponse, SimpleContractResponse, TransferType, TxStatus, UtxoResponse,
-        WatcherDetailResponse, WatcherRequest, WatcherResponse, WatcherUtxoResponse,
+        SchemaImportType, SchemasResponse, SealCloseMethod, SecretString, SimpleContractResponse,
+        SplitRequest, SplitResponse, TransferType, TxStatus, UnifiedUriRequest, UnifiedUriResponse,
+        UtxoResponse, ValidateConsignmentRequest, WatcherDetail, WatcherDetailResponse,
+        WatcherRequest, WatcherResponse, WatcherUtxoResponse, WitnessInvoiceRequest,
     },
     validators::RGBContext,
 };
 
 use self::{
     consignment::NewTransferOptions,
-    constants::{RGB_DEFAULT_FETCH_LIMIT, RGB_DEFAULT_NAME},
-    contract::{export_boilerplate, export_contract, extract_metadata, ExportContractError},
+    constants::{
+        BITCOIN_DEFAULT_FETCH_LIMIT, RGB_DEFAULT_FETCH_LIMIT, RGB_DEFAULT_NAME,
+        RGB_EXPORT_LOCK_EXPIRY, RGB_INFLATION_RIGHT, RGB_REORG_DEPTH,
+    },
+    contract::{
+        export_boilerplate, export_contract, export_formats, extract_metadata, ExportContractError,
+    },
     crdt::{LocalRgbAccount, RawRgbAccount, RgbMerge},
     fs::{
-        retrieve_account, retrieve_bids, retrieve_local_account, retrieve_offers,
-        retrieve_public_offers, retrieve_stock as retrieve_rgb_stock, retrieve_stock_account,
+        retrieve_account, retrieve_anchors, retrieve_bids, retrieve_invoices,
+        retrieve_local_account, retrieve_locks, retrieve_offers, retrieve_public_offers,
+        retrieve_reservations, retrieve_stock as retrieve_rgb_stock, retrieve_stock_account,
         retrieve_stock_account_transfers, retrieve_stock_transfers, retrieve_transfers,
-        store_account, store_bids, store_local_account, store_offers,
+        retrieve_transfers_archive, store_account, store_anchors, store_bids, store_invoices,
+        store_local_account, store_locks, store_offers, store_reservations,
         store_stock as store_rgb_stock, store_stock_account, store_stock_account_transfers,
-        store_stock_transfers, store_transfers, RgbPersistenceError,
+        store_stock_transfers, store_transfers, store_transfers_archive, RgbPersistenceError,
+    },
+    import::{
+        import_contract, import_iface as import_rgb_iface,
+        import_iface_impl as import_rgb_iface_impl, import_schema as import_rgb_schema,
+        ImportContractError, ImportSchemaError,
     },
-    import::{import_contract, ImportContractError},
     prebuild::{
         prebuild_buyer_swap, prebuild_extract_transfer, prebuild_seller_swap,
-        prebuild_transfer_asset,
+        prebuild_transfer_asset, DUST_LIMIT_SATOSHI,
     },
     prefetch::{
-        prefetch_resolver_allocations, prefetch_resolver_import_rgb, prefetch_resolver_psbt,
-        prefetch_resolver_rgb, prefetch_resolver_txs_status, prefetch_resolver_user_utxo_status,
-        prefetch_resolver_utxos, prefetch_resolver_waddress, prefetch_resolver_wutxo,
+        prefetch_resolver_allocations, prefetch_resolver_fee_estimates,
+        prefetch_resolver_import_rgb, prefetch_resolver_psbt, prefetch_resolver_rgb,
+        prefetch_resolver_tip_height, prefetch_resolver_txs, prefetch_resolver_txs_fee,
+        prefetch_resolver_txs_status, prefetch_resolver_user_utxo_status, prefetch_resolver_utxos,
+        prefetch_resolver_waddress, prefetch_resolver_wutxo,
     },
     proxy::{
-        get_consignment as get_rgb_consignment, get_media_metadata as get_rgb_media_metadata,
-        post_consignments, post_media_metadata, post_media_metadata_list, ProxyError,
+        digest_media_metadata, get_consignment as get_rgb_consignment,
+        get_media_metadata as get_rgb_media_metadata, post_consignments, post_media_metadata,
+        post_media_metadata_list, ProxyError,
     },
     psbt::{
-        save_tap_commit_str, set_tapret_output, CreatePsbtError, EstimateFeeError, NewPsbtOptions,
+        estimate_cpfp_fee, estimate_fee, estimate_fee_rate, estimate_psbt_fee_rate,
+        estimate_taproot_vsize, save_tap_commit_str, set_opret_output, set_tapret_output,
+        CreatePsbtError, EstimateFeeError, NewPsbtOptions,
     },
+    selection::{find_missing_outpoint, select_coins},
     structs::{
-        ContractAmount, ContractBoilerplate, MediaMetadata, RgbAccountV1, RgbExtractTransfer,
-        RgbTransferV1, RgbTransfersV1,
+        AddressAmount, ContractAmount, ContractBoilerplate, MediaMetadata, RgbAccountV1,
+        RgbAnchors, RgbExtractTransfer, RgbInvoiceRecord, RgbInvoices, RgbLockOwner, RgbLocks,
+        RgbReservations, RgbTransferV1, RgbTransfersV1,
     },
     swap::{
         get_public_offer, get_swap_bid, get_swap_bid_by_buyer, get_swap_bids_by_seller,
@@ -120,8 +160,8 @@ use self::{
     },
     transfer::{extract_transfer, AcceptTransferError, NewInvoiceError, NewPaymentError},
     wallet::{
-        create_wallet, next_address, next_utxo, next_utxos, register_address, register_utxo,
-        sync_wallet,
+        contract_rights, create_wallet, next_address, next_addresses, next_utxo, next_utxos,
+        register_address, register_utxo, reserve_address, sync_wallet,
     },
 };
 
@@ -140,6 +180,18 @@ pub enum IssueError {
     Issue(IssueContractError),
     /// Occurs an error in export step. {0}
     Export(ExportContractError),
+    /// Contract is required in this operation. Please, import or issue a Contract.
+    NoContract,
+    /// The contract {0} has no inflation right available in this wallet.
+    NoInflationRight(String),
+    /// Occurs an error in create psbt step. {0}
+    Create(PsbtError),
+    /// Occurs an error in commitment step. {0}
+    Commitment(DbcPsbtError),
+    /// Consignment cannot be encoded. {0}
+    WrongConsig(String),
+    /// Occurs an error in save step. {0}
+    Save(SaveTransferError),
 }
 
 /// RGB Operations
@@ -152,29 +204,17 @@ pub async fn issue_contract(sk: &str, request: IssueRequest) -> Result<IssueResp
         return Err(IssueError::Validation(errors));
     }
 
-    let IssueRequest {
-        ticker,
-        name,
-        description,
-        supply,
-        precision,
-        iface,
-        seal,
-        meta,
-    } = request;
-
     let mut resolver = ExplorerResolver {
         explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
         ..default!()
     };
 
     let (mut stock, mut rgb_account) = retrieve_stock_account(sk).await.map_err(IssueError::IO)?;
-    let network = get_network().await;
     let wallet = rgb_account.wallets.get(RGB_DEFAULT_NAME);
     let mut wallet = match wallet {
         Some(wallet) => {
             let mut fetch_wallet = wallet.to_owned();
-            for contract_type in [AssetType::RGB20, AssetType::RGB21] {
+            for contract_type in [AssetType::RGB20, AssetType::RGB21, AssetType::RGB25] {
                 let contract_index = contract_type as u32;
                 prefetch_resolver_utxos(
                     contract_index,
@@ -197,7 +237,107 @@ pub async fn issue_contract(sk: &str, request: IssueRequest) -> Result<IssueResp
         _ => None,
     };
 
+    let resp =
+        internal_issue_contract(request, false, &mut stock, &mut wallet, &mut resolver).await?;
+
+    if let Some(wallet) = wallet {
+        rgb_account
+            .wallets
+            .insert(RGB_DEFAULT_NAME.to_string(), wallet);
+    };
+
+    store_stock_account(sk, stock, rgb_account)
+        .await
+        .map_err(IssueError::IO)?;
+
+    Ok(resp)
+}
+
+/// Build and validate the contract in an ephemeral stock (dry-run).
+/// The user stock, storage and proxy are not touched.
+pub async fn preview_issue_contract(request: IssueRequest) -> Result<IssueResponse, IssueError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(IssueError::Validation(errors));
+    }
+
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let mut stock = Stock::default();
+    internal_issue_contract(request, true, &mut stock, &mut None, &mut resolver).await
+}
+
+async fn internal_issue_contract(
+    request: IssueRequest,
+    preview: bool,
+    stock: &mut Stock,
+    wallet: &mut Option<RgbWallet>,
+    resolver: &mut ExplorerResolver,
+) -> Result<IssueResponse, IssueError> {
+    let IssueRequest {
+        ticker,
+        name,
+        description,
+        supply,
+        precision,
+        iface,
+        seal,
+        allocations,
+        meta,
+        terms,
+        attachment,
+        inflation,
+        collection,
+    } = request;
+
+    if let Some(inflation) = &inflation {
+        if inflation.seal == seal || allocations.iter().any(|alloc| alloc.seal == inflation.seal) {
+            return Err(IssueError::Validation(BTreeMap::from([(
+                "inflation.seal".to_string(),
+                "inflation right must use a different seal".to_string(),
+            )])));
+        }
+    }
+
+    // Attached document (stored in the proxy, only the hash is committed).
+    // Previews only compute the hash, nothing is uploaded.
+    let meta = match attachment {
+        Some(attachment) => {
+            let metadata = if preview {
+                digest_media_metadata(attachment, MediaEncode::Sha2).await
+            } else {
+                post_media_metadata(attachment, MediaEncode::Sha2).await
+            }
+            .map_err(IssueError::Proxy)?;
+            let mut meta = meta.unwrap_or_default();
+            meta.media = Some(MediaInfo {
+                ty: metadata.mime,
+                source: metadata.digest,
+            });
+            Some(meta)
+        }
+        None => meta,
+    };
+
+    let network = get_network().await;
     let contract_amount = ContractAmount::with(supply, precision);
+    let allocations = allocations
+        .into_iter()
+        .map(|alloc| IssueAllocationRequest {
+            amount: ContractAmount::with(alloc.amount, precision).to_value(),
+            ..alloc
+        })
+        .collect();
+    let inflation = inflation.map(|inflation| IssueInflationRequest {
+        amount: ContractAmount::with(inflation.amount, precision).to_value(),
+        ..inflation
+    });
     let contract = create_contract(
         &ticker,
         &name,
@@ -206,10 +346,14 @@ pub async fn issue_contract(sk: &str, request: IssueRequest) -> Result<IssueResp
         contract_amount.to_value(),
         &iface,
         &seal,
+        allocations,
         &network,
         meta,
-        &mut resolver,
-        &mut stock,
+        terms,
+        inflation,
+        collection,
+        resolver,
+        stock,
     )
     .map_err(IssueError::Issue)?;
 
@@ -224,15 +368,11 @@ pub async fn issue_contract(sk: &str, request: IssueRequest) -> Result<IssueResp
         contract,
         genesis,
         meta,
+        tokens,
         created,
         ..
-    } = export_contract(
-        contract.contract_id(),
-        &mut stock,
-        &mut resolver,
-        &mut wallet,
-    )
-    .map_err(IssueError::Export)?;
+    } = export_contract(contract.contract_id(), stock, resolver, wallet)
+        .map_err(IssueError::Export)?;
 
     let meta = if let Some(metadata) = meta {
         Some(
@@ -244,16 +384,7 @@ pub async fn issue_contract(sk: &str, request: IssueRequest) -> Result<IssueResp
         None
     };
 
-    if let Some(wallet) = wallet {
-        rgb_account
-            .wallets
-            .insert(RGB_DEFAULT_NAME.to_string(), wallet);
-    };
-
-    store_stock_account(sk, stock, rgb_account)
-        .await
-        .map_err(IssueError::IO)?;
-
+    let (issue_method, issue_utxo) = seal.split_once(':').unwrap_or_default();
     Ok(IssueResponse {
         contract_id,
         iface,
@@ -266,9 +397,76 @@ pub async fn issue_contract(sk: &str, request: IssueRequest) -> Result<IssueResp
         contract,
         genesis,
         created,
-        issue_method: "tapret1st".to_string(),
-        issue_utxo: seal.replace("tapret1st:", ""),
+        issue_method: issue_method.to_string(),
+        issue_utxo: issue_utxo.to_string(),
         meta,
+        tokens,
+    })
+}
+
+/// Issue a contract against a schema previously imported by the user
+pub async fn issue_custom_contract(
+    sk: &str,
+    request: IssueCustomRequest,
+) -> Result<IssueCustomResponse, IssueError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(IssueError::Validation(errors));
+    }
+
+    let IssueCustomRequest {
+        schema_id,
+        iface,
+        globals,
+        assignments,
+    } = request;
+
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let mut stock = retrieve_rgb_stock(sk).await.map_err(IssueError::IO)?;
+
+    let network = get_network().await;
+    let contract = create_custom_contract(
+        &schema_id,
+        &iface,
+        &network,
+        globals,
+        assignments,
+        &mut resolver,
+        &mut stock,
+    )
+    .map_err(IssueError::Issue)?;
+
+    let contract_id = contract.contract_id();
+    let contract_bindle = stock
+        .export_contract(contract_id)
+        .map_err(|_| IssueError::NoContract)?;
+    let iimpl_id = contract_bindle
+        .ifaces
+        .values()
+        .next()
+        .map(|pair| pair.iimpl.impl_id().to_string())
+        .unwrap_or_default();
+
+    let contract_id = contract_id.to_string();
+    let (contract, genesis) =
+        export_formats(&contract_id, &contract_bindle).map_err(IssueError::Export)?;
+
+    store_rgb_stock(sk, stock).await.map_err(IssueError::IO)?;
+
+    Ok(IssueCustomResponse {
+        contract_id,
+        schema_id,
+        iimpl_id,
+        iface,
+        contract,
+        genesis,
     })
 }
 
@@ -312,13 +510,17 @@ pub async fn reissue_contract(
         let seal = seals.first().unwrap().to_owned();
 
         // TODO: Move to rgb/issue sub-module
+        let terms = contract_meta
+            .as_ref()
+            .map(|meta| meta.terms.clone())
+            .filter(|terms| !terms.is_empty());
         let meta = contract_meta.map(IssueMediaRequest::from);
         let network = get_network().await;
         let wallet = rgb_account.wallets.get(RGB_DEFAULT_NAME);
         let mut wallet = match wallet {
             Some(wallet) => {
                 let mut fetch_wallet = wallet.to_owned();
-                for contract_type in [AssetType::RGB20, AssetType::RGB21] {
+                for contract_type in [AssetType::RGB20, AssetType::RGB21, AssetType::RGB25] {
                     prefetch_resolver_utxos(
                         contract_type as u32,
                         &mut fetch_wallet,
@@ -341,8 +543,12 @@ pub async fn reissue_contract(
             supply,
             &iface,
             &seal,
+            vec![],
             &network,
             meta,
+            terms,
+            None,
+            vec![],
             &mut resolver,
             &mut stock,
         )
@@ -359,6 +565,7 @@ pub async fn reissue_contract(
             contract,
             genesis,
             meta,
+            tokens,
             created,
             ..
         } = export_contract(
@@ -385,6 +592,7 @@ pub async fn reissue_contract(
             None
         };
 
+        let (issue_method, issue_utxo) = seal.split_once(':').unwrap_or_default();
         reissue_resp.push(IssueResponse {
             contract_id,
             iface,
@@ -397,9 +605,10 @@ pub async fn reissue_contract(
             contract,
             genesis,
             created,
-            issue_method: "tapret1st".to_string(),
-            issue_utxo: seal.replace("tapret1st:", ""),
+            issue_method: issue_method.to_string(),
+            issue_utxo: issue_utxo.to_string(),
             meta,
+            tokens,
         });
     }
 
@@ -412,134 +621,511 @@ pub async fn reissue_contract(
     })
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
-#[display(doc_comments)]
-pub enum InvoiceError {
-    /// Some request data is missing. {0:?}
-    Validation(BTreeMap<String, String>),
-    /// Contract is required in this operation. Please, import or issue a Contract.
-    NoContract,
-    /// Invoice contains wrong contract precision. expect: {0} / current: {1}.
-    WrongPrecision(u8, u8),
-    /// I/O or connectivity error. {0}
-    IO(RgbPersistenceError),
-    /// Occurs an error in invoice step. {0}
-    Invoice(NewInvoiceError),
-}
-
-pub async fn create_invoice(
+pub async fn inflate_contract(
     sk: &str,
-    request: InvoiceRequest,
-) -> Result<InvoiceResponse, InvoiceError> {
-    let (mut stock, mut rgb_account) =
-        retrieve_stock_account(sk).await.map_err(InvoiceError::IO)?;
-
-    let invoice = internal_create_invoice(request, &mut stock).await?;
-    rgb_account.invoices.push(invoice.to_string());
-
-    store_stock_account(sk, stock, rgb_account)
-        .await
-        .map_err(InvoiceError::IO)?;
-
-    Ok(InvoiceResponse {
-        invoice: invoice.to_string(),
-    })
-}
-
-async fn internal_create_invoice(
-    request: InvoiceRequest,
-    stock: &mut Stock,
-) -> Result<RgbInvoice, InvoiceError> {
+    request: InflateRequest,
+) -> Result<InflateResponse, IssueError> {
     if let Err(err) = request.validate(&RGBContext::default()) {
         let errors = err
             .iter()
             .map(|(f, e)| (f.to_string(), e.to_string()))
             .collect();
-        return Err(InvoiceError::Validation(errors));
+        return Err(IssueError::Validation(errors));
     }
 
-    let InvoiceRequest {
+    let InflateRequest {
         contract_id,
-        iface,
-        seal,
         amount,
-        params,
+        seal,
+        descriptor,
+        change_terminal,
+        fee,
+        close_method,
     } = request;
 
-    let network = NETWORK.read().await.to_string();
-
-    let contr_id = ContractId::from_str(&contract_id).map_err(|_| InvoiceError::NoContract)?;
-    let boilerplate = export_boilerplate(contr_id, stock).map_err(|_| InvoiceError::NoContract)?;
-    let invoice_amount = ContractAmount::from_raw(amount.to_string());
-    if invoice_amount.precision != boilerplate.precision {
-        return Err(InvoiceError::WrongPrecision(
-            boilerplate.precision,
-            invoice_amount.precision,
-        ));
-    }
-
-    let invoice_amount = invoice_amount.to_value();
-    let invoice = create_rgb_invoice(
-        &contract_id,
-        &iface,
-        invoice_amount,
-        &seal,
-        &network,
-        params,
-        stock,
-    )
-    .map_err(InvoiceError::Invoice)?;
-
-    Ok(invoice)
-}
-
-#[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
-#[display(doc_comments)]
-pub enum PsbtError {
-    /// Some request data is missing. {0:?}
-    Validation(BTreeMap<String, String>),
-    /// Retrieve I/O or connectivity error. {0:?}
-    IO(RgbPersistenceError),
-    /// Watcher is required in this operation. Please, create watcher.
-    NoWatcher,
-    /// Contract is required in this operation. Please, import or issue a Contract.
-    NoContract,
-    /// FeeRate is supported in this operation. Please, use the absolute fee value.
-    NoFeeRate,
-    /// Insufficient funds (expected: {input} sats / current: {output} sats)
-    Inflation {
-        /// Amount spent: input amounts
-        input: u64,
-
-        /// Amount sent: sum of output value + transaction fee
-        output: u64,
-    },
-    /// Auto merge fail in this opration
-    WrongAutoMerge(String),
-    /// Occurs an error in create step. {0}
-    Create(CreatePsbtError),
-    /// Bitcoin network be decoded. {0}
-    WrongNetwork(String),
-    /// Occurs an error in export step. {0}
-    Export(ExportContractError),
-}
-
-pub async fn create_psbt(sk: &str, request: PsbtRequest) -> Result<PsbtResponse, PsbtError> {
     let mut resolver = ExplorerResolver {
         explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
         ..default!()
     };
 
-    let mut rgb_account = retrieve_account(sk).await.map_err(PsbtError::IO)?;
+    let (mut stock, mut rgb_account, mut rgb_transfers) = retrieve_stock_account_transfers(sk)
+        .await
+        .map_err(IssueError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(IssueError::IO)?;
 
-    let options = NewPsbtOptions::with(request.rbf);
-    let psbt =
-        internal_create_psbt(request, &mut rgb_account, &mut resolver, Some(options)).await?;
-    Ok(psbt)
-}
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(IssueError::Watcher),
+    };
 
-async fn internal_create_psbt(
-    request: PsbtRequest,
+    let contr_id = ContractId::from_str(&contract_id).map_err(|_| IssueError::NoContract)?;
+    let iface_index = AssetType::RGB20 as u32;
+    let iface = stock
+        .iface_by_name(&tn!("RGB20"))
+        .map_err(|_| IssueError::NoContract)?;
+    let contract_iface = stock
+        .contract_iface(contr_id, iface.iface_id())
+        .map_err(|_| IssueError::NoContract)?;
+
+    prefetch_resolver_utxos(
+        iface_index,
+        &mut rgb_wallet,
+        &mut resolver,
+        Some(RGB_DEFAULT_FETCH_LIMIT),
+    )
+    .await;
+    prefetch_resolver_allocations(contract_iface, &mut resolver, true).await;
+    sync_wallet(iface_index, &mut rgb_wallet, &mut resolver);
+
+    let ContractResponse {
+        precision,
+        supply,
+        max_supply,
+        ..
+    } = export_contract(contr_id, &mut stock, &mut resolver, &mut None)
+        .map_err(IssueError::Export)?;
+    let amount = ContractAmount::with(amount, precision).to_value();
+
+    let rights = contract_rights(
+        contr_id,
+        iface_index,
+        RGB_INFLATION_RIGHT,
+        &mut rgb_wallet,
+        &mut stock,
+        &mut resolver,
+    )
+    .map_err(|_| IssueError::NoInflationRight(contract_id.clone()))?;
+
+    let wildcard_terminal = "/*/*";
+    let terminal_step = format!("/{iface_index}/*");
+    let universal_desc = SecretString(
+        descriptor
+            .to_string()
+            .replace(&terminal_step, wildcard_terminal),
+    );
+
+    let asset_inputs: Vec<PsbtInputRequest> = rights
+        .into_iter()
+        .filter(|right| right.is_mine && !right.is_spent && !rgb_locks.is_locked(&right.utxo))
+        .map(|right| PsbtInputRequest {
+            descriptor: universal_desc.clone(),
+            utxo: right.utxo,
+            utxo_terminal: right.derivation,
+            tapret: None,
+            sigh_hash: None,
+        })
+        .collect();
+
+    if asset_inputs.is_empty() {
+        return Err(IssueError::NoInflationRight(contract_id));
+    }
+
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
+
+    let psbt_req = PsbtRequest {
+        fee,
+        asset_inputs,
+        bitcoin_inputs: vec![],
+        bitcoin_changes: vec![],
+        asset_descriptor_change: None,
+        asset_terminal_change: Some(change_terminal.clone()),
+        rbf: true,
+        close_method,
+        coin_selection: default!(),
+    };
+
+    let PsbtResponse { psbt, .. } =
+        internal_create_psbt(psbt_req, &mut rgb_account, &mut resolver, None)
+            .await
+            .map_err(IssueError::Create)?;
+
+    let (psbt, transfer) =
+        inflate_rgb_contract(&contract_id, "RGB20", amount, &seal, psbt, &mut stock)
+            .map_err(IssueError::Issue)?;
+
+    let (outpoint, change_amount, commit) =
+        extract_output_commit(psbt.clone()).map_err(IssueError::Commitment)?;
+
+    let consig_id = transfer.bindle_id().to_string();
+    let consig = transfer
+        .to_strict_serialized::<{ U32 }>()
+        .map_err(|err| IssueError::WrongConsig(err.to_string()))?
+        .to_hex();
+    let commit = commit.to_hex();
+    let psbt_hex = psbt.to_string();
+
+    let internal_request = RgbInternalSaveTransferRequest::with(
+        consig_id.clone(),
+        consig.clone(),
+        seal.clone(),
+        "RGB20".to_string(),
+        true,
+        None,
+        Some(psbt),
+    );
+
+    // Spent outpoints are reserved until the transfer is confirmed (or removed)
+    let utc = chrono::Local::now().naive_utc().timestamp();
+    rgb_locks.lock(
+        internal_request.utxos.clone(),
+        RgbLockOwner::Transfer(consig_id.clone()),
+        utc,
+    );
+
+    let txid = internal_save_transfer(internal_request, &mut rgb_transfers)
+        .await
+        .map_err(IssueError::Save)?;
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(IssueError::Watcher),
+    };
+
+    save_tap_commit_str(
+        &outpoint.to_string(),
+        change_amount,
+        &commit,
+        &change_terminal,
+        &mut rgb_wallet,
+    );
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
+
+    store_stock_account_transfers(sk, stock, rgb_account, rgb_transfers)
+        .await
+        .map_err(IssueError::IO)?;
+    store_locks(sk, rgb_locks).await.map_err(IssueError::IO)?;
+
+    Ok(InflateResponse {
+        fee_rate: estimate_psbt_fee_rate(&psbt_hex),
+        contract_id,
+        consig_id,
+        consig,
+        psbt: psbt_hex,
+        commit,
+        txid: txid.to_hex(),
+        supply: supply + amount,
+        max_supply,
+    })
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
+#[display(doc_comments)]
+pub enum InvoiceError {
+    /// Some request data is missing. {0:?}
+    Validation(BTreeMap<String, String>),
+    /// Contract is required in this operation. Please, import or issue a Contract.
+    NoContract,
+    /// Invoice contains wrong contract precision. expect: {0} / current: {1}.
+    WrongPrecision(u8, u8),
+    /// I/O or connectivity error. {0}
+    IO(RgbPersistenceError),
+    /// Occurs an error in invoice step. {0}
+    Invoice(NewInvoiceError),
+    /// Watcher is required in this operation. Please, create watcher.
+    NoWatcher,
+    /// Beneficiary address cannot be derived. {0}
+    WrongAddress(String),
+    /// Occurs an error in unified URI step. {0}
+    WrongUri(UnifiedUriError),
+}
+
+pub async fn create_invoice(
+    sk: &str,
+    request: InvoiceRequest,
+) -> Result<InvoiceResponse, InvoiceError> {
+    let (mut stock, mut rgb_account) =
+        retrieve_stock_account(sk).await.map_err(InvoiceError::IO)?;
+
+    let invoice = internal_create_invoice(request, &mut stock).await?;
+    rgb_account.invoices.push(invoice.to_string());
+
+    store_stock_account(sk, stock, rgb_account)
+        .await
+        .map_err(InvoiceError::IO)?;
+    register_invoice(sk, &invoice).await?;
+
+    Ok(InvoiceResponse {
+        invoice: invoice.to_string(),
+    })
+}
+
+async fn internal_create_invoice(
+    request: InvoiceRequest,
+    stock: &mut Stock,
+) -> Result<RgbInvoice, InvoiceError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(InvoiceError::Validation(errors));
+    }
+
+    let InvoiceRequest {
+        contract_id,
+        iface,
+        seal,
+        amount,
+        params,
+        expiry,
+        transports,
+    } = request;
+
+    let network = NETWORK.read().await.to_string();
+
+    let contr_id = ContractId::from_str(&contract_id).map_err(|_| InvoiceError::NoContract)?;
+    let boilerplate = export_boilerplate(contr_id, stock).map_err(|_| InvoiceError::NoContract)?;
+    let invoice_amount = ContractAmount::from_raw(amount.to_string());
+    if invoice_amount.precision != boilerplate.precision {
+        return Err(InvoiceError::WrongPrecision(
+            boilerplate.precision,
+            invoice_amount.precision,
+        ));
+    }
+
+    let invoice_amount = invoice_amount.to_value();
+    let invoice = create_rgb_invoice(
+        &contract_id,
+        &iface,
+        invoice_amount,
+        &seal,
+        &network,
+        params,
+        invoice_options(expiry, transports).await,
+        stock,
+    )
+    .map_err(InvoiceError::Invoice)?;
+
+    Ok(invoice)
+}
+
+async fn invoice_options(
+    expiry: Option<i64>,
+    transports: Option<Vec<String>>,
+) -> NewInvoiceOptions {
+    // By default, the consignment is posted to the configured RGB transport
+    let transports = match transports {
+        Some(transports) => transports,
+        None => {
+            let endpoint = RGB_TRANSPORT_ENDPOINT.read().await.to_string();
+            if endpoint.is_empty() {
+                vec![]
+            } else {
+                vec![endpoint]
+            }
+        }
+    };
+
+    NewInvoiceOptions::with(expiry, transports)
+}
+
+pub async fn create_witness_invoice(
+    sk: &str,
+    request: WitnessInvoiceRequest,
+) -> Result<InvoiceResponse, InvoiceError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(InvoiceError::Validation(errors));
+    }
+
+    let (mut stock, mut rgb_account) =
+        retrieve_stock_account(sk).await.map_err(InvoiceError::IO)?;
+    let mut rgb_reservations = retrieve_reservations(sk).await.map_err(InvoiceError::IO)?;
+
+    let WitnessInvoiceRequest {
+        contract_id,
+        iface,
+        amount,
+        address,
+        params,
+        expiry,
+        transports,
+    } = request;
+
+    let network = NETWORK.read().await.to_string();
+
+    let contr_id = ContractId::from_str(&contract_id).map_err(|_| InvoiceError::NoContract)?;
+    let boilerplate =
+        export_boilerplate(contr_id, &mut stock).map_err(|_| InvoiceError::NoContract)?;
+    let invoice_amount = ContractAmount::from_raw(amount.to_string());
+    if invoice_amount.precision != boilerplate.precision {
+        return Err(InvoiceError::WrongPrecision(
+            boilerplate.precision,
+            invoice_amount.precision,
+        ));
+    }
+
+    // Beneficiary output (default: next unused address of the watcher, reserved in the wallet)
+    let address = match address {
+        Some(address) => address,
+        None => {
+            let wallet = rgb_account
+                .wallets
+                .get(RGB_DEFAULT_NAME)
+                .ok_or(InvoiceError::NoWatcher)?;
+
+            let address_network = Network::from_str(&network)
+                .map_err(|op| InvoiceError::WrongAddress(op.to_string()))?;
+            let address_network = AddressNetwork::from(address_network);
+
+            let contract_index = match iface.as_str() {
+                "RGB20" => AssetType::RGB20,
+                "RGB25" => AssetType::RGB25,
+                _ => AssetType::RGB21,
+            };
+
+            reserve_address(
+                contract_index as u32,
+                wallet,
+                &mut rgb_reservations,
+                address_network,
+            )
+            .map_err(|op| InvoiceError::WrongAddress(op.to_string()))?
+            .address
+            .to_string()
+        }
+    };
+
+    let invoice = create_rgb_witness_invoice(
+        &contract_id,
+        &iface,
+        invoice_amount.to_value(),
+        &address,
+        &network,
+        params,
+        invoice_options(expiry, transports).await,
+        &mut stock,
+    )
+    .map_err(InvoiceError::Invoice)?;
+    rgb_account.invoices.push(invoice.to_string());
+
+    store_stock_account(sk, stock, rgb_account)
+        .await
+        .map_err(InvoiceError::IO)?;
+    store_reservations(sk, rgb_reservations)
+        .await
+        .map_err(InvoiceError::IO)?;
+    register_invoice(sk, &invoice).await?;
+
+    Ok(InvoiceResponse {
+        invoice: invoice.to_string(),
+    })
+}
+
+async fn register_invoice(sk: &str, invoice: &RgbInvoice) -> Result<(), InvoiceError> {
+    let mut rgb_invoices = retrieve_invoices(sk).await.map_err(InvoiceError::IO)?;
+
+    let created = chrono::Local::now().naive_utc().timestamp();
+    let record = RgbInvoiceRecord::new(invoice, created);
+    rgb_invoices.invoices.insert(record.invoice.clone(), record);
+
+    store_invoices(sk, rgb_invoices)
+        .await
+        .map_err(InvoiceError::IO)
+}
+
+pub async fn list_invoices(sk: &str) -> Result<RgbInvoicesResponse, InvoiceError> {
+    let mut rgb_invoices = retrieve_invoices(sk).await.map_err(InvoiceError::IO)?;
+
+    let now = chrono::Local::now().naive_utc().timestamp();
+    let mut invoices = vec![];
+    for record in rgb_invoices.invoices.values_mut() {
+        record.refresh(now);
+        invoices.push(RgbInvoiceStatusDetail::from(record.to_owned()));
+    }
+    invoices.sort_by(|a, b| b.created.cmp(&a.created));
+
+    store_invoices(sk, rgb_invoices)
+        .await
+        .map_err(InvoiceError::IO)?;
+
+    Ok(RgbInvoicesResponse { invoices })
+}
+
+fn internal_settle_invoices(
+    transfer: &Bindle<Transfer>,
+    network: bitcoin_30::Network,
+    resolver: &ExplorerResolver,
+    rgb_invoices: &mut RgbInvoices,
+) {
+    let transfer_id = transfer.transfer_id().to_string();
+    let contract_id = transfer.contract_id().to_string();
+    // Each payment settles a single invoice (invoices of the same contract first)
+    for (beneficiary, amount) in extract_beneficiary_amounts(transfer, network, resolver) {
+        let mut records: Vec<_> = rgb_invoices
+            .invoices
+            .values_mut()
+            .filter(|record| record.beneficiary == beneficiary)
+            .collect();
+        records.sort_by_key(|record| record.contract_id != contract_id);
+
+        for record in records {
+            if record.settle(&contract_id, &transfer_id, amount) {
+                break;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
+#[display(doc_comments)]
+pub enum PsbtError {
+    /// Some request data is missing. {0:?}
+    Validation(BTreeMap<String, String>),
+    /// Retrieve I/O or connectivity error. {0:?}
+    IO(RgbPersistenceError),
+    /// Watcher is required in this operation. Please, create watcher.
+    NoWatcher,
+    /// Contract is required in this operation. Please, import or issue a Contract.
+    NoContract,
+    /// The explorer has no fee estimate for the target of {0} blocks
+    NoFeeEstimate(u16),
+    /// Insufficient funds (expected: {input} sats / current: {output} sats)
+    Inflation {
+        /// Amount spent: input amounts
+        input: u64,
+
+        /// Amount sent: sum of output value + transaction fee
+        output: u64,
+    },
+    /// Auto merge fail in this opration
+    WrongAutoMerge(String),
+    /// Occurs an error in create step. {0}
+    Create(CreatePsbtError),
+    /// Occurs an error in estimate fee step. {0}
+    Estimate(EstimateFeeError),
+    /// Bitcoin network be decoded. {0}
+    WrongNetwork(String),
+    /// Occurs an error in export step. {0}
+    Export(ExportContractError),
+}
+
+pub async fn create_psbt(sk: &str, request: PsbtRequest) -> Result<PsbtResponse, PsbtError> {
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let mut rgb_account = retrieve_account(sk).await.map_err(PsbtError::IO)?;
+
+    let options = NewPsbtOptions {
+        close_method: request.close_method.into(),
+        ..NewPsbtOptions::with(request.rbf)
+    };
+    let psbt =
+        internal_create_psbt(request, &mut rgb_account, &mut resolver, Some(options)).await?;
+    Ok(psbt)
+}
+
+async fn internal_create_psbt(
+    request: PsbtRequest,
     rgb_account: &mut RgbAccountV1,
     resolver: &mut ExplorerResolver,
     options: Option<NewPsbtOptions>,
@@ -549,117 +1135,1150 @@ async fn internal_create_psbt(
             .iter()
             .map(|(f, e)| (f.to_string(), e.to_string()))
             .collect();
-        return Err(PsbtError::Validation(errors));
+        return Err(PsbtError::Validation(errors));
+    }
+
+    if rgb_account.wallets.get(RGB_DEFAULT_NAME).is_none() {
+        return Err(PsbtError::NoWatcher);
+    }
+
+    let PsbtRequest {
+        asset_inputs,
+        asset_terminal_change,
+        bitcoin_inputs,
+        bitcoin_changes,
+        fee,
+        coin_selection,
+        close_method,
+        ..
+    } = request;
+
+    // Resolve the confirmation target into a fee rate
+    let fee_request = match fee {
+        PsbtFeeRequest::Target { blocks } => {
+            prefetch_resolver_fee_estimates(resolver).await;
+            let fee_rate = estimate_fee_rate(blocks, &resolver.fee_estimates)
+                .ok_or(PsbtError::NoFeeEstimate(blocks))?;
+            PsbtFeeRequest::FeeRate(fee_rate)
+        }
+        fee => fee,
+    };
+
+    let mut all_inputs = asset_inputs.clone();
+    all_inputs.extend(bitcoin_inputs.clone());
+    for input_utxo in all_inputs.clone() {
+        prefetch_resolver_psbt(&input_utxo.utxo, resolver).await;
+    }
+
+    // The fee of a fee rate is estimated with all the candidates (upper bound
+    // used by the coin selection)
+    let fee = match fee_request.clone() {
+        PsbtFeeRequest::Value(fee) => fee,
+        PsbtFeeRequest::FeeRate(fee_rate) => estimate_inputs_fee(
+            all_inputs,
+            bitcoin_changes.clone(),
+            fee_rate,
+            asset_terminal_change.clone(),
+            rgb_account,
+            resolver,
+        )?,
+        PsbtFeeRequest::Target { blocks } => return Err(PsbtError::NoFeeEstimate(blocks)),
+    };
+
+    // Bitcoin inputs are candidates when a coin selection strategy is given
+    let bitcoin_inputs = if coin_selection == CoinSelectionStrategy::Default {
+        bitcoin_inputs
+    } else {
+        let wallet_utxos = rgb_account
+            .wallets
+            .get(RGB_DEFAULT_NAME)
+            .map(|wallet| wallet.utxos.clone())
+            .unwrap_or_default();
+
+        // The amounts of the inputs are required to select the coins
+        let mut amounts = BTreeMap::new();
+        for input in asset_inputs.iter().chain(bitcoin_inputs.iter()) {
+            match wallet_utxos
+                .iter()
+                .find(|utxo| utxo.outpoint.to_string() == input.utxo)
+            {
+                Some(utxo) => amounts.insert(input.utxo.clone(), utxo.amount),
+                _ => {
+                    let mut errors = BTreeMap::new();
+                    errors.insert(
+                        "coin_selection".to_string(),
+                        format!("outpoint {} is unknown in the watcher", input.utxo),
+                    );
+                    return Err(PsbtError::Validation(errors));
+                }
+            };
+        }
+        let utxo_amount = |input: &PsbtInputRequest| amounts[&input.utxo];
+
+        let total_spend: u64 = bitcoin_changes
+            .iter()
+            .filter_map(|x| AddressAmount::from_str(x).ok())
+            .map(|x| x.amount)
+            .sum::<u64>()
+            + fee;
+        let total_assets: u64 = asset_inputs.iter().map(utxo_amount).sum();
+        select_coins(
+            bitcoin_inputs,
+            total_spend.saturating_sub(total_assets),
+            &coin_selection,
+            |x| x.utxo.clone(),
+            utxo_amount,
+        )
+    };
+
+    let all_inputs: Vec<String> = asset_inputs
+        .iter()
+        .chain(bitcoin_inputs.iter())
+        .map(|x| x.utxo.clone())
+        .collect();
+    if let Some(outpoint) = find_missing_outpoint(&coin_selection, &all_inputs) {
+        let mut errors = BTreeMap::new();
+        errors.insert(
+            "coin_selection".to_string(),
+            format!("outpoint {outpoint} is not an input of the request"),
+        );
+        return Err(PsbtError::Validation(errors));
+    }
+
+    let mut all_inputs = asset_inputs.clone();
+    all_inputs.extend(bitcoin_inputs.clone());
+
+    // The selected inputs pay the fee rate
+    let fee = match fee_request {
+        PsbtFeeRequest::FeeRate(fee_rate) if coin_selection != CoinSelectionStrategy::Default => {
+            estimate_inputs_fee(
+                all_inputs.clone(),
+                bitcoin_changes.clone(),
+                fee_rate,
+                asset_terminal_change.clone(),
+                rgb_account,
+                resolver,
+            )?
+        }
+        _ => fee,
+    };
+
+    // Without explicit options, the commitment follows the requested close method
+    let options = options.unwrap_or(NewPsbtOptions {
+        close_method: close_method.into(),
+        ..default!()
+    });
+    let wallet = rgb_account.wallets.get(RGB_DEFAULT_NAME);
+    let (mut psbt_file, change_terminal) = create_rgb_psbt(
+        all_inputs,
+        bitcoin_changes,
+        fee,
+        asset_terminal_change,
+        wallet.cloned(),
+        resolver,
+        options.clone(),
+    )
+    .map_err(PsbtError::Create)?;
+
+    if options.set_tapret {
+        let pos = (psbt_file.outputs.len() - 1) as u16;
+        psbt_file = match options.close_method {
+            CloseMethod::TapretFirst => set_tapret_output(psbt_file, pos),
+            CloseMethod::OpretFirst => set_opret_output(psbt_file, pos),
+        }
+        .map_err(PsbtError::Create)?;
+    }
+
+    let psbt = Serialize::serialize(&psbt_file).to_hex();
+    let psbt = PsbtResponse {
+        fee_rate: estimate_psbt_fee_rate(&psbt),
+        psbt,
+        terminal: change_terminal,
+    };
+
+    Ok(psbt)
+}
+
+/// Fee (in sats) paid by the PSBT spending the inputs at the fee rate (in sat/vB)
+fn estimate_inputs_fee(
+    inputs: Vec<PsbtInputRequest>,
+    bitcoin_changes: Vec<String>,
+    fee_rate: f32,
+    terminal_change: Option<String>,
+    rgb_account: &RgbAccountV1,
+    resolver: &mut ExplorerResolver,
+) -> Result<u64, PsbtError> {
+    let mut wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(wallet) => wallet.to_owned(),
+        _ => return Err(PsbtError::NoWatcher),
+    };
+
+    if inputs.is_empty() {
+        let mut errors = BTreeMap::new();
+        errors.insert(
+            "asset_inputs".to_string(),
+            "at least one input is required to estimate the fee".to_string(),
+        );
+        return Err(PsbtError::Validation(errors));
+    }
+
+    let (_, fee) = estimate_fee(
+        inputs,
+        vec![],
+        bitcoin_changes,
+        fee_rate,
+        &mut wallet,
+        None,
+        terminal_change,
+        resolver,
+    )
+    .map_err(PsbtError::Estimate)?;
+    Ok(fee)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
+#[display(doc_comments)]
+pub enum TransferError {
+    /// Some request data is missing. {0:?}
+    Validation(BTreeMap<String, String>),
+    /// Retrieve I/O or connectivity error. {0:?}
+    IO(RgbPersistenceError),
+    /// Watcher is required in this operation. Please, create watcher.
+    NoWatcher,
+    /// Contract is required in this operation. Please, import or issue a Contract.
+    NoContract,
+    /// Iface is required in this operation. Please, use the correct iface contract.
+    NoIface,
+    /// FeeRate is supported in this operation. Please, use the absolute fee value.
+    NoFeeRate,
+    /// The explorer has no fee estimate for the target of {0} blocks
+    NoFeeEstimate(u16),
+    /// Insufficient funds (expected: {input} sats / current: {output} sats)
+    Inflation {
+        /// Amount spent: input amounts
+        input: u64,
+
+        /// Amount sent: sum of output value + transaction fee
+        output: u64,
+    },
+    /// Occurs an error in create step. {0}
+    Create(PsbtError),
+    /// Occurs an error in estimate fee step. {0}
+    Estimate(EstimateFeeError),
+    /// Occurs an error in commitment step. {0}
+    Commitment(DbcPsbtError),
+    /// Occurs an error in payment step. {0}
+    Pay(NewPaymentError),
+    /// Occurs an error in accept step. {0}
+    Accept(AcceptTransferError),
+    /// Auto merge fail in this opration
+    WrongAutoMerge(String),
+    /// Consignment cannot be encoded.
+    WrongConsig(String),
+    /// Rgb Invoice cannot be decoded. {0}
+    WrongInvoice(String),
+    /// Bitcoin network be decoded. {0}
+    WrongNetwork(String),
+    /// Witness output cannot be registered in the watcher. {0}
+    WrongWatcher(String),
+    /// Occurs an error in swap step. {0}
+    WrongSwap(RgbOfferErrors),
+    /// Occurs an error in save transfer step. {0}
+    WrongSave(SaveTransferError),
+    /// Occurs an error in export step. {0}
+    Export(ExportContractError),
+    /// Occurs an error in retrieve transfers step. {0}
+    Save(SaveTransferError),
+    /// Occurs an error in retrieve proxy step. {0}
+    Proxy(ProxyError),
+    /// Insufficient assets (expected: {0} / available: {1})
+    InsufficientAssets(u64, u64),
+    /// Nothing to consolidate, at least two allocations are required (current: {0})
+    NothingToConsolidate(usize),
+    /// Contract {0} cannot be burned (only contracts issued with an inflation allowance support burn)
+    NoBurnSupport(String),
+    /// Transaction {0} is not found in the explorer
+    NoTransaction(String),
+    /// Transaction {0} is already confirmed
+    TransactionConfirmed(String),
+    /// Transaction {0} has no bitcoin output of the watcher (outputs with RGB allocations cannot be spent)
+    NoCpfpOutput(String),
+    /// Signed PSBT does not match the transfer bundle. {0}
+    WrongPsbt(String),
+    /// Signed PSBT cannot be published. {0}
+    Publish(String),
+}
+
+pub async fn full_transfer_asset(
+    sk: &str,
+    request: FullRgbTransferRequest,
+) -> Result<RgbTransferResponse, TransferError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(TransferError::Validation(errors));
+    }
+
+    // Unified URIs (BIP-21) carry the RGB invoice in the `rgb` parameter
+    let network = NETWORK.read().await.to_string();
+    let rgb_invoice = extract_rgb_invoice(&request.rgb_invoice, &network)
+        .map_err(|op| TransferError::WrongInvoice(op.to_string()))?;
+    let request = FullRgbTransferRequest {
+        rgb_invoice,
+        ..request
+    };
+
+    let (mut stock, mut rgb_transfers) = retrieve_stock_transfers(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
+
+    let local_rgb_account = retrieve_local_account(sk)
+        .await
+        .map_err(TransferError::IO)?;
+
+    let LocalRgbAccount {
+        doc,
+        mut rgb_account,
+    } = local_rgb_account;
+    let mut fork_wallet = automerge::AutoCommit::load(&doc)
+        .map_err(|op| TransferError::WrongAutoMerge(op.to_string()))?;
+    let mut rgb_account_changes = RawRgbAccount::from(rgb_account.clone());
+
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(TransferError::NoWatcher),
+    };
+
+    let (asset_inputs, bitcoin_inputs, bitcoin_changes, fee_value) = prebuild_transfer_asset(
+        request.clone(),
+        &mut stock,
+        &mut rgb_wallet,
+        &mut resolver,
+        &rgb_locks,
+    )
+    .await?;
+
+    let FullRgbTransferRequest {
+        rgb_invoice,
+        change_terminal,
+        other_invoices,
+        close_method,
+        ..
+    } = request;
+
+    let psbt_req = PsbtRequest {
+        fee: PsbtFeeRequest::Value(fee_value),
+        asset_inputs,
+        bitcoin_inputs,
+        bitcoin_changes,
+        asset_descriptor_change: None,
+        asset_terminal_change: Some(change_terminal),
+        rbf: true,
+        close_method,
+        coin_selection: default!(),
+    };
+
+    let psbt_response = internal_create_psbt(psbt_req, &mut rgb_account, &mut resolver, None)
+        .await
+        .map_err(TransferError::Create)?;
+
+    let transfer_req = RgbTransferRequest {
+        rgb_invoice,
+        psbt: psbt_response.psbt,
+        terminal: psbt_response.terminal.clone(),
+        other_invoices,
+    };
+
+    let options = NewTransferOptions::default();
+    let RgbInternalTransferResponse {
+        consig_id,
+        consig,
+        psbt,
+        commit,
+        outpoint,
+        amount,
+        consigs,
+        txid,
+    } = internal_transfer_asset(
+        transfer_req,
+        options,
+        &mut stock,
+        &mut rgb_account,
+        &mut rgb_transfers,
+        &mut rgb_locks,
+    )
+    .await?;
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(TransferError::NoWatcher),
+    };
+
+    save_tap_commit_str(
+        &outpoint,
+        amount,
+        &commit,
+        &psbt_response.terminal,
+        &mut rgb_wallet,
+    );
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
+
+    let resp = RgbTransferResponse {
+        fee_rate: estimate_psbt_fee_rate(&psbt),
+        consig_id,
+        consig,
+        psbt,
+        commit,
+        consigs,
+        txid,
+    };
+
+    rgb_account.clone().update(&mut rgb_account_changes);
+    reconcile(&mut fork_wallet, rgb_account_changes.clone())
+        .map_err(|op| TransferError::WrongAutoMerge(op.to_string()))?;
+
+    store_local_account(sk, fork_wallet.save())
+        .await
+        .map_err(TransferError::IO)?;
+
+    store_stock_transfers(sk, stock, rgb_transfers)
+        .await
+        .map_err(TransferError::IO)?;
+    store_locks(sk, rgb_locks)
+        .await
+        .map_err(TransferError::IO)?;
+
+    Ok(resp)
+}
+
+/// Build an unsigned RGB transfer (air-gapped flow, step 1 of 3)
+///
+/// The PSBT keeps the tapret proprietary keys and can be signed offline with
+/// `sign_psbt_file` (step 2). The transfer is saved only when the signed PSBT
+/// is imported with `import_transfer_psbt` (step 3).
+pub async fn export_transfer_psbt(
+    sk: &str,
+    request: FullRgbTransferRequest,
+) -> Result<RgbTransferBundle, TransferError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(TransferError::Validation(errors));
+    }
+
+    let network = NETWORK.read().await.to_string();
+    let rgb_invoice = extract_rgb_invoice(&request.rgb_invoice, &network)
+        .map_err(|op| TransferError::WrongInvoice(op.to_string()))?;
+    let request = FullRgbTransferRequest {
+        rgb_invoice,
+        ..request
+    };
+
+    let (mut stock, mut rgb_account) = retrieve_stock_account(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
+
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(TransferError::NoWatcher),
+    };
+
+    let (asset_inputs, bitcoin_inputs, bitcoin_changes, fee_value) = prebuild_transfer_asset(
+        request.clone(),
+        &mut stock,
+        &mut rgb_wallet,
+        &mut resolver,
+        &rgb_locks,
+    )
+    .await?;
+
+    let FullRgbTransferRequest {
+        rgb_invoice,
+        change_terminal,
+        other_invoices,
+        close_method,
+        ..
+    } = request;
+
+    let psbt_req = PsbtRequest {
+        fee: PsbtFeeRequest::Value(fee_value),
+        asset_inputs,
+        bitcoin_inputs,
+        bitcoin_changes,
+        asset_descriptor_change: None,
+        asset_terminal_change: Some(change_terminal),
+        rbf: true,
+        close_method,
+        coin_selection: default!(),
+    };
+
+    let psbt_response = internal_create_psbt(psbt_req, &mut rgb_account, &mut resolver, None)
+        .await
+        .map_err(TransferError::Create)?;
+
+    let transfer_req = RgbTransferRequest {
+        rgb_invoice,
+        psbt: psbt_response.psbt,
+        terminal: psbt_response.terminal.clone(),
+        other_invoices,
+    };
+
+    let options = NewTransferOptions::default();
+    let (
+        RgbInternalTransferResponse {
+            consig_id,
+            consig,
+            psbt,
+            commit,
+            outpoint,
+            amount,
+            consigs,
+            txid,
+        },
+        RgbInternalSaveTransferRequest {
+            iface,
+            beneficiary,
+            utxos,
+            ..
+        },
+    ) = internal_build_transfer(transfer_req, options, &mut stock).await?;
+
+    // Spent outpoints are reserved while the PSBT is signed (`verify_transfers`
+    // releases them if the transfer is not imported before the lock expires)
+    let utc = chrono::Local::now().naive_utc().timestamp();
+    rgb_locks.lock(utxos, RgbLockOwner::Export(consig_id.clone()), utc);
+
+    // The stock keeps the secrets of the change seals
+    store_rgb_stock(sk, stock)
+        .await
+        .map_err(TransferError::IO)?;
+    store_locks(sk, rgb_locks)
+        .await
+        .map_err(TransferError::IO)?;
+
+    Ok(RgbTransferBundle {
+        fee_rate: estimate_psbt_fee_rate(&psbt),
+        consig_id,
+        consig,
+        consigs,
+        iface,
+        beneficiary,
+        psbt,
+        outpoint,
+        amount,
+        commit,
+        terminal: psbt_response.terminal,
+        txid,
+    })
+}
+
+/// Publish the signed PSBT and save the transfer (air-gapped flow, step 3 of 3)
+pub async fn import_transfer_psbt(
+    sk: &str,
+    request: RgbSignedTransferRequest,
+) -> Result<RgbTransferResponse, TransferError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(TransferError::Validation(errors));
+    }
+
+    let RgbSignedTransferRequest { psbt, bundle } = request;
+    let RgbTransferBundle {
+        consig_id,
+        consig,
+        consigs,
+        iface,
+        beneficiary,
+        psbt: unsigned_psbt,
+        outpoint,
+        amount,
+        commit,
+        terminal,
+        txid,
+        ..
+    } = bundle;
+
+    // The signatures do not change the txid (segwit), so the signed PSBT must
+    // spend and create the same outputs committed in the consignment
+    let signed_psbt =
+        Psbt::from_str(&psbt).map_err(|err| TransferError::WrongPsbt(err.to_string()))?;
+    let signed_txid = PartiallySignedTransaction::from(signed_psbt)
+        .unsigned_tx
+        .txid()
+        .to_string();
+    if signed_txid != txid {
+        return Err(TransferError::WrongPsbt(format!(
+            "expected transaction {txid}, found {signed_txid}"
+        )));
+    }
+
+    let RgbExtractTransfer {
+        txid: consig_txid, ..
+    } = prebuild_extract_transfer(&consig).map_err(TransferError::WrongSave)?;
+    if consig_txid.to_hex() != txid {
+        return Err(TransferError::WrongPsbt(format!(
+            "consignment {consig_id} is not anchored to transaction {txid}"
+        )));
+    }
+
+    let mut rgb_account = retrieve_account(sk).await.map_err(TransferError::IO)?;
+    let mut rgb_transfers = retrieve_transfers(sk).await.map_err(TransferError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(TransferError::NoWatcher),
+    };
+
+    publish_psbt_file(PublishPsbtRequest { psbt: psbt.clone() })
+        .await
+        .map_err(|err| TransferError::Publish(err.to_string()))?;
+
+    let unsigned_psbt =
+        Psbt::from_str(&unsigned_psbt).map_err(|err| TransferError::WrongPsbt(err.to_string()))?;
+    let internal_request = RgbInternalSaveTransferRequest::with(
+        consig_id.clone(),
+        consig.clone(),
+        beneficiary,
+        iface,
+        true,
+        Some(consigs.clone()),
+        Some(unsigned_psbt),
+    );
+
+    // The imported transfer takes over the reservations of its export
+    rgb_locks.release(&RgbLockOwner::Export(consig_id.clone()));
+    let utc = chrono::Local::now().naive_utc().timestamp();
+    rgb_locks.lock(
+        internal_request.utxos.clone(),
+        RgbLockOwner::Transfer(consig_id.clone()),
+        utc,
+    );
+
+    internal_save_transfer(internal_request, &mut rgb_transfers)
+        .await
+        .map_err(TransferError::WrongSave)?;
+
+    save_tap_commit_str(&outpoint, amount, &commit, &terminal, &mut rgb_wallet);
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
+
+    store_account(sk, rgb_account)
+        .await
+        .map_err(TransferError::IO)?;
+    store_transfers(sk, rgb_transfers)
+        .await
+        .map_err(TransferError::IO)?;
+    store_locks(sk, rgb_locks)
+        .await
+        .map_err(TransferError::IO)?;
+
+    Ok(RgbTransferResponse {
+        fee_rate: estimate_psbt_fee_rate(&psbt),
+        consig_id,
+        consig,
+        psbt,
+        commit,
+        consigs,
+        txid,
+    })
+}
+
+pub async fn transfer_asset(
+    sk: &str,
+    request: RgbTransferRequest,
+) -> Result<RgbTransferResponse, TransferError> {
+    let (mut stock, mut rgb_account, mut rgb_transfers) = retrieve_stock_account_transfers(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
+
+    let options = NewTransferOptions::default();
+    let RgbInternalTransferResponse {
+        consig_id,
+        consig,
+        psbt,
+        commit,
+        outpoint,
+        amount,
+        consigs,
+        txid,
+    } = internal_transfer_asset(
+        request.clone(),
+        options,
+        &mut stock,
+        &mut rgb_account,
+        &mut rgb_transfers,
+        &mut rgb_locks,
+    )
+    .await?;
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(TransferError::NoWatcher),
+    };
+
+    save_tap_commit_str(
+        &outpoint,
+        amount,
+        &commit,
+        &request.terminal,
+        &mut rgb_wallet,
+    );
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
+
+    let resp = RgbTransferResponse {
+        fee_rate: estimate_psbt_fee_rate(&psbt),
+        consig_id,
+        consig,
+        psbt,
+        commit,
+        consigs,
+        txid,
+    };
+
+    store_stock_account_transfers(sk, stock, rgb_account, rgb_transfers)
+        .await
+        .map_err(TransferError::IO)?;
+    store_locks(sk, rgb_locks)
+        .await
+        .map_err(TransferError::IO)?;
+
+    Ok(resp)
+}
+
+/// Destroy supply of an IFA contract (RGB20 issued with an inflation allowance)
+///
+/// Only the IFA schema has the `Burn` transition. Non-inflatable (NIA)
+/// contracts, and IFA contracts issued before burn was supported, fail with
+/// `NoBurnSupport`. The `burnedSupply` declared by the transition is checked
+/// by bitmask when the consignment is accepted, not by the schema script.
+pub async fn burn_asset(sk: &str, request: BurnRequest) -> Result<BurnResponse, TransferError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(TransferError::Validation(errors));
     }
 
-    if rgb_account.wallets.get(RGB_DEFAULT_NAME).is_none() {
-        return Err(PsbtError::NoWatcher);
+    let BurnRequest {
+        contract_id,
+        amount,
+        descriptor,
+        change_terminal,
+        fee,
+        close_method,
+    } = request;
+
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let (mut stock, mut rgb_account, mut rgb_transfers) = retrieve_stock_account_transfers(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(TransferError::NoWatcher),
+    };
+
+    let contr_id = ContractId::from_str(&contract_id).map_err(|_| TransferError::NoContract)?;
+    let iface_index = AssetType::RGB20 as u32;
+    let iface = stock
+        .iface_by_name(&tn!("RGB20"))
+        .map_err(|_| TransferError::NoIface)?;
+    let contract_iface = stock
+        .contract_iface(contr_id, iface.iface_id())
+        .map_err(|_| TransferError::NoContract)?;
+    if contract_iface.iface.transition_type(&tn!("Burn")).is_none() {
+        return Err(TransferError::NoBurnSupport(contract_id));
     }
 
-    let PsbtRequest {
+    prefetch_resolver_utxos(
+        iface_index,
+        &mut rgb_wallet,
+        &mut resolver,
+        Some(RGB_DEFAULT_FETCH_LIMIT),
+    )
+    .await;
+    prefetch_resolver_allocations(contract_iface, &mut resolver, true).await;
+    sync_wallet(iface_index, &mut rgb_wallet, &mut resolver);
+
+    let ContractBoilerplate { precision, .. } =
+        export_boilerplate(contr_id, &mut stock).map_err(|_| TransferError::NoContract)?;
+    let amount = ContractAmount::with(amount, precision).to_value();
+
+    let WatcherDetail {
+        allocations,
+        burned_supply,
+        ..
+    } = contract_allocations(
+        contr_id,
+        iface_index,
+        &mut rgb_wallet,
+        &mut stock,
+        &mut resolver,
+    )
+    .map_err(|_| TransferError::NoContract)?;
+
+    let wildcard_terminal = "/*/*";
+    let terminal_step = format!("/{iface_index}/*");
+    let universal_desc = SecretString(
+        descriptor
+            .to_string()
+            .replace(&terminal_step, wildcard_terminal),
+    );
+
+    // Select the allocations needed to cover the burned amount (outpoints reserved
+    // by pending transfers and open offers cannot be spent)
+    let mut total = 0;
+    let mut asset_inputs = vec![];
+    for alloc in allocations
+        .into_iter()
+        .filter(|alloc| alloc.is_mine && !alloc.is_spent && !rgb_locks.is_locked(&alloc.utxo))
+    {
+        if total >= amount {
+            break;
+        }
+
+        if let AllocationValue::Value(value) = alloc.value {
+            total += value;
+            asset_inputs.push(PsbtInputRequest {
+                descriptor: universal_desc.clone(),
+                utxo: alloc.utxo,
+                utxo_terminal: alloc.derivation,
+                tapret: None,
+                sigh_hash: None,
+            });
+        }
+    }
+
+    if total < amount {
+        return Err(TransferError::InsufficientAssets(amount, total));
+    }
+
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
+
+    let psbt_req = PsbtRequest {
+        fee,
         asset_inputs,
-        asset_terminal_change,
-        bitcoin_inputs,
-        bitcoin_changes,
+        bitcoin_inputs: vec![],
+        bitcoin_changes: vec![],
+        asset_descriptor_change: None,
+        asset_terminal_change: Some(change_terminal.clone()),
+        rbf: true,
+        close_method,
+        coin_selection: default!(),
+    };
+
+    let PsbtResponse { psbt, .. } =
+        internal_create_psbt(psbt_req, &mut rgb_account, &mut resolver, None)
+            .await
+            .map_err(TransferError::Create)?;
+
+    let (psbt, transfer) = burn_rgb_asset(&contract_id, "RGB20", amount, psbt, &mut stock)
+        .map_err(TransferError::Pay)?;
+
+    let (outpoint, change_amount, commit) =
+        extract_output_commit(psbt.clone()).map_err(TransferError::Commitment)?;
+
+    let consig_id = transfer.bindle_id().to_string();
+    let consig = transfer
+        .to_strict_serialized::<{ U32 }>()
+        .map_err(|err| TransferError::WrongConsig(err.to_string()))?
+        .to_hex();
+    let commit = commit.to_hex();
+    let psbt_hex = psbt.to_string();
+
+    let internal_request = RgbInternalSaveTransferRequest::with(
+        consig_id.clone(),
+        consig.clone(),
+        String::new(),
+        "RGB20".to_string(),
+        true,
+        None,
+        Some(psbt),
+    );
+
+    // Spent outpoints are reserved until the transfer is confirmed (or removed)
+    let utc = chrono::Local::now().naive_utc().timestamp();
+    rgb_locks.lock(
+        internal_request.utxos.clone(),
+        RgbLockOwner::Transfer(consig_id.clone()),
+        utc,
+    );
+
+    let txid = internal_save_transfer(internal_request, &mut rgb_transfers)
+        .await
+        .map_err(TransferError::WrongSave)?;
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(TransferError::NoWatcher),
+    };
+
+    save_tap_commit_str(
+        &outpoint.to_string(),
+        change_amount,
+        &commit,
+        &change_terminal,
+        &mut rgb_wallet,
+    );
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
+
+    let ContractResponse { supply, .. } =
+        export_contract(contr_id, &mut stock, &mut resolver, &mut None)
+            .map_err(TransferError::Export)?;
+
+    store_stock_account_transfers(sk, stock, rgb_account, rgb_transfers)
+        .await
+        .map_err(TransferError::IO)?;
+    store_locks(sk, rgb_locks)
+        .await
+        .map_err(TransferError::IO)?;
+
+    let burned_supply = burned_supply + amount;
+    Ok(BurnResponse {
+        fee_rate: estimate_psbt_fee_rate(&psbt_hex),
+        contract_id,
+        consig_id,
+        consig,
+        psbt: psbt_hex,
+        commit,
+        txid: txid.to_hex(),
+        burned_supply,
+        circulating_supply: supply.saturating_sub(burned_supply),
+    })
+}
+
+pub async fn consolidate_allocations(
+    sk: &str,
+    request: ConsolidateRequest,
+) -> Result<ConsolidateResponse, TransferError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(TransferError::Validation(errors));
+    }
+
+    let ConsolidateRequest {
+        contract_ids,
+        descriptor,
+        change_terminal,
         fee,
-        ..
+        close_method,
     } = request;
 
-    let mut all_inputs = asset_inputs.clone();
-    all_inputs.extend(bitcoin_inputs.clone());
-    for input_utxo in all_inputs.clone() {
-        prefetch_resolver_psbt(&input_utxo.utxo, resolver).await;
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let (mut stock, mut rgb_account) = retrieve_stock_account(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(TransferError::NoWatcher),
+    };
+
+    let iface_index = AssetType::RGB20 as u32;
+    prefetch_resolver_utxos(
+        iface_index,
+        &mut rgb_wallet,
+        &mut resolver,
+        Some(RGB_DEFAULT_FETCH_LIMIT),
+    )
+    .await;
+    sync_wallet(iface_index, &mut rgb_wallet, &mut resolver);
+
+    let iface = stock
+        .iface_by_name(&tn!("RGB20"))
+        .map_err(|_| TransferError::NoIface)?;
+
+    // Sum all allocations owned by the wallet (by contract), except the ones
+    // reserved by pending transfers or open offers
+    let mut utxos = BTreeSet::new();
+    let mut amounts = BTreeMap::new();
+    for contract_id in &contract_ids {
+        let contr_id = ContractId::from_str(contract_id).map_err(|_| TransferError::NoContract)?;
+        let contract_iface = stock
+            .contract_iface(contr_id, iface.iface_id())
+            .map_err(|_| TransferError::NoContract)?;
+        prefetch_resolver_allocations(contract_iface, &mut resolver, true).await;
+
+        let WatcherDetail { allocations, .. } = contract_allocations(
+            contr_id,
+            iface_index,
+            &mut rgb_wallet,
+            &mut stock,
+            &mut resolver,
+        )
+        .map_err(|_| TransferError::NoContract)?;
+
+        let mut total = 0u64;
+        let mut count = 0;
+        for alloc in allocations
+            .into_iter()
+            .filter(|alloc| alloc.is_mine && !alloc.is_spent && !rgb_locks.is_locked(&alloc.utxo))
+        {
+            if let AllocationValue::Value(value) = alloc.value {
+                total = match total.checked_add(value) {
+                    Some(total) => total,
+                    _ => {
+                        let mut errors = BTreeMap::new();
+                        errors.insert(
+                            "contract_ids".to_string(),
+                            format!("allocation amount overflow in {contract_id}"),
+                        );
+                        return Err(TransferError::Validation(errors));
+                    }
+                };
+                count += 1;
+                utxos.insert(alloc.utxo);
+            }
+        }
+
+        if total == 0 {
+            return Err(TransferError::NothingToConsolidate(count));
+        }
+        amounts.insert(contract_id.to_owned(), total);
+    }
+
+    if utxos.len() < 2 {
+        return Err(TransferError::NothingToConsolidate(utxos.len()));
     }
 
-    // Retrieve transaction fee
-    let fee = match fee {
-        PsbtFeeRequest::Value(fee) => fee,
-        PsbtFeeRequest::FeeRate(_) => return Err(PsbtError::NoFeeRate),
-    };
+    // The state of each contract is paid to a new output of the watcher (witness-vout
+    // invoices), reserved in the wallet so it is not handed out again before the
+    // transfer is mined
+    let network = NETWORK.read().await.to_string();
+    let address_network =
+        Network::from_str(&network).map_err(|op| TransferError::WrongNetwork(op.to_string()))?;
+    let mut rgb_reservations = retrieve_reservations(sk).await.map_err(TransferError::IO)?;
+    let mut addresses = BTreeMap::new();
+    for contract_id in amounts.keys() {
+        let address = reserve_address(
+            iface_index,
+            &rgb_wallet,
+            &mut rgb_reservations,
+            AddressNetwork::from(address_network),
+        )
+        .map_err(|op| TransferError::WrongWatcher(op.to_string()))?
+        .address
+        .to_string();
+        addresses.insert(contract_id.to_owned(), address);
+    }
 
-    let options = options.unwrap_or_default();
-    let wallet = rgb_account.wallets.get(RGB_DEFAULT_NAME);
-    let (mut psbt_file, change_terminal) = create_rgb_psbt(
-        all_inputs,
-        bitcoin_changes,
-        fee,
-        asset_terminal_change,
-        wallet.cloned(),
-        resolver,
-        options.clone(),
-    )
-    .map_err(PsbtError::Create)?;
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
+    store_account(sk, rgb_account)
+        .await
+        .map_err(TransferError::IO)?;
+    store_reservations(sk, rgb_reservations)
+        .await
+        .map_err(TransferError::IO)?;
 
-    if options.set_tapret {
-        let pos = (psbt_file.outputs.len() - 1) as u16;
-        psbt_file = set_tapret_output(psbt_file, pos).map_err(PsbtError::Create)?;
+    let mut invoices = vec![];
+    for (contract_id, amount) in &amounts {
+        let invoice = create_rgb_witness_invoice(
+            contract_id,
+            "RGB20",
+            *amount,
+            &addresses[contract_id],
+            &network,
+            HashMap::new(),
+            NewInvoiceOptions::default(),
+            &mut stock,
+        )
+        .map_err(|op| TransferError::WrongInvoice(op.to_string()))?;
+        invoices.push(invoice.to_string());
     }
 
-    let psbt = PsbtResponse {
-        psbt: Serialize::serialize(&psbt_file).to_hex(),
-        terminal: change_terminal,
+    let rgb_invoice = invoices.remove(0);
+    let contract_id = amounts
+        .keys()
+        .next()
+        .cloned()
+        .expect("at least one contract is consolidated");
+    let request = FullRgbTransferRequest {
+        contract_id,
+        iface: "RGB20".to_string(),
+        rgb_invoice,
+        descriptor,
+        change_terminal,
+        fee,
+        bitcoin_changes: vec![],
+        other_invoices: invoices,
+        coin_selection: default!(),
+        close_method,
     };
 
-    Ok(psbt)
-}
-
-#[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
-#[display(doc_comments)]
-pub enum TransferError {
-    /// Some request data is missing. {0:?}
-    Validation(BTreeMap<String, String>),
-    /// Retrieve I/O or connectivity error. {0:?}
-    IO(RgbPersistenceError),
-    /// Watcher is required in this operation. Please, create watcher.
-    NoWatcher,
-    /// Contract is required in this operation. Please, import or issue a Contract.
-    NoContract,
-    /// Iface is required in this operation. Please, use the correct iface contract.
-    NoIface,
-    /// FeeRate is supported in this operation. Please, use the absolute fee value.
-    NoFeeRate,
-    /// Insufficient funds (expected: {input} sats / current: {output} sats)
-    Inflation {
-        /// Amount spent: input amounts
-        input: u64,
+    let RgbTransferResponse {
+        consig_id,
+        consig,
+        psbt,
+        commit,
+        consigs,
+        txid,
+        fee_rate,
+    } = full_transfer_asset(sk, request).await?;
 
-        /// Amount sent: sum of output value + transaction fee
-        output: u64,
-    },
-    /// Occurs an error in create step. {0}
-    Create(PsbtError),
-    /// Occurs an error in estimate fee step. {0}
-    Estimate(EstimateFeeError),
-    /// Occurs an error in commitment step. {0}
-    Commitment(DbcPsbtError),
-    /// Occurs an error in payment step. {0}
-    Pay(NewPaymentError),
-    /// Occurs an error in accept step. {0}
-    Accept(AcceptTransferError),
-    /// Auto merge fail in this opration
-    WrongAutoMerge(String),
-    /// Consignment cannot be encoded.
-    WrongConsig(String),
-    /// Rgb Invoice cannot be decoded. {0}
-    WrongInvoice(String),
-    /// Bitcoin network be decoded. {0}
-    WrongNetwork(String),
-    /// Occurs an error in swap step. {0}
-    WrongSwap(RgbOfferErrors),
-    /// Occurs an error in save transfer step. {0}
-    WrongSave(SaveTransferError),
-    /// Occurs an error in export step. {0}
-    Export(ExportContractError),
-    /// Occurs an error in retrieve transfers step. {0}
-    Save(SaveTransferError),
-    /// Occurs an error in retrieve proxy step. {0}
-    Proxy(ProxyError),
+    Ok(ConsolidateResponse {
+        addresses,
+        utxos: utxos.len(),
+        amounts,
+        consig_id,
+        consig,
+        consigs,
+        psbt,
+        commit,
+        txid,
+        fee_rate,
+    })
 }
 
-pub async fn full_transfer_asset(
+pub async fn split_allocation(
     sk: &str,
-    request: FullRgbTransferRequest,
-) -> Result<RgbTransferResponse, TransferError> {
+    request: SplitRequest,
+) -> Result<SplitResponse, TransferError> {
     if let Err(err) = request.validate(&RGBContext::default()) {
         let errors = err
             .iter()
@@ -668,39 +2287,187 @@ pub async fn full_transfer_asset(
         return Err(TransferError::Validation(errors));
     }
 
-    let (mut stock, mut rgb_transfers) = retrieve_stock_transfers(sk)
-        .await
-        .map_err(TransferError::IO)?;
-
-    let local_rgb_account = retrieve_local_account(sk)
-        .await
-        .map_err(TransferError::IO)?;
-
-    let LocalRgbAccount {
-        doc,
-        mut rgb_account,
-    } = local_rgb_account;
-    let mut fork_wallet = automerge::AutoCommit::load(&doc)
-        .map_err(|op| TransferError::WrongAutoMerge(op.to_string()))?;
-    let mut rgb_account_changes = RawRgbAccount::from(rgb_account.clone());
+    let SplitRequest {
+        contract_id,
+        utxo,
+        amounts,
+        descriptor,
+        change_terminal,
+        fee,
+        close_method,
+    } = request;
 
     let mut resolver = ExplorerResolver {
         explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
         ..default!()
     };
 
+    let (mut stock, mut rgb_account, mut rgb_transfers) = retrieve_stock_account_transfers(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
+    if rgb_locks.is_locked(&utxo) {
+        return Err(TransferError::Validation(BTreeMap::from([(
+            "utxo".to_string(),
+            format!("the allocation {utxo} is locked by a pending transfer or open offer"),
+        )])));
+    }
+
     let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
         Some(rgb_wallet) => rgb_wallet.to_owned(),
         _ => return Err(TransferError::NoWatcher),
     };
 
-    let (asset_inputs, bitcoin_inputs, bitcoin_changes, fee_value) =
-        prebuild_transfer_asset(request.clone(), &mut stock, &mut rgb_wallet, &mut resolver)
-            .await?;
+    let contr_id = ContractId::from_str(&contract_id).map_err(|_| TransferError::NoContract)?;
+    let iface_index = AssetType::RGB20 as u32;
+    let iface = stock
+        .iface_by_name(&tn!("RGB20"))
+        .map_err(|_| TransferError::NoIface)?;
+    let contract_iface = stock
+        .contract_iface(contr_id, iface.iface_id())
+        .map_err(|_| TransferError::NoContract)?;
+
+    prefetch_resolver_utxos(
+        iface_index,
+        &mut rgb_wallet,
+        &mut resolver,
+        Some(RGB_DEFAULT_FETCH_LIMIT),
+    )
+    .await;
+    prefetch_resolver_allocations(contract_iface, &mut resolver, true).await;
+    sync_wallet(iface_index, &mut rgb_wallet, &mut resolver);
+
+    let WatcherDetail { allocations, .. } = contract_allocations(
+        contr_id,
+        iface_index,
+        &mut rgb_wallet,
+        &mut stock,
+        &mut resolver,
+    )
+    .map_err(|_| TransferError::NoContract)?;
+
+    // The other allocations of the contract are excluded from the coin selection
+    let mut allocation_value = None;
+    let mut other_utxos = vec![];
+    for alloc in allocations
+        .into_iter()
+        .filter(|alloc| alloc.is_mine && !alloc.is_spent)
+    {
+        if alloc.utxo != utxo {
+            other_utxos.push(alloc.utxo);
+        } else if let AllocationValue::Value(value) = alloc.value {
+            let current: u64 = allocation_value.unwrap_or_default();
+            match current.checked_add(value) {
+                Some(sum) => allocation_value = Some(sum),
+                _ => {
+                    let mut errors = BTreeMap::new();
+                    errors.insert("utxo".to_string(), "allocation amount overflow".to_string());
+                    return Err(TransferError::Validation(errors));
+                }
+            }
+        }
+    }
+
+    let allocation_value = match allocation_value {
+        Some(allocation_value) => allocation_value,
+        _ => {
+            let mut errors = BTreeMap::new();
+            errors.insert("utxo".to_string(), "allocation not found".to_string());
+            return Err(TransferError::Validation(errors));
+        }
+    };
+
+    let total = match amounts
+        .iter()
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+    {
+        Some(total) => total,
+        _ => {
+            let mut errors = BTreeMap::new();
+            errors.insert("amounts".to_string(), "total amount overflow".to_string());
+            return Err(TransferError::Validation(errors));
+        }
+    };
+    if amounts.iter().any(|amount| *amount == 0) || total > allocation_value {
+        return Err(TransferError::InsufficientAssets(total, allocation_value));
+    }
+
+    // Each new allocation is paid to a new output of the watcher (witness-vout invoices),
+    // reserved in the wallet so it is not handed out again before the transfer is mined
+    let network = NETWORK.read().await.to_string();
+    let address_network =
+        Network::from_str(&network).map_err(|op| TransferError::WrongNetwork(op.to_string()))?;
+
+    let mut rgb_reservations = retrieve_reservations(sk).await.map_err(TransferError::IO)?;
+    let mut invoices = vec![];
+    let mut new_allocations = vec![];
+    for amount in amounts {
+        let address = reserve_address(
+            iface_index,
+            &rgb_wallet,
+            &mut rgb_reservations,
+            AddressNetwork::from(address_network),
+        )
+        .map_err(|op| TransferError::WrongWatcher(op.to_string()))?
+        .address
+        .to_string();
+        let invoice = create_rgb_witness_invoice(
+            &contract_id,
+            "RGB20",
+            amount,
+            &address,
+            &network,
+            HashMap::new(),
+            NewInvoiceOptions::default(),
+            &mut stock,
+        )
+        .map_err(|op| TransferError::WrongInvoice(op.to_string()))?;
+        invoices.push(invoice.to_string());
+        new_allocations.push(format!("{address}:{amount}"));
+    }
+
+    let rgb_invoice = invoices.remove(0);
+    let request = FullRgbTransferRequest {
+        contract_id,
+        iface: "RGB20".to_string(),
+        rgb_invoice,
+        descriptor,
+        change_terminal,
+        fee,
+        bitcoin_changes: vec![],
+        other_invoices: invoices,
+        coin_selection: default!(),
+        close_method,
+    };
+
+    // Only the requested UTXO can be selected as asset input (the bitcoin inputs
+    // paying the fee are selected from the wallet)
+    let mut selection_locks = rgb_locks.clone();
+    let utc = chrono::Local::now().naive_utc().timestamp();
+    selection_locks.lock(
+        other_utxos,
+        RgbLockOwner::Transfer(format!("split:{utxo}")),
+        utc,
+    );
+
+    let (asset_inputs, bitcoin_inputs, bitcoin_changes, fee_value) = prebuild_transfer_asset(
+        request.clone(),
+        &mut stock,
+        &mut rgb_wallet,
+        &mut resolver,
+        &selection_locks,
+    )
+    .await?;
+
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
 
     let FullRgbTransferRequest {
         rgb_invoice,
         change_terminal,
+        other_invoices,
+        close_method,
         ..
     } = request;
 
@@ -712,6 +2479,8 @@ pub async fn full_transfer_asset(
         asset_descriptor_change: None,
         asset_terminal_change: Some(change_terminal),
         rbf: true,
+        close_method,
+        coin_selection: default!(),
     };
 
     let psbt_response = internal_create_psbt(psbt_req, &mut rgb_account, &mut resolver, None)
@@ -722,9 +2491,9 @@ pub async fn full_transfer_asset(
         rgb_invoice,
         psbt: psbt_response.psbt,
         terminal: psbt_response.terminal.clone(),
+        other_invoices,
     };
 
-    let options = NewTransferOptions::default();
     let RgbInternalTransferResponse {
         consig_id,
         consig,
@@ -732,14 +2501,15 @@ pub async fn full_transfer_asset(
         commit,
         outpoint,
         amount,
+        consigs,
         txid,
-        ..
     } = internal_transfer_asset(
         transfer_req,
-        options,
+        NewTransferOptions::default(),
         &mut stock,
         &mut rgb_account,
         &mut rgb_transfers,
+        &mut rgb_locks,
     )
     .await?;
 
@@ -759,85 +2529,26 @@ pub async fn full_transfer_asset(
         .wallets
         .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
 
-    let resp = RgbTransferResponse {
-        consig_id,
-        consig,
-        psbt,
-        commit,
-        txid,
-    };
-
-    rgb_account.clone().update(&mut rgb_account_changes);
-    reconcile(&mut fork_wallet, rgb_account_changes.clone())
-        .map_err(|op| TransferError::WrongAutoMerge(op.to_string()))?;
-
-    store_local_account(sk, fork_wallet.save())
+    store_stock_account_transfers(sk, stock, rgb_account, rgb_transfers)
         .await
         .map_err(TransferError::IO)?;
-
-    store_stock_transfers(sk, stock, rgb_transfers)
+    store_locks(sk, rgb_locks)
         .await
         .map_err(TransferError::IO)?;
-
-    Ok(resp)
-}
-
-pub async fn transfer_asset(
-    sk: &str,
-    request: RgbTransferRequest,
-) -> Result<RgbTransferResponse, TransferError> {
-    let (mut stock, mut rgb_account, mut rgb_transfers) = retrieve_stock_account_transfers(sk)
+    store_reservations(sk, rgb_reservations)
         .await
         .map_err(TransferError::IO)?;
 
-    let options = NewTransferOptions::default();
-    let RgbInternalTransferResponse {
-        consig_id,
-        consig,
-        psbt,
-        commit,
-        outpoint,
-        amount,
-        txid,
-        ..
-    } = internal_transfer_asset(
-        request.clone(),
-        options,
-        &mut stock,
-        &mut rgb_account,
-        &mut rgb_transfers,
-    )
-    .await?;
-
-    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
-        Some(rgb_wallet) => rgb_wallet.to_owned(),
-        _ => return Err(TransferError::NoWatcher),
-    };
-
-    save_tap_commit_str(
-        &outpoint,
-        amount,
-        &commit,
-        &request.terminal,
-        &mut rgb_wallet,
-    );
-    rgb_account
-        .wallets
-        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
-
-    let resp = RgbTransferResponse {
+    Ok(SplitResponse {
+        fee_rate: estimate_psbt_fee_rate(&psbt),
+        allocations: new_allocations,
         consig_id,
         consig,
+        consigs,
         psbt,
         commit,
         txid,
-    };
-
-    store_stock_account_transfers(sk, stock, rgb_account, rgb_transfers)
-        .await
-        .map_err(TransferError::IO)?;
-
-    Ok(resp)
+    })
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
@@ -871,6 +2582,8 @@ pub enum RgbSwapError {
     Create(PsbtError),
     /// Occurs an error in estimate fee step. {0}
     Estimate(EstimateFeeError),
+    /// The explorer has no fee estimate for the target of {0} blocks
+    NoFeeEstimate(u16),
     /// Occurs an error in publish offer step. {0}
     Marketplace(RgbOfferErrors),
     /// Occurs an error in invoice step. {0}
@@ -922,6 +2635,7 @@ pub async fn create_seller_offer(
 
     let (mut stock, mut rgb_account) =
         retrieve_stock_account(sk).await.map_err(RgbSwapError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(RgbSwapError::IO)?;
 
     let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
         Some(rgb_wallet) => rgb_wallet.to_owned(),
@@ -936,6 +2650,7 @@ pub async fn create_seller_offer(
         expire_at,
         presig,
         change_terminal,
+        close_method,
         ..
     } = request.clone();
 
@@ -948,7 +2663,14 @@ pub async fn create_seller_offer(
         export_boilerplate(contr_id, &mut stock).map_err(|_| RgbSwapError::NoContract)?;
 
     let (allocations, asset_inputs, bitcoin_inputs, mut bitcoin_changes, change_value) =
-        prebuild_seller_swap(request, &mut stock, &mut rgb_wallet, &mut resolver).await?;
+        prebuild_seller_swap(
+            request,
+            &mut stock,
+            &mut rgb_wallet,
+            &mut resolver,
+            &rgb_locks,
+        )
+        .await?;
 
     rgb_account
         .wallets
@@ -956,6 +2678,12 @@ pub async fn create_seller_offer(
 
     bitcoin_changes.push(format!("{seller_address}:{bitcoin_price}"));
 
+    let offer_utxos: Vec<String> = asset_inputs
+        .iter()
+        .map(|x| x.utxo.clone())
+        .chain(bitcoin_inputs.iter().map(|x| x.utxo.clone()))
+        .collect();
+
     let psbt_req = PsbtRequest {
         fee: PsbtFeeRequest::Value(0),
         asset_inputs,
@@ -964,6 +2692,8 @@ pub async fn create_seller_offer(
         asset_descriptor_change: None,
         asset_terminal_change: Some(change_terminal.clone()),
         rbf: true,
+        close_method,
+        coin_selection: default!(),
     };
 
     let options = NewPsbtOptions::set_inflaction(change_value);
@@ -1014,6 +2744,13 @@ pub async fn create_seller_offer(
         .await
         .map_err(RgbSwapError::IO)?;
 
+    rgb_locks.lock(
+        offer_utxos,
+        RgbLockOwner::Offer(new_offer.offer_id.clone()),
+        chrono::Local::now().naive_utc().timestamp(),
+    );
+    store_locks(sk, rgb_locks).await.map_err(RgbSwapError::IO)?;
+
     store_stock_account(sk, stock, rgb_account)
         .await
         .map_err(RgbSwapError::IO)?;
@@ -1073,6 +2810,60 @@ pub async fn update_seller_offer(
     })
 }
 
+pub async fn cancel_seller_offer(
+    sk: &str,
+    request: RgbOfferCancelRequest,
+) -> Result<RgbOfferCancelResponse, RgbSwapError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(RgbSwapError::Validation(errors));
+    }
+
+    let RgbOfferCancelRequest {
+        contract_id,
+        offer_id,
+    } = request;
+
+    let mut cancelled = false;
+    let mut my_offers = retrieve_offers(sk).await.map_err(RgbSwapError::IO)?;
+    if let Some(offers) = my_offers.offers.get(&contract_id.clone()) {
+        let mut current_offers = offers.to_owned();
+        if let Some(position) = current_offers
+            .iter()
+            .position(|x| x.offer_id == offer_id && x.offer_status == RgbOrderStatus::Open)
+        {
+            let offer = current_offers.remove(position);
+            my_offers.offers.insert(contract_id.clone(), current_offers);
+
+            cancelled = true;
+            store_offers(sk, my_offers)
+                .await
+                .map_err(RgbSwapError::IO)?;
+
+            remove_public_offers(vec![offer])
+                .await
+                .map_err(RgbSwapError::Marketplace)?;
+        }
+    }
+
+    let mut released = vec![];
+    if cancelled {
+        let mut rgb_locks = retrieve_locks(sk).await.map_err(RgbSwapError::IO)?;
+        released = rgb_locks.release(&RgbLockOwner::Offer(offer_id.clone()));
+        store_locks(sk, rgb_locks).await.map_err(RgbSwapError::IO)?;
+    }
+
+    Ok(RgbOfferCancelResponse {
+        contract_id,
+        offer_id,
+        cancelled,
+        released,
+    })
+}
+
 pub async fn create_buyer_bid(
     sk: &str,
     request: RgbBidRequest,
@@ -1101,6 +2892,7 @@ pub async fn create_buyer_bid(
     let RgbBidRequest {
         offer_id,
         change_terminal,
+        close_method,
         ..
     } = request.clone();
 
@@ -1140,6 +2932,8 @@ pub async fn create_buyer_bid(
         asset_descriptor_change: None,
         asset_terminal_change: Some(change_terminal.clone()),
         rbf: true,
+        close_method,
+        coin_selection: default!(),
     };
 
     let options = NewPsbtOptions {
@@ -1202,8 +2996,10 @@ pub async fn create_buyer_bid(
         iface,
         contract_id: contract_id.to_string(),
         amount: invoice_amount.to_string(),
-        seal: format!("tapret1st:{buyer_outpoint}"),
+        seal: format!("{}:{buyer_outpoint}", CloseMethod::from(close_method)),
         params: HashMap::new(),
+        expiry: None,
+        transports: Some(vec![]),
     };
     let invoice = internal_create_invoice(invoice_req, &mut stock)
         .await
@@ -1213,6 +3009,7 @@ pub async fn create_buyer_bid(
     new_bid.buyer_invoice = invoice.clone();
 
     let resp = RgbBidResponse {
+        fee_rate: estimate_psbt_fee_rate(&swap_psbt),
         bid_id,
         offer_id,
         invoice,
@@ -1253,6 +3050,7 @@ pub async fn create_swap_transfer(
     let (mut stock, mut rgb_account, mut rgb_transfers) = retrieve_stock_account_transfers(sk)
         .await
         .map_err(RgbSwapError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(RgbSwapError::IO)?;
 
     let RgbSwapRequest {
         offer_id,
@@ -1290,6 +3088,7 @@ pub async fn create_swap_transfer(
     let change_terminal = match iface.to_uppercase().as_str() {
         "RGB20" => "/20/1",
         "RGB21" => "/21/1",
+        "RGB25" => "/25/1",
         _ => "/10/1",
     };
 
@@ -1297,6 +3096,7 @@ pub async fn create_swap_transfer(
         psbt: swap_psbt.clone(),
         rgb_invoice: buyer_invoice.to_string(),
         terminal: change_terminal.to_string(),
+        other_invoices: vec![],
     };
 
     let params = NewTransferOptions {
@@ -1305,6 +3105,9 @@ pub async fn create_swap_transfer(
         ..default!()
     };
 
+    // The outpoints reserved by the offer are now reserved by the swap transfer
+    rgb_locks.release(&RgbLockOwner::Offer(offer_id.clone()));
+
     let RgbInternalTransferResponse {
         consig_id,
         psbt: final_psbt,
@@ -1319,6 +3122,7 @@ pub async fn create_swap_transfer(
         &mut stock,
         &mut rgb_account,
         &mut rgb_transfers,
+        &mut rgb_locks,
     )
     .await
     .map_err(RgbSwapError::Transfer)?;
@@ -1375,6 +3179,7 @@ pub async fn create_swap_transfer(
     store_stock_account_transfers(sk, stock, rgb_account, rgb_transfers)
         .await
         .map_err(RgbSwapError::IO)?;
+    store_locks(sk, rgb_locks).await.map_err(RgbSwapError::IO)?;
 
     Ok(RgbSwapResponse {
         consig_id,
@@ -1405,7 +3210,38 @@ async fn internal_transfer_asset(
     stock: &mut Stock,
     rgb_account: &mut RgbAccountV1,
     rgb_transfers: &mut RgbTransfersV1,
+    rgb_locks: &mut RgbLocks,
 ) -> Result<RgbInternalTransferResponse, TransferError> {
+    if rgb_account.wallets.get(RGB_DEFAULT_NAME).is_none() {
+        return Err(TransferError::NoWatcher);
+    }
+
+    let (resp, internal_request) = internal_build_transfer(request, options, stock).await?;
+
+    // Spent outpoints are reserved until the transfer is confirmed (or removed)
+    let utc = chrono::Local::now().naive_utc().timestamp();
+    rgb_locks.lock(
+        internal_request.utxos.clone(),
+        RgbLockOwner::Transfer(resp.consig_id.clone()),
+        utc,
+    );
+
+    let txid = internal_save_transfer(internal_request, rgb_transfers)
+        .await
+        .map_err(TransferError::WrongSave)?;
+
+    Ok(RgbInternalTransferResponse {
+        txid: txid.to_hex(),
+        ..resp
+    })
+}
+
+/// Pay the invoices with the PSBT, without saving the transfer
+async fn internal_build_transfer(
+    request: RgbTransferRequest,
+    options: NewTransferOptions,
+    stock: &mut Stock,
+) -> Result<(RgbInternalTransferResponse, RgbInternalSaveTransferRequest), TransferError> {
     let network = NETWORK.read().await.to_string();
     let context = RGBContext::with(&network);
 
@@ -1417,16 +3253,44 @@ async fn internal_transfer_asset(
         return Err(TransferError::Validation(errors));
     }
 
-    if rgb_account.wallets.get(RGB_DEFAULT_NAME).is_none() {
-        return Err(TransferError::NoWatcher);
-    }
-
     let RgbTransferRequest {
         rgb_invoice: invoice,
         psbt,
+        other_invoices,
         ..
     } = request;
 
+    let rgb_invoice = RgbInvoice::from_str(&invoice)
+        .map_err(|err| TransferError::WrongInvoice(err.to_string()))?;
+    let contract_id = match rgb_invoice.contract {
+        Some(contract_id) => contract_id,
+        _ => return Err(TransferError::NoContract),
+    };
+
+    // Other invoices are paid in the same transaction (one consignment per contract
+    // and beneficiary)
+    let mut options = options;
+    let mut payments = BTreeSet::from([format!("{contract_id}:{}", rgb_invoice.beneficiary)]);
+    for other_invoice in other_invoices {
+        let other_invoice = extract_rgb_invoice(&other_invoice, &network)
+            .map_err(|err| TransferError::WrongInvoice(err.to_string()))?;
+        let other_invoice = RgbInvoice::from_str(&other_invoice)
+            .map_err(|err| TransferError::WrongInvoice(err.to_string()))?;
+
+        let other_contract_id = other_invoice.contract.unwrap_or(contract_id);
+        let payment = format!("{other_contract_id}:{}", other_invoice.beneficiary);
+        if !payments.insert(payment.clone()) {
+            let mut errors = BTreeMap::new();
+            errors.insert(
+                "other_invoices".to_string(),
+                format!("duplicated payment to {payment}"),
+            );
+            return Err(TransferError::Validation(errors));
+        }
+        options.other_invoices.push(other_invoice);
+        options.strict = true;
+    }
+
     let (psbt, mut transfers) =
         pay_invoice(invoice.clone(), psbt, options.clone(), stock).map_err(TransferError::Pay)?;
     let (outpoint, amount, commit) =
@@ -1438,9 +3302,6 @@ async fn internal_transfer_asset(
         .to_strict_serialized::<{ U32 }>()
         .map_err(|err| TransferError::WrongConsig(err.to_string()))?;
 
-    let rgb_invoice = RgbInvoice::from_str(&invoice)
-        .map_err(|err| TransferError::WrongInvoice(err.to_string()))?;
-
     let consig = consig.to_hex();
     let commit = commit.to_hex();
     let psbt_hex = psbt.to_string();
@@ -1454,9 +3315,16 @@ async fn internal_transfer_asset(
             .map_err(|err| TransferError::WrongConsig(err.to_string()))?;
 
         let current_transfer = current_transfer.to_hex();
-        consigs.insert(invoice.beneficiary.to_string(), current_transfer);
+        let other_contract_id = invoice.contract.unwrap_or(contract_id);
+        consigs.insert(
+            format!("{other_contract_id}:{}", invoice.beneficiary),
+            current_transfer,
+        );
     }
 
+    let RgbExtractTransfer { txid, .. } =
+        prebuild_extract_transfer(&consig).map_err(TransferError::WrongSave)?;
+
     let internal_request = RgbInternalSaveTransferRequest::with(
         consig_id.clone(),
         consig.clone(),
@@ -1467,10 +3335,6 @@ async fn internal_transfer_asset(
         Some(psbt),
     );
 
-    let txid = internal_save_transfer(internal_request, rgb_transfers)
-        .await
-        .map_err(TransferError::WrongSave)?;
-
     let resp = RgbInternalTransferResponse {
         consig_id,
         consig,
@@ -1482,7 +3346,7 @@ async fn internal_transfer_asset(
         txid: txid.to_hex(),
     };
 
-    Ok(resp)
+    Ok((resp, internal_request))
 }
 
 pub async fn internal_replace_transfer(
@@ -1493,6 +3357,7 @@ pub async fn internal_replace_transfer(
     let (mut stock, mut rgb_account, mut rgb_transfers) = retrieve_stock_account_transfers(sk)
         .await
         .map_err(TransferError::IO)?;
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
 
     let RgbInternalTransferResponse {
         consig_id,
@@ -1510,6 +3375,7 @@ pub async fn internal_replace_transfer(
         &mut stock,
         &mut rgb_account,
         &mut rgb_transfers,
+        &mut rgb_locks,
     )
     .await?;
 
@@ -1538,17 +3404,362 @@ pub async fn internal_replace_transfer(
         txid,
     };
 
-    store_stock_account_transfers(sk, stock, rgb_account, rgb_transfers)
-        .await
-        .map_err(TransferError::IO)?;
+    store_stock_account_transfers(sk, stock, rgb_account, rgb_transfers)
+        .await
+        .map_err(TransferError::IO)?;
+    store_locks(sk, rgb_locks)
+        .await
+        .map_err(TransferError::IO)?;
+
+    Ok(resp)
+}
+
+/// Bump an unconfirmed RGB transfer with a child transaction (CPFP) that spends
+/// the bitcoin outputs of the watcher, leaving the RGB allocations untouched
+pub async fn bump_transfer_cpfp(
+    sk: &str,
+    request: CpfpRequest,
+) -> Result<CpfpResponse, TransferError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(TransferError::Validation(errors));
+    }
+
+    let CpfpRequest {
+        txid,
+        fee_rate,
+        descriptor,
+    } = request;
+
+    let parent_txid = Txid::from_str(&txid).map_err(|_| {
+        let mut errors = BTreeMap::new();
+        errors.insert("txid".to_string(), "invalid transaction id".to_string());
+        TransferError::Validation(errors)
+    })?;
+
+    if fee_rate <= 0.0 {
+        let mut errors = BTreeMap::new();
+        errors.insert("fee_rate".to_string(), "invalid fee rate".to_string());
+        return Err(TransferError::Validation(errors));
+    }
+
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let (mut stock, mut rgb_account) = retrieve_stock_account(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
+
+    let mut rgb_wallet = match rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        Some(rgb_wallet) => rgb_wallet.to_owned(),
+        _ => return Err(TransferError::NoWatcher),
+    };
+
+    prefetch_resolver_txs_status(vec![parent_txid], &mut resolver).await;
+    prefetch_resolver_txs_fee(vec![parent_txid], &mut resolver).await;
+    match resolver.txs_status.get(&parent_txid) {
+        Some(TxStatus::Mempool) => {}
+        Some(TxStatus::Block(_)) => return Err(TransferError::TransactionConfirmed(txid)),
+        _ => return Err(TransferError::NoTransaction(txid)),
+    }
+
+    let parent_fee = match resolver.txs_fee.get(&parent_txid) {
+        Some(parent_fee) => parent_fee.clone(),
+        _ => return Err(TransferError::NoTransaction(txid)),
+    };
+
+    // Outputs with RGB allocations (or tapret commitments) are never spent
+    let mut parent_outputs = vec![];
+    let mut other_unspents = vec![];
+    for bitcoin_index in [AssetType::Bitcoin as u32, AssetType::Change as u32] {
+        sync_wallet(bitcoin_index, &mut rgb_wallet, &mut resolver);
+        prefetch_resolver_utxos(
+            bitcoin_index,
+            &mut rgb_wallet,
+            &mut resolver,
+            Some(BITCOIN_DEFAULT_FETCH_LIMIT),
+        )
+        .await;
+        prefetch_resolver_user_utxo_status(bitcoin_index, &mut rgb_wallet, &mut resolver, false)
+            .await;
+
+        let unspents = next_utxos(bitcoin_index, rgb_wallet.clone(), &mut resolver)
+            .map_err(|op| TransferError::WrongWatcher(op.to_string()))?;
+        for utxo in unspents {
+            let has_state = stock
+                .contracts_by_outpoints([utxo.outpoint])
+                .map(|contract_ids| !contract_ids.is_empty())
+                .unwrap_or(true);
+            if has_state
+                || utxo.derivation.tweak.is_some()
+                || rgb_locks.is_locked(&utxo.outpoint.to_string())
+            {
+                continue;
+            }
+
+            if utxo.outpoint.txid.to_hex() == parent_txid.to_string() {
+                parent_outputs.push(utxo);
+            } else {
+                other_unspents.push(utxo);
+            }
+        }
+    }
+
+    if parent_outputs.is_empty() {
+        return Err(TransferError::NoCpfpOutput(txid));
+    }
+
+    // Other bitcoin UTXOs are added when the parent outputs cannot pay the child fee
+    let mut inputs = parent_outputs;
+    let mut total: u64 = inputs.iter().map(|utxo| utxo.amount).sum();
+    let mut fee = estimate_cpfp_fee(&parent_fee, inputs.len(), 1, fee_rate);
+    for utxo in other_unspents {
+        if total >= fee + DUST_LIMIT_SATOSHI {
+            break;
+        }
+        total += utxo.amount;
+        inputs.push(utxo);
+        fee = estimate_cpfp_fee(&parent_fee, inputs.len(), 1, fee_rate);
+    }
+
+    if total < fee + DUST_LIMIT_SATOSHI {
+        return Err(TransferError::Inflation {
+            input: total,
+            output: fee + DUST_LIMIT_SATOSHI,
+        });
+    }
+
+    let network = NETWORK.read().await.to_string();
+    let address_network =
+        Network::from_str(&network).map_err(|op| TransferError::WrongNetwork(op.to_string()))?;
+    let TerminalPath { app, index } = next_address(
+        AssetType::Change as u32,
+        rgb_wallet.clone(),
+        AddressNetwork::from(address_network),
+    )
+    .map_err(|op| TransferError::WrongWatcher(op.to_string()))?
+    .terminal;
+
+    let bitcoin_inputs = inputs
+        .iter()
+        .map(|utxo| {
+            let TerminalPath { app, index } = utxo.derivation.terminal;
+            PsbtInputRequest {
+                descriptor: descriptor.clone(),
+                utxo: utxo.outpoint.to_string(),
+                utxo_terminal: format!("/{app}/{index}"),
+                tapret: None,
+                sigh_hash: None,
+            }
+        })
+        .collect();
+
+    // The child pays everything (minus the fee) to a new change output
+    let psbt_req = PsbtRequest {
+        asset_inputs: vec![],
+        asset_descriptor_change: None,
+        asset_terminal_change: Some(format!("/{app}/{index}")),
+        bitcoin_inputs,
+        bitcoin_changes: vec![],
+        fee: PsbtFeeRequest::Value(fee),
+        rbf: true,
+        close_method: SealCloseMethod::TapretFirst,
+        coin_selection: default!(),
+    };
+
+    rgb_account
+        .wallets
+        .insert(RGB_DEFAULT_NAME.to_owned(), rgb_wallet);
+
+    let options = NewPsbtOptions {
+        set_tapret: false,
+        ..NewPsbtOptions::with(true)
+    };
+    let psbt = internal_create_psbt(psbt_req, &mut rgb_account, &mut resolver, Some(options))
+        .await
+        .map_err(TransferError::Create)?;
+
+    store_account(sk, rgb_account)
+        .await
+        .map_err(TransferError::IO)?;
+
+    let package_vsize = parent_fee.weight.div_ceil(4) + estimate_taproot_vsize(inputs.len(), 1);
+    Ok(CpfpResponse {
+        parent_txid: txid,
+        inputs: inputs
+            .into_iter()
+            .map(|utxo| utxo.outpoint.to_string())
+            .collect(),
+        fee,
+        package_fee_rate: (parent_fee.fee + fee) as f32 / package_vsize as f32,
+        psbt: psbt.psbt,
+    })
+}
+
+pub async fn accept_transfer(
+    sk: &str,
+    request: AcceptRequest,
+) -> Result<AcceptResponse, TransferError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(TransferError::Validation(errors));
+    }
+    let (mut stock, mut rgb_account) = retrieve_stock_account(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let mut rgb_invoices = retrieve_invoices(sk).await.map_err(TransferError::IO)?;
+    let rgb_reservations = retrieve_reservations(sk).await.map_err(TransferError::IO)?;
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let resp = internal_accept_transfer(
+        request,
+        &mut stock,
+        &mut rgb_account,
+        &mut rgb_invoices,
+        &rgb_reservations,
+        &mut resolver,
+    )
+    .await?;
+
+    store_stock_account(sk, stock, rgb_account)
+        .await
+        .map_err(TransferError::IO)?;
+    store_invoices(sk, rgb_invoices)
+        .await
+        .map_err(TransferError::IO)?;
+
+    Ok(resp)
+}
+
+/// Accept many consignments loading and storing the stock only once
+///
+/// The consignments are accepted in sequence. A rejected consignment does
+/// not abort the batch: its error is returned in the item of the response.
+pub async fn accept_transfers(
+    sk: &str,
+    requests: Vec<AcceptRequest>,
+) -> Result<BatchAcceptResponse, TransferError> {
+    let (mut stock, mut rgb_account) = retrieve_stock_account(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let mut rgb_invoices = retrieve_invoices(sk).await.map_err(TransferError::IO)?;
+    let rgb_reservations = retrieve_reservations(sk).await.map_err(TransferError::IO)?;
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    let mut transfers = vec![];
+    for (index, request) in requests.into_iter().enumerate() {
+        let item = match request.validate(&RGBContext::default()) {
+            Err(err) => {
+                let errors: BTreeMap<String, String> = err
+                    .iter()
+                    .map(|(f, e)| (f.to_string(), e.to_string()))
+                    .collect();
+                BatchAcceptItem {
+                    index,
+                    accept: None,
+                    error: Some(TransferError::Validation(errors).to_string()),
+                }
+            }
+            Ok(_) => match internal_accept_transfer(
+                request,
+                &mut stock,
+                &mut rgb_account,
+                &mut rgb_invoices,
+                &rgb_reservations,
+                &mut resolver,
+            )
+            .await
+            {
+                Ok(resp) => BatchAcceptItem {
+                    index,
+                    accept: Some(resp),
+                    error: None,
+                },
+                Err(err) => BatchAcceptItem {
+                    index,
+                    accept: None,
+                    error: Some(err.to_string()),
+                },
+            },
+        };
+        transfers.push(item);
+    }
+
+    if transfers.iter().any(|item| item.accept.is_some()) {
+        store_stock_account(sk, stock, rgb_account)
+            .await
+            .map_err(TransferError::IO)?;
+        store_invoices(sk, rgb_invoices)
+            .await
+            .map_err(TransferError::IO)?;
+    }
+
+    Ok(BatchAcceptResponse { transfers })
+}
+
+async fn internal_accept_transfer(
+    request: AcceptRequest,
+    stock: &mut Stock,
+    rgb_account: &mut RgbAccountV1,
+    rgb_invoices: &mut RgbInvoices,
+    rgb_reservations: &RgbReservations,
+    resolver: &mut ExplorerResolver,
+) -> Result<AcceptResponse, TransferError> {
+    let AcceptRequest { consignment, .. } = request;
+    prefetch_resolver_rgb(&consignment, resolver, None).await;
+
+    let transfer =
+        accept_rgb_transfer(consignment, false, resolver, stock).map_err(TransferError::Accept)?;
 
-    Ok(resp)
+    // Update the status of the invoices paid by the transfer
+    let network = NETWORK.read().await.to_string();
+    let bitcoin_network = bitcoin_30::Network::from_str(&network)
+        .map_err(|op| TransferError::WrongNetwork(op.to_string()))?;
+    internal_settle_invoices(&transfer, bitcoin_network, resolver, rgb_invoices);
+
+    // Register outputs created by the witness transaction (witness-vout invoices)
+    if let Some(wallet) = rgb_account.wallets.get(RGB_DEFAULT_NAME) {
+        let mut wallet = wallet.to_owned();
+        register_witness_outputs(&transfer, &network, &mut wallet, rgb_reservations, resolver)
+            .await?;
+        rgb_account
+            .wallets
+            .insert(RGB_DEFAULT_NAME.to_string(), wallet);
+    }
+
+    let status = transfer
+        .clone()
+        .unbindle()
+        .into_validation_status()
+        .unwrap_or_default();
+    let report = internal_validation_report(&transfer, &status, resolver).await;
+
+    Ok(AcceptResponse {
+        contract_id: transfer.contract_id().to_string(),
+        transfer_id: transfer.transfer_id().to_string(),
+        valid: report.valid,
+        report,
+    })
 }
 
-pub async fn accept_transfer(
-    sk: &str,
-    request: AcceptRequest,
-) -> Result<AcceptResponse, TransferError> {
+pub async fn validate_consignment(
+    request: ValidateConsignmentRequest,
+) -> Result<ConsignmentValidationReport, TransferError> {
     if let Err(err) = request.validate(&RGBContext::default()) {
         let errors = err
             .iter()
@@ -1556,29 +3767,99 @@ pub async fn accept_transfer(
             .collect();
         return Err(TransferError::Validation(errors));
     }
-    let mut stock = retrieve_rgb_stock(sk).await.map_err(TransferError::IO)?;
+
     let mut resolver = ExplorerResolver {
         explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
         ..default!()
     };
 
-    let AcceptRequest { consignment, .. } = request;
+    let ValidateConsignmentRequest { consignment } = request;
     prefetch_resolver_rgb(&consignment, &mut resolver, None).await;
 
-    let transfer = accept_rgb_transfer(consignment, false, &mut resolver, &mut stock)
-        .map_err(TransferError::Accept)?;
+    // Validation only, the consignment is not imported into the stock
+    let (transfer, status) =
+        validate_transfer(consignment, &mut resolver).map_err(TransferError::Accept)?;
 
-    let resp = AcceptResponse {
-        contract_id: transfer.contract_id().to_string(),
-        transfer_id: transfer.transfer_id().to_string(),
-        valid: true,
-    };
+    Ok(internal_validation_report(&transfer, &status, &mut resolver).await)
+}
 
-    store_rgb_stock(sk, stock)
-        .await
-        .map_err(TransferError::IO)?;
+async fn internal_validation_report(
+    transfer: &Bindle<Transfer>,
+    status: &Status,
+    resolver: &mut ExplorerResolver,
+) -> ConsignmentValidationReport {
+    let txids = extract_anchor_txids(transfer)
+        .into_iter()
+        .map(|txid| Txid::from_str(&txid.to_hex()).expect("invalid tx id"))
+        .collect();
+    prefetch_resolver_txs_status(txids, resolver).await;
+
+    extract_validation_report(transfer, status, |txid| {
+        let txid = Txid::from_str(&txid.to_hex()).expect("invalid tx id");
+        resolver
+            .txs_status
+            .get(&txid)
+            .cloned()
+            .unwrap_or(TxStatus::NotFound)
+    })
+}
 
-    Ok(resp)
+async fn register_witness_outputs(
+    transfer: &Bindle<Transfer>,
+    network: &str,
+    wallet: &mut RgbWallet,
+    rgb_reservations: &RgbReservations,
+    resolver: &mut ExplorerResolver,
+) -> Result<(), TransferError> {
+    let witness_outpoints = extract_witness_outpoints(transfer);
+    if witness_outpoints.is_empty() {
+        return Ok(());
+    }
+
+    let network =
+        Network::from_str(network).map_err(|op| TransferError::WrongNetwork(op.to_string()))?;
+    let network = AddressNetwork::from(network);
+
+    // Witness outputs are derived in the index of the contract interface (see
+    // `create_witness_invoice`), so every interface of the transferred contract is scanned
+    let iface_indexes: BTreeSet<u32> = transfer
+        .ifaces
+        .values()
+        .map(|pair| match pair.iface.name.as_str() {
+            "RGB20" => AssetType::RGB20,
+            "RGB25" => AssetType::RGB25,
+            _ => AssetType::RGB21,
+        } as u32)
+        .collect();
+
+    // Scan up to the last used or reserved derivation (plus the default window)
+    let mut last_index = 0;
+    for iface_index in &iface_indexes {
+        let next_index = next_addresses(*iface_index, wallet.clone(), rgb_reservations, network, 1)
+            .map_err(|op| TransferError::WrongWatcher(op.to_string()))?
+            .first()
+            .map(|address| address.terminal.index)
+            .unwrap_or_default();
+        last_index = last_index.max(next_index);
+    }
+    let limit = Some(last_index + RGB_DEFAULT_FETCH_LIMIT);
+
+    let iface_indexes: Vec<u32> = iface_indexes.into_iter().collect();
+    for outpoint in witness_outpoints {
+        let utxo = outpoint.to_string();
+        prefetch_resolver_wutxo(&utxo, network, wallet, resolver, limit).await;
+        register_utxo(
+            &utxo,
+            network,
+            iface_indexes.clone(),
+            wallet,
+            resolver,
+            limit,
+        )
+        .map_err(|op| TransferError::WrongWatcher(op.to_string()))?;
+    }
+
+    Ok(())
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
@@ -1660,6 +3941,7 @@ pub async fn internal_save_transfer(
     let RgbInternalSaveTransferRequest {
         iface,
         consig: consignment,
+        beneficiary,
         sender,
         utxos,
         beneficiaries,
@@ -1680,40 +3962,49 @@ pub async fn internal_save_transfer(
         BTreeMap::new()
     };
 
-    let secret_seals: Vec<String> = beneficiaries.keys().map(|x| x.to_string()).collect();
+    // The main consignment tracks its own beneficiary (when known)
+    let secret_seals: Vec<String> = if beneficiary.is_empty() {
+        beneficiaries.keys().map(|x| x.to_string()).collect()
+    } else {
+        vec![beneficiary]
+    };
 
     let consig = strict.to_hex();
     let rgb_transfer = RgbTransferV1 {
-        consig_id: consig_id.clone(),
-        consig: consig.clone(),
-        iface,
+        consig_id,
+        consig,
+        iface: iface.clone(),
         tx_id,
         sender,
-        utxos,
+        utxos: utxos.clone(),
         beneficiaries: secret_seals,
         rbf: true,
     };
-
-    if let Some(transfers) = rgb_transfers.transfers.get(&contract_id.clone()) {
-        let mut current_transfers = transfers.clone();
-
-        if let Some(pos) = current_transfers
-            .clone()
-            .into_iter()
-            .position(|x| x.consig_id == consig_id)
-        {
-            current_transfers.remove(pos);
-            current_transfers.insert(pos, rgb_transfer);
-        } else {
-            current_transfers.push(rgb_transfer);
+    upsert_transfer(contract_id, rgb_transfer, rgb_transfers);
+
+    // Each other beneficiary is tracked by its own consignment
+    if sender {
+        for (seal, other_consignment) in beneficiaries.iter() {
+            let RgbExtractTransfer {
+                consig_id,
+                contract_id,
+                txid: tx_id,
+                strict,
+                ..
+            } = prebuild_extract_transfer(other_consignment)?;
+
+            let rgb_transfer = RgbTransferV1 {
+                consig_id,
+                consig: strict.to_hex(),
+                iface: iface.clone(),
+                tx_id,
+                sender,
+                utxos: utxos.clone(),
+                beneficiaries: vec![seal.to_string()],
+                rbf: true,
+            };
+            upsert_transfer(contract_id, rgb_transfer, rgb_transfers);
         }
-        rgb_transfers
-            .transfers
-            .insert(contract_id.clone(), current_transfers.to_vec());
-    } else {
-        rgb_transfers
-            .transfers
-            .insert(contract_id.clone(), vec![rgb_transfer]);
     }
 
     post_consignments(beneficiaries)
@@ -1723,6 +4014,32 @@ pub async fn internal_save_transfer(
     Ok(tx_id)
 }
 
+fn upsert_transfer(
+    contract_id: String,
+    rgb_transfer: RgbTransferV1,
+    rgb_transfers: &mut RgbTransfersV1,
+) {
+    let mut current_transfers = rgb_transfers
+        .transfers
+        .get(&contract_id)
+        .cloned()
+        .unwrap_or_default();
+
+    if let Some(pos) = current_transfers
+        .iter()
+        .position(|x| x.consig_id == rgb_transfer.consig_id)
+    {
+        current_transfers.remove(pos);
+        current_transfers.insert(pos, rgb_transfer);
+    } else {
+        current_transfers.push(rgb_transfer);
+    }
+
+    rgb_transfers
+        .transfers
+        .insert(contract_id, current_transfers);
+}
+
 pub async fn remove_transfer(
     sk: &str,
     request: RgbRemoveTransferRequest,
@@ -1760,6 +4077,14 @@ pub async fn remove_transfer(
         .await
         .map_err(SaveTransferError::IO)?;
 
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(SaveTransferError::IO)?;
+    for consig_id in consig_ids.clone() {
+        rgb_locks.release(&RgbLockOwner::Transfer(consig_id));
+    }
+    store_locks(sk, rgb_locks)
+        .await
+        .map_err(SaveTransferError::IO)?;
+
     let status = consig_ids.into_iter().map(|x| (x, true)).collect();
     Ok(RgbTransferStatusResponse {
         contract_id,
@@ -1778,7 +4103,42 @@ pub async fn verify_transfers(sk: &str) -> Result<BatchRgbTransferResponse, Tran
         .await
         .map_err(TransferError::Save)?;
 
-    let (rgb_pending, transfers) = internal_verify_transfers(&mut stock, rgb_transfers).await?;
+    let mut rgb_invoices = retrieve_invoices(sk).await.map_err(TransferError::IO)?;
+    let mut rgb_archive = retrieve_transfers_archive(sk)
+        .await
+        .map_err(TransferError::IO)?;
+    let rgb_reservations = retrieve_reservations(sk).await.map_err(TransferError::IO)?;
+    let rgb_activities = rgb_transfers.clone();
+    let (mut rgb_pending, transfers) = internal_verify_transfers(
+        &mut stock,
+        rgb_transfers,
+        &mut rgb_invoices,
+        &rgb_reservations,
+        &mut rgb_wallet,
+    )
+    .await?;
+
+    // Accepted transfers whose anchor block left the best chain are pending again
+    let mut rgb_locks = retrieve_locks(sk).await.map_err(TransferError::IO)?;
+    let mut rgb_anchors = retrieve_anchors(sk).await.map_err(TransferError::IO)?;
+    let reorgs = internal_monitor_reorgs(
+        rgb_activities.clone(),
+        &mut rgb_pending,
+        &mut rgb_anchors,
+        &mut rgb_locks,
+    )
+    .await;
+    internal_archive_transfers(rgb_activities, &rgb_pending, &mut rgb_archive);
+
+    // Confirmed (or removed) transfers no longer reserve their outpoints
+    let pending_transfers: Vec<String> = rgb_pending
+        .transfers
+        .values()
+        .flat_map(|x| x.iter().map(|t| t.consig_id.clone()))
+        .collect();
+    rgb_locks.release_transfers(&pending_transfers);
+    let utc = chrono::Local::now().naive_utc().timestamp();
+    rgb_locks.release_expired_exports(utc, RGB_EXPORT_LOCK_EXPIRY);
 
     let mut my_public_offers = vec![];
     let check_offers: Vec<_> = transfers
@@ -1802,6 +4162,7 @@ pub async fn verify_transfers(sk: &str) -> Result<BatchRgbTransferResponse, Tran
                 .await
                 .map_err(TransferError::WrongSwap)?
             {
+                rgb_locks.release(&RgbLockOwner::Offer(offer.offer_id.clone()));
                 my_public_offers.push(offer);
             }
         }
@@ -1833,8 +4194,153 @@ pub async fn verify_transfers(sk: &str) -> Result<BatchRgbTransferResponse, Tran
     store_stock_account_transfers(sk, stock, rgb_accounts, rgb_pending)
         .await
         .map_err(TransferError::IO)?;
+    store_invoices(sk, rgb_invoices)
+        .await
+        .map_err(TransferError::IO)?;
+    store_locks(sk, rgb_locks)
+        .await
+        .map_err(TransferError::IO)?;
+    store_anchors(sk, rgb_anchors)
+        .await
+        .map_err(TransferError::IO)?;
+    store_transfers_archive(sk, rgb_archive)
+        .await
+        .map_err(TransferError::IO)?;
+
+    Ok(BatchRgbTransferResponse { transfers, reorgs })
+}
+
+/// Move the accepted transfers to the archive (the transfers pending again
+/// after a reorg are removed from it)
+pub fn internal_archive_transfers(
+    rgb_activities: RgbTransfersV1,
+    rgb_pending: &RgbTransfersV1,
+    rgb_archive: &mut RgbTransfersV1,
+) {
+    for (contract_id, activities) in rgb_activities.transfers {
+        let pending = rgb_pending
+            .transfers
+            .get(&contract_id)
+            .cloned()
+            .unwrap_or_default();
+        for activity in activities {
+            if !pending.iter().any(|x| x.consig_id == activity.consig_id) {
+                upsert_transfer(contract_id.clone(), activity, rgb_archive);
+            }
+        }
+    }
+
+    for (contract_id, pending) in &rgb_pending.transfers {
+        if let Some(archived) = rgb_archive.transfers.get_mut(contract_id) {
+            archived.retain(|x| !pending.iter().any(|p| p.consig_id == x.consig_id));
+        }
+    }
+}
+
+/// Record the block of each accepted anchor and restore as pending the
+/// transfers whose block is no longer in the best chain (they are accepted
+/// again once their anchor is mined)
+pub async fn internal_monitor_reorgs(
+    rgb_activities: RgbTransfersV1,
+    rgb_pending: &mut RgbTransfersV1,
+    rgb_anchors: &mut RgbAnchors,
+    rgb_locks: &mut RgbLocks,
+) -> Vec<BatchRgbTransferItem> {
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    // Transfers no longer pending were accepted
+    let mut accepted = vec![];
+    for (contract_id, activities) in rgb_activities.transfers {
+        let pending = rgb_pending
+            .transfers
+            .get(&contract_id)
+            .cloned()
+            .unwrap_or_default();
+        for activity in activities {
+            if !pending.iter().any(|x| x.consig_id == activity.consig_id) {
+                accepted.push((contract_id.clone(), activity));
+            }
+        }
+    }
+
+    let mut txids: Vec<Txid> = rgb_anchors
+        .anchors
+        .values()
+        .map(|anchor| Txid::from_str(&anchor.txid).expect("invalid tx id"))
+        .collect();
+    txids.extend(
+        accepted
+            .iter()
+            .map(|(_, x)| Txid::from_str(&x.tx_id.to_hex()).expect("invalid tx id")),
+    );
+    txids.sort();
+    txids.dedup();
+    prefetch_resolver_txs_status(txids, &mut resolver).await;
+    prefetch_resolver_tip_height(&mut resolver).await;
+
+    for (contract_id, activity) in accepted {
+        let txid = Txid::from_str(&activity.tx_id.to_hex()).expect("invalid tx id");
+        if let (Some(TxStatus::Block(height)), Some(hash)) = (
+            resolver.txs_status.get(&txid),
+            resolver.txs_block_hash.get(&txid),
+        ) {
+            rgb_anchors.record(&contract_id, activity, *height, hash);
+        }
+    }
+
+    // Explorer errors do not roll back the transfers
+    let block_hashes: BTreeMap<String, Option<String>> = rgb_anchors
+        .anchors
+        .values()
+        .filter_map(|anchor| {
+            let txid = Txid::from_str(&anchor.txid).expect("invalid tx id");
+            match resolver.txs_status.get(&txid)? {
+                TxStatus::Block(_) => resolver
+                    .txs_block_hash
+                    .get(&txid)
+                    .map(|hash| (anchor.txid.clone(), Some(hash.clone()))),
+                TxStatus::Mempool | TxStatus::NotFound => Some((anchor.txid.clone(), None)),
+                TxStatus::Error(_) => None,
+            }
+        })
+        .collect();
+
+    let utc = chrono::Local::now().naive_utc().timestamp();
+    let mut reorgs = vec![];
+    for anchor in rgb_anchors.reorged(&block_hashes) {
+        let txid = Txid::from_str(&anchor.txid).expect("invalid tx id");
+        let status = resolver
+            .txs_status
+            .get(&txid)
+            .unwrap_or(&TxStatus::NotFound)
+            .to_owned();
+
+        reorgs.push(BatchRgbTransferItem {
+            contract_id: anchor.contract_id.clone(),
+            consig_id: anchor.transfer.consig_id.clone(),
+            iface: anchor.transfer.iface.clone(),
+            status,
+            is_accept: false,
+            is_mine: anchor.transfer.sender,
+            txid: anchor.txid.clone(),
+        });
+
+        rgb_locks.lock(
+            anchor.transfer.utxos.clone(),
+            RgbLockOwner::Transfer(anchor.transfer.consig_id.clone()),
+            utc,
+        );
+        upsert_transfer(anchor.contract_id, anchor.transfer, rgb_pending);
+    }
+
+    if let Some(tip_height) = resolver.tip_height {
+        rgb_anchors.prune(tip_height, RGB_REORG_DEPTH);
+    }
 
-    Ok(BatchRgbTransferResponse { transfers })
+    reorgs
 }
 
 pub async fn internal_swap_transfers(
@@ -2012,12 +4518,19 @@ pub async fn internal_update_transfers(
 pub async fn internal_verify_transfers(
     stock: &mut Stock,
     rgb_transfers: RgbTransfersV1,
+    rgb_invoices: &mut RgbInvoices,
+    rgb_reservations: &RgbReservations,
+    rgb_wallet: &mut RgbWallet,
 ) -> Result<(RgbTransfersV1, Vec<BatchRgbTransferItem>), TransferError> {
     let mut resolver = ExplorerResolver {
         explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
         ..default!()
     };
 
+    let network_name = NETWORK.read().await.to_string();
+    let network = bitcoin_30::Network::from_str(&network_name)
+        .map_err(|op| TransferError::WrongNetwork(op.to_string()))?;
+
     let mut transfers = vec![];
     let mut rgb_pending = RgbTransfersV1::default();
     for (contract_id, transfer_activities) in rgb_transfers.transfers {
@@ -2059,9 +4572,20 @@ pub async fn internal_verify_transfers(
                 }
             };
             let transfer_id = accept_status.transfer_id();
+            let transfer = accept_status.clone();
             let accept_status = accept_status.unbindle();
             if let Some(rgb_status) = accept_status.into_validation_status() {
                 if rgb_status.validity() == Validity::Valid {
+                    internal_settle_invoices(&transfer, network, &resolver, rgb_invoices);
+                    // Outputs created by the transfer (ex: split allocations)
+                    register_witness_outputs(
+                        &transfer,
+                        &network_name,
+                        rgb_wallet,
+                        rgb_reservations,
+                        &mut resolver,
+                    )
+                    .await?;
                     transfers.push(BatchRgbTransferItem {
                         iface,
                         status,
@@ -2107,7 +4631,7 @@ pub async fn get_contract(sk: &str, contract_id: &str) -> Result<ContractRespons
     let mut wallet = match wallet {
         Some(wallet) => {
             let mut fetch_wallet = wallet.to_owned();
-            for contract_type in [AssetType::RGB20, AssetType::RGB21] {
+            for contract_type in [AssetType::RGB20, AssetType::RGB21, AssetType::RGB25] {
                 let contract_index = contract_type.clone() as u32;
                 let iface_name = contract_type.to_string().to_uppercase().clone();
 
@@ -2200,7 +4724,7 @@ pub async fn list_contracts(sk: &str, hidden_contracts: bool) -> Result<Contract
     let mut wallet = match wallet {
         Some(wallet) => {
             let mut fetch_wallet = wallet.to_owned();
-            for contract_type in [AssetType::RGB20, AssetType::RGB21] {
+            for contract_type in [AssetType::RGB20, AssetType::RGB21, AssetType::RGB25] {
                 let contract_index = contract_type as u32;
                 sync_wallet(contract_index, &mut fetch_wallet, &mut resolver);
                 prefetch_resolver_utxos(
@@ -2217,7 +4741,7 @@ pub async fn list_contracts(sk: &str, hidden_contracts: bool) -> Result<Contract
     };
 
     let mut contracts = vec![];
-    for contract_type in [AssetType::RGB20, AssetType::RGB21] {
+    for contract_type in [AssetType::RGB20, AssetType::RGB21, AssetType::RGB25] {
         let iface_name = contract_type.to_string().to_uppercase().clone();
         let iface_name = tn!(iface_name);
         let iface = stock
@@ -2353,6 +4877,194 @@ pub async fn list_transfers(sk: &str, contract_id: String) -> Result<RgbTransfer
     Ok(RgbTransfersResponse { transfers })
 }
 
+pub async fn transfer_history(
+    sk: &str,
+    request: RgbTransferHistoryRequest,
+) -> Result<RgbTransferHistoryResponse> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(TransferError::Validation(errors).into());
+    }
+
+    let RgbTransferHistoryRequest {
+        contract_id,
+        offset,
+        limit,
+    } = request;
+
+    let rgb_transfers = retrieve_transfers(sk).await?;
+    let rgb_archive = retrieve_transfers_archive(sk).await?;
+    let rgb_invoices = retrieve_invoices(sk).await?;
+    let rgb_offers = retrieve_offers(sk).await?;
+    let rgb_bids = retrieve_bids(sk).await?;
+
+    let network = NETWORK.read().await.to_string();
+    let network = bitcoin_30::Network::from_str(&network)?;
+
+    let mut resolver = ExplorerResolver {
+        explorer_url: BITCOIN_EXPLORER_API.read().await.to_string(),
+        ..default!()
+    };
+
+    // Accepted transfers are archived by `verify_transfers`
+    let mut activities = rgb_archive
+        .transfers
+        .get(&contract_id)
+        .cloned()
+        .unwrap_or_default();
+    for activity in rgb_transfers
+        .transfers
+        .get(&contract_id)
+        .cloned()
+        .unwrap_or_default()
+    {
+        if !activities.iter().any(|x| x.consig_id == activity.consig_id) {
+            activities.push(activity);
+        }
+    }
+
+    // Newest first
+    let page: Vec<(usize, RgbTransferV1)> = activities
+        .clone()
+        .into_iter()
+        .enumerate()
+        .rev()
+        .skip(offset)
+        .take(limit)
+        .collect();
+
+    let txids: Vec<Txid> = page
+        .iter()
+        .map(|(_, x)| Txid::from_str(&x.tx_id.to_hex()).expect("invalid tx id"))
+        .collect();
+    prefetch_resolver_txs_status(txids.clone(), &mut resolver).await;
+    prefetch_resolver_txs(txids.clone(), &mut resolver).await;
+    let sended_txids = page
+        .iter()
+        .filter(|(_, x)| x.sender)
+        .map(|(_, x)| Txid::from_str(&x.tx_id.to_hex()).expect("invalid tx id"))
+        .collect();
+    prefetch_resolver_txs_fee(sended_txids, &mut resolver).await;
+    prefetch_resolver_tip_height(&mut resolver).await;
+
+    let offers = rgb_offers
+        .offers
+        .get(&contract_id)
+        .cloned()
+        .unwrap_or_default();
+    let bids = rgb_bids.bids.get(&contract_id).cloned().unwrap_or_default();
+
+    let mut transfers = vec![];
+    for (pos, activity) in page {
+        let ty = if activity.sender {
+            TransferType::Sended
+        } else {
+            TransferType::Received
+        };
+
+        let txid = Txid::from_str(&activity.tx_id.to_hex()).expect("invalid tx id");
+        let status = resolver
+            .txs_status
+            .get(&txid)
+            .unwrap_or(&TxStatus::NotFound)
+            .to_owned();
+
+        let (block_height, confirmations) = match (&status, resolver.tip_height) {
+            (TxStatus::Block(height), Some(tip)) => {
+                (Some(*height), tip.saturating_sub(*height) + 1)
+            }
+            (TxStatus::Block(height), None) => (Some(*height), 1),
+            _ => (None, 0),
+        };
+
+        // Amounts assigned by the consignment
+        let mut transfer_id = None;
+        let mut amounts = BTreeMap::new();
+        if let Ok((_, transfer)) = extract_transfer(activity.consig.clone()) {
+            transfer_id = Some(transfer.transfer_id().to_string());
+            amounts = extract_beneficiary_amounts(&transfer, network, &resolver);
+        }
+
+        let beneficiaries = if activity.sender {
+            activity
+                .beneficiaries
+                .iter()
+                .map(|beneficiary| RgbTransferBeneficiary {
+                    beneficiary: beneficiary.to_owned(),
+                    amount: amounts.get(beneficiary).copied(),
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+
+        // Transfers spending the same outputs are replacements (RBF)
+        let same_utxos = |other: &RgbTransferV1| {
+            other.consig_id != activity.consig_id
+                && other.utxos.iter().any(|utxo| activity.utxos.contains(utxo))
+        };
+        let replaces = activities[..pos]
+            .iter()
+            .filter(|other| same_utxos(other))
+            .map(|other| other.consig_id.clone())
+            .collect();
+        let replaced_by = activities[pos + 1..]
+            .iter()
+            .rev()
+            .find(|other| same_utxos(other))
+            .map(|other| other.consig_id.clone());
+
+        let is_transfer = |id: &str| id == activity.consig_id || Some(id) == transfer_id.as_deref();
+        let invoice = rgb_invoices
+            .invoices
+            .values()
+            .find(|record| record.transfers.iter().any(|id| is_transfer(id)))
+            .map(|record| record.invoice.clone());
+        let offer_id = offers
+            .iter()
+            .find(|offer| offer.transfer_id.as_deref().is_some_and(is_transfer))
+            .map(|offer| offer.offer_id.clone());
+        let bid_id = bids
+            .iter()
+            .find(|bid| bid.transfer_id.as_deref().is_some_and(is_transfer))
+            .map(|bid| bid.bid_id.clone());
+
+        transfers.push(RgbTransferHistoryItem {
+            consig_id: activity.consig_id,
+            txid: txid.to_hex(),
+            iface: activity.iface,
+            ty,
+            amount: amounts.values().sum(),
+            beneficiaries,
+            fee: match activity.sender {
+                true => resolver.txs_fee.get(&txid).map(|tx_fee| tx_fee.fee),
+                false => None,
+            },
+            status,
+            block_height,
+            block_time: resolver.txs_block_time.get(&txid).copied(),
+            confirmations,
+            rbf: activity.rbf,
+            replaces,
+            replaced_by,
+            invoice,
+            offer_id,
+            bid_id,
+        });
+    }
+
+    Ok(RgbTransferHistoryResponse {
+        contract_id,
+        total: activities.len(),
+        offset,
+        limit,
+        transfers,
+    })
+}
+
 pub async fn list_my_orders(sk: &str) -> Result<RgbOfferBidsResponse> {
     let rgb_offers = retrieve_offers(sk).await?;
     let rgb_bids = retrieve_bids(sk).await?;
@@ -2434,6 +5146,8 @@ pub enum ImportError {
     Import(ImportContractError),
     /// Occurs an error in export step. {0}
     Export(ExportContractError),
+    /// Occurs an error in schema import step. {0}
+    Schema(ImportSchemaError),
 }
 
 pub async fn import(sk: &str, request: ImportRequest) -> Result<ContractResponse, ImportError> {
@@ -2486,6 +5200,43 @@ pub async fn import(sk: &str, request: ImportRequest) -> Result<ContractResponse
     Ok(resp)
 }
 
+/// Import a schema, interface or interface implementation into the stock
+pub async fn import_schema(
+    sk: &str,
+    request: ImportSchemaRequest,
+) -> Result<ImportSchemaResponse, ImportError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| format!("{f}: {e}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(ImportError::Validation(errors));
+    }
+
+    let ImportSchemaRequest { import, data } = request;
+    let mut stock = retrieve_rgb_stock(sk).await.map_err(ImportError::IO)?;
+
+    let (id, name) = match import {
+        SchemaImportType::Schema => {
+            let schema = import_rgb_schema(&data, &mut stock).map_err(ImportError::Schema)?;
+            (schema.schema_id().to_string(), None)
+        }
+        SchemaImportType::Iface => {
+            let iface = import_rgb_iface(&data, &mut stock).map_err(ImportError::Schema)?;
+            (iface.iface_id().to_string(), Some(iface.name.to_string()))
+        }
+        SchemaImportType::IfaceImpl => {
+            let iimpl = import_rgb_iface_impl(&data, &mut stock).map_err(ImportError::Schema)?;
+            (iimpl.impl_id().to_string(), None)
+        }
+    };
+
+    store_rgb_stock(sk, stock).await.map_err(ImportError::IO)?;
+
+    Ok(ImportSchemaResponse { import, id, name })
+}
+
 // TODO: Extracte all watcher operations to watcher module
 #[derive(Debug, Clone, Eq, PartialEq, Display, From, Error)]
 #[display(doc_comments)]
@@ -2583,7 +5334,7 @@ pub async fn watcher_details(sk: &str, name: &str) -> Result<WatcherDetailRespon
     };
 
     let mut allocations = vec![];
-    for contract_type in [AssetType::RGB20, AssetType::RGB21] {
+    for contract_type in [AssetType::RGB20, AssetType::RGB21, AssetType::RGB25] {
         let iface_index = contract_type as u32;
         prefetch_resolver_utxos(
             iface_index,
@@ -2708,6 +5459,7 @@ pub async fn watcher_next_address(
     let iface_index = match iface {
         "RGB20" => 20,
         "RGB21" => 21,
+        "RGB25" => 25,
         _ => 10,
     };
 
@@ -2730,6 +5482,7 @@ pub async fn watcher_next_utxo(
     let iface_index = match iface {
         "RGB20" => 20,
         "RGB21" => 21,
+        "RGB25" => 25,
         _ => 10,
     };
 
@@ -2789,6 +5542,7 @@ pub async fn watcher_unspent_utxos(
     let iface_index = match iface {
         "RGB20" => 20,
         "RGB21" => 21,
+        "RGB25" => 25,
         _ => 10,
     };
 
@@ -2869,7 +5623,40 @@ pub async fn import_uda_data(request: MediaRequest) -> Result<MediaResponse> {
     Ok(resp)
 }
 
+pub async fn create_unified_uri(
+    request: UnifiedUriRequest,
+) -> Result<UnifiedUriResponse, InvoiceError> {
+    if let Err(err) = request.validate(&RGBContext::default()) {
+        let errors = err
+            .iter()
+            .map(|(f, e)| (f.to_string(), e.to_string()))
+            .collect();
+        return Err(InvoiceError::Validation(errors));
+    }
+
+    let UnifiedUriRequest {
+        rgb_invoice,
+        address,
+        amount,
+        lightning,
+    } = request;
+
+    let network = NETWORK.read().await.to_string();
+    let uri = UnifiedUri::with(&network, address, amount, Some(rgb_invoice), lightning)
+        .map_err(InvoiceError::WrongUri)?;
+
+    Ok(UnifiedUriResponse::from(uri))
+}
+
+pub async fn decode_unified_uri(uri: String) -> Result<UnifiedUriResponse> {
+    let network = NETWORK.read().await.to_string();
+    let uri = UnifiedUri::from_str(&uri)?.require_network(&network)?;
+    Ok(UnifiedUriResponse::from(uri))
+}
+
 pub async fn decode_invoice(invoice: String) -> Result<RgbInvoiceResponse> {
+    let network = NETWORK.read().await.to_string();
+    let invoice = extract_rgb_invoice(&invoice, &network)?;
     let rgb_invoice = RgbInvoice::from_str(&invoice)?;
 
     let contract_id = rgb_invoice
@@ -2883,9 +5670,18 @@ pub async fn decode_invoice(invoice: String) -> Result<RgbInvoiceResponse> {
         _ => 0,
     };
 
+    let transports = rgb_invoice
+        .transports
+        .iter()
+        .filter(|transport| **transport != RgbTransport::UnspecifiedMeans)
+        .map(|transport| transport.to_string())
+        .collect();
+
     Ok(RgbInvoiceResponse {
         contract_id,
         amount,
+        expiry: rgb_invoice.expiry,
+        transports,
     })
 }
 
@@ -2904,7 +5700,7 @@ pub async fn inspect_contract(
     let mut wallet = match wallet {
         Some(wallet) => {
             let mut fetch_wallet = wallet.to_owned();
-            for contract_type in [AssetType::RGB20, AssetType::RGB21] {
+            for contract_type in [AssetType::RGB20, AssetType::RGB21, AssetType::RGB25] {
                 let contract_index = contract_type.clone() as u32;
                 let iface_name = contract_type.to_string().to_uppercase().clone();
 
@@ -2947,7 +5743,7 @@ pub async fn read_contract(sk: &str, contract_id: &str) -> Result<ContractRespon
     let mut wallet = match wallet {
         Some(wallet) => {
             let mut fetch_wallet = wallet.to_owned();
-            for contract_type in [AssetType::RGB20, AssetType::RGB21] {
+            for contract_type in [AssetType::RGB20, AssetType::RGB21, AssetType::RGB25] {
                 let contract_index = contract_type.clone() as u32;
                 let iface_name = contract_type.to_string().to_uppercase().clone();
 
@@ -20,7 +20,7 @@ use crate::{
 };
 
 use super::cambria::RgbtransferVersions;
-use super::structs::RgbTransfersV1;
+use super::structs::{RgbAnchors, RgbInvoices, RgbLocks, RgbReservations, RgbTransfersV1};
 use super::swap::{PublicRgbOffers, RgbBidSwap};
 
 const RGB_ACCOUNT_VERSION: [u8; 2] = *b"v1";
@@ -149,6 +149,94 @@ pub async fn store_offers(
     .map_err(|op| StorageError::CarbonadoWrite(name.to_string(), op.to_string()))
 }
 
+pub async fn store_invoices(
+    sk: &str,
+    name: &str,
+    rgb_invoices: &RgbInvoices,
+) -> Result<(), StorageError> {
+    let data = to_allocvec(rgb_invoices)
+        .map_err(|op| StorageError::StrictWrite(name.to_string(), op.to_string()))?;
+
+    let hashed_name = blake3::hash(format!("{LIB_ID_RGB}-{name}").as_bytes())
+        .to_hex()
+        .to_lowercase();
+
+    store(
+        sk,
+        &format!("{hashed_name}.c15"),
+        &data,
+        true,
+        Some(RGB_STRICT_TYPE_VERSION.to_vec()),
+    )
+    .await
+    .map_err(|op| StorageError::CarbonadoWrite(name.to_string(), op.to_string()))
+}
+
+pub async fn store_locks(sk: &str, name: &str, rgb_locks: &RgbLocks) -> Result<(), StorageError> {
+    let data = to_allocvec(rgb_locks)
+        .map_err(|op| StorageError::StrictWrite(name.to_string(), op.to_string()))?;
+
+    let hashed_name = blake3::hash(format!("{LIB_ID_RGB}-{name}").as_bytes())
+        .to_hex()
+        .to_lowercase();
+
+    store(
+        sk,
+        &format!("{hashed_name}.c15"),
+        &data,
+        true,
+        Some(RGB_STRICT_TYPE_VERSION.to_vec()),
+    )
+    .await
+    .map_err(|op| StorageError::CarbonadoWrite(name.to_string(), op.to_string()))
+}
+
+pub async fn store_reservations(
+    sk: &str,
+    name: &str,
+    rgb_reservations: &RgbReservations,
+) -> Result<(), StorageError> {
+    let data = to_allocvec(rgb_reservations)
+        .map_err(|op| StorageError::StrictWrite(name.to_string(), op.to_string()))?;
+
+    let hashed_name = blake3::hash(format!("{LIB_ID_RGB}-{name}").as_bytes())
+        .to_hex()
+        .to_lowercase();
+
+    store(
+        sk,
+        &format!("{hashed_name}.c15"),
+        &data,
+        true,
+        Some(RGB_STRICT_TYPE_VERSION.to_vec()),
+    )
+    .await
+    .map_err(|op| StorageError::CarbonadoWrite(name.to_string(), op.to_string()))
+}
+
+pub async fn store_anchors(
+    sk: &str,
+    name: &str,
+    rgb_anchors: &RgbAnchors,
+) -> Result<(), StorageError> {
+    let data = to_allocvec(rgb_anchors)
+        .map_err(|op| StorageError::StrictWrite(name.to_string(), op.to_string()))?;
+
+    let hashed_name = blake3::hash(format!("{LIB_ID_RGB}-{name}").as_bytes())
+        .to_hex()
+        .to_lowercase();
+
+    store(
+        sk,
+        &format!("{hashed_name}.c15"),
+        &data,
+        true,
+        Some(RGB_STRICT_TYPE_VERSION.to_vec()),
+    )
+    .await
+    .map_err(|op| StorageError::CarbonadoWrite(name.to_string(), op.to_string()))
+}
+
 pub async fn store_bids(sk: &str, name: &str, rgb_bids: &RgbBids) -> Result<(), StorageError> {
     let data = to_allocvec(rgb_bids)
         .map_err(|op| StorageError::StrictWrite(name.to_string(), op.to_string()))?;
@@ -253,6 +341,78 @@ pub async fn retrieve_offers(sk: &str, name: &str) -> Result<RgbOffers, StorageE
     }
 }
 
+pub async fn retrieve_invoices(sk: &str, name: &str) -> Result<RgbInvoices, StorageError> {
+    let hashed_name = blake3::hash(format!("{LIB_ID_RGB}-{name}").as_bytes())
+        .to_hex()
+        .to_lowercase();
+
+    let (data, _) = retrieve(sk, &format!("{hashed_name}.c15"), vec![])
+        .await
+        .map_err(|op| StorageError::CarbonadoRetrieve(name.to_string(), op.to_string()))?;
+
+    if data.is_empty() {
+        Ok(RgbInvoices::default())
+    } else {
+        let rgb_invoices = from_bytes(&data)
+            .map_err(|op| StorageError::StrictRetrieve(name.to_string(), op.to_string()))?;
+        Ok(rgb_invoices)
+    }
+}
+
+pub async fn retrieve_locks(sk: &str, name: &str) -> Result<RgbLocks, StorageError> {
+    let hashed_name = blake3::hash(format!("{LIB_ID_RGB}-{name}").as_bytes())
+        .to_hex()
+        .to_lowercase();
+
+    let (data, _) = retrieve(sk, &format!("{hashed_name}.c15"), vec![])
+        .await
+        .map_err(|op| StorageError::CarbonadoRetrieve(name.to_string(), op.to_string()))?;
+
+    if data.is_empty() {
+        Ok(RgbLocks::default())
+    } else {
+        let rgb_locks = from_bytes(&data)
+            .map_err(|op| StorageError::StrictRetrieve(name.to_string(), op.to_string()))?;
+        Ok(rgb_locks)
+    }
+}
+
+pub async fn retrieve_reservations(sk: &str, name: &str) -> Result<RgbReservations, StorageError> {
+    let hashed_name = blake3::hash(format!("{LIB_ID_RGB}-{name}").as_bytes())
+        .to_hex()
+        .to_lowercase();
+
+    let (data, _) = retrieve(sk, &format!("{hashed_name}.c15"), vec![])
+        .await
+        .map_err(|op| StorageError::CarbonadoRetrieve(name.to_string(), op.to_string()))?;
+
+    if data.is_empty() {
+        Ok(RgbReservations::default())
+    } else {
+        let rgb_reservations = from_bytes(&data)
+            .map_err(|op| StorageError::StrictRetrieve(name.to_string(), op.to_string()))?;
+        Ok(rgb_reservations)
+    }
+}
+
+pub async fn retrieve_anchors(sk: &str, name: &str) -> Result<RgbAnchors, StorageError> {
+    let hashed_name = blake3::hash(format!("{LIB_ID_RGB}-{name}").as_bytes())
+        .to_hex()
+        .to_lowercase();
+
+    let (data, _) = retrieve(sk, &format!("{hashed_name}.c15"), vec![])
+        .await
+        .map_err(|op| StorageError::CarbonadoRetrieve(name.to_string(), op.to_string()))?;
+
+    if data.is_empty() {
+        Ok(RgbAnchors::default())
+    } else {
+        let rgb_anchors = from_bytes(&data)
+            .map_err(|op| StorageError::StrictRetrieve(name.to_string(), op.to_string()))?;
+        Ok(rgb_anchors)
+    }
+}
+
 pub async fn retrieve_bids(sk: &str, name: &str) -> Result<RgbBids, StorageError> {
     let hashed_name = blake3::hash(format!("{LIB_ID_RGB}-{name}").as_bytes())
         .to_hex()
@@ -14,12 +14,16 @@ use rgbstd::{
     interface::{BuilderError, ContractSuppl, TypedState, VelocityHint},
     persistence::{Inventory, Stash},
     schema::AssignmentType,
+    stl::Amount,
 };
 use rgbwallet::{
     psbt::{PsbtDbc, RgbExt, RgbInExt, RgbOutExt},
     Beneficiary, PayError, RgbInvoice,
 };
 use seals::txout::CloseMethod;
+use strict_encoding::{tn, FieldName, TypeName};
+
+use crate::rgb::constants::{RGB_ASSET_OWNER, RGB_INFLATION_RIGHT};
 
 #[derive(Clone, Debug, Display, Error, From)]
 #[display(doc_comments)]
@@ -34,6 +38,16 @@ impl PreviousSeal {
     }
 }
 
+/// Payment of the invoices of another contract in the same witness transaction
+#[derive(Clone, Debug)]
+struct OtherPayment {
+    iface: TypeName,
+    operation: Option<TypeName>,
+    assignment: Option<FieldName>,
+    amount: u64,
+    seals: Vec<(BuilderSeal<GraphSeal>, TypedState)>,
+}
+
 #[derive(Clone, Debug, Display, Error, From, Default)]
 #[display(doc_comments)]
 pub struct NewTransferOptions {
@@ -188,22 +202,30 @@ pub trait ConsignmentEx: Inventory {
 
         // Retrieve Previous State Transtitions
         let mut previous_state_value = 0u64;
-        let mut previous_states: Vec<PreviousSeal> = vec![];
+        let mut previous_states: Vec<(BuilderSeal<GraphSeal>, TypedState)> = vec![];
+        // Invoices of other contracts, paid in the same witness transaction
+        let mut other_states = HashMap::<ContractId, OtherPayment>::new();
+        let mut other_seals: Vec<(ContractId, BuilderSeal<GraphSeal>)> = vec![];
         for invoice in options.other_invoices {
-            match invoice.owned_state {
-                TypedState::Amount(value) => {
-                    previous_state_value += value;
-                }
-                TypedState::Data(_) => {
-                    previous_state_value += 1;
+            if let Some(expiry) = invoice.expiry {
+                if expiry < Utc::now().timestamp() {
+                    return Err(PayError::InvoiceExpired);
                 }
+            }
+            let other_contract_id = invoice.contract.unwrap_or(contract_id);
+            let value = match invoice.owned_state {
+                TypedState::Amount(value) => value,
+                TypedState::Data(_) if other_contract_id == contract_id => 1,
+                // Only fungible (and same-contract data) state can be paid
                 _ => {
-                    todo!("only TypedState::Amount is currently supported")
+                    let name = invoice.assignment.as_ref().unwrap_or(assignment_name);
+                    return Err(BuilderError::InvalidStateField(name.clone()).into());
                 }
-            }
+            };
 
+            // Witness seals are kept revealed, so the new output can be spent later
             let seal = match invoice.beneficiary {
-                Beneficiary::BlindedSeal(seal) => seal,
+                Beneficiary::BlindedSeal(seal) => BuilderSeal::Concealed(seal),
                 Beneficiary::WitnessUtxo(addr) => {
                     let vout = psbt
                         .unsigned_tx
@@ -213,29 +235,46 @@ pub trait ConsignmentEx: Inventory {
                         .find(|(_, txout)| txout.script_pubkey == addr.script_pubkey())
                         .map(|(no, _)| no as u32)
                         .ok_or(PayError::NoBeneficiaryOutput)?;
-                    GraphSeal::new_vout(method, vout).to_concealed_seal()
+                    BuilderSeal::Revealed(GraphSeal::new_vout(method, vout))
                 }
             };
 
-            let prev_seal = PreviousSeal {
-                state: invoice.owned_state,
-                seal,
-            };
-            previous_states.push(prev_seal);
+            let prev_seal = (seal, invoice.owned_state);
+            other_seals.push((other_contract_id, seal));
+            if other_contract_id == contract_id {
+                previous_state_value = previous_state_value
+                    .checked_add(value)
+                    .ok_or(BuilderError::InvalidStateField(assignment_name.clone()))?;
+                previous_states.push(prev_seal);
+            } else {
+                let payment =
+                    other_states
+                        .entry(other_contract_id)
+                        .or_insert_with(|| OtherPayment {
+                            iface: invoice.iface.clone().unwrap_or(iface.clone()),
+                            operation: invoice.operation.clone(),
+                            assignment: invoice.assignment.clone(),
+                            amount: 0,
+                            seals: vec![],
+                        });
+                payment.amount = payment
+                    .amount
+                    .checked_add(value)
+                    .ok_or(BuilderError::InvalidStateField(assignment_name.clone()))?;
+                payment.seals.push(prev_seal);
+            }
         }
 
         // Add change
         let amt = match invoice.owned_state {
             TypedState::Amount(amt) => {
-                if sum_inputs < amt + previous_state_value {
-                    return Err(PayError::InsufficientState);
-                }
-
-                match sum_inputs.cmp(&amt) {
+                let total = amt
+                    .checked_add(previous_state_value)
+                    .ok_or(BuilderError::InvalidStateField(assignment_name.clone()))?;
+                match sum_inputs.cmp(&total) {
                     Ordering::Greater => {
                         let seal = output_for_assignment(suppl.as_ref(), assignment_id)?;
-                        let change = amt + previous_state_value;
-                        let change = TypedState::Amount(sum_inputs - change);
+                        let change = TypedState::Amount(sum_inputs - total);
                         main_builder = main_builder.add_raw_state(assignment_id, seal, change)?;
                         amt
                     }
@@ -243,13 +282,10 @@ pub trait ConsignmentEx: Inventory {
                     Ordering::Less => return Err(PayError::InsufficientState),
                 }
             }
-            _ => {
-                todo!("only TypedState::Amount is currently supported")
-            }
+            _ => return Err(BuilderError::InvalidStateField(assignment_name.clone()).into()),
         };
 
-        for PreviousSeal { seal, state } in previous_states.clone() {
-            let prev_seal = BuilderSeal::Concealed(seal);
+        for (prev_seal, state) in previous_states {
             main_builder = match state {
                 TypedState::Amount(value) => main_builder.add_raw_state(
                     assignment_id,
@@ -259,9 +295,7 @@ pub trait ConsignmentEx: Inventory {
                 TypedState::Data(_) => {
                     main_builder.add_raw_state(assignment_id, prev_seal, state)?
                 }
-                _ => {
-                    todo!("Only TypedState::Amount and TypedState::Data are currently supported")
-                }
+                _ => return Err(BuilderError::InvalidStateField(assignment_name.clone()).into()),
             };
         }
 
@@ -273,9 +307,7 @@ pub trait ConsignmentEx: Inventory {
             TypedState::Data(_) => main_builder
                 .add_raw_state(assignment_id, beneficiary, type_state)?
                 .complete_transition(contract_id)?,
-            _ => {
-                todo!("Only TypedState::Amount and TypedState::Data are currently supported")
-            }
+            _ => return Err(BuilderError::InvalidStateField(assignment_name.clone()).into()),
         };
 
         // 3. Prepare and self-consume other transitions
@@ -295,21 +327,75 @@ pub trait ConsignmentEx: Inventory {
             }
         }
 
-        // Construct blank transitions, self-consume them
+        // The PSBT inputs must contain the state of every other contract paid
+        if other_states.keys().any(|id| !spent_state.contains_key(id)) {
+            return Err(PayError::InsufficientState);
+        }
+
+        // Construct blank (or payment) transitions, self-consume them
         let mut other_transitions = HashMap::with_capacity(spent_state.len());
         for (id, opouts) in spent_state {
-            let mut blank_builder = self.blank_builder(id, iface.clone())?;
             // TODO: select supplement basing on the signer trust level
-            let suppl = self.contract_suppl(id).and_then(|set| set.first());
+            let suppl = self.contract_suppl(id).and_then(|set| set.first()).cloned();
+
+            let payment = match other_states.remove(&id) {
+                Some(payment) => payment,
+                None => {
+                    let mut blank_builder = self.blank_builder(id, iface.clone())?;
+                    for (opout, state) in opouts {
+                        let seal = output_for_assignment(suppl.as_ref(), opout.ty)?;
+                        blank_builder = blank_builder
+                            .add_input(opout)?
+                            .add_raw_state(opout.ty, seal, state)?;
+                    }
+
+                    other_transitions.insert(id, blank_builder.complete_transition(contract_id)?);
+                    continue;
+                }
+            };
+
+            let OtherPayment {
+                iface: other_iface,
+                operation,
+                assignment,
+                amount,
+                seals,
+            } = payment;
+            let mut other_builder = self.transition_builder(id, other_iface, operation)?;
+            let assignment_name = assignment
+                .or_else(|| other_builder.default_assignment().ok().cloned())
+                .ok_or(BuilderError::NoDefaultAssignment)?;
+            let other_assignment_id = other_builder
+                .assignments_type(&assignment_name)
+                .ok_or(BuilderError::InvalidStateField(assignment_name.clone()))?;
 
+            let mut other_inputs = 0u64;
             for (opout, state) in opouts {
-                let seal = output_for_assignment(suppl, opout.ty)?;
-                blank_builder = blank_builder
-                    .add_input(opout)?
-                    .add_raw_state(opout.ty, seal, state)?;
+                other_builder = other_builder.add_input(opout)?;
+                match state {
+                    TypedState::Amount(value) if opout.ty == other_assignment_id => {
+                        other_inputs += value;
+                    }
+                    _ => {
+                        let seal = output_for_assignment(suppl.as_ref(), opout.ty)?;
+                        other_builder = other_builder.add_raw_state(opout.ty, seal, state)?;
+                    }
+                }
+            }
+
+            if other_inputs < amount {
+                return Err(PayError::InsufficientState);
+            } else if other_inputs > amount {
+                let seal = output_for_assignment(suppl.as_ref(), other_assignment_id)?;
+                let change = TypedState::Amount(other_inputs - amount);
+                other_builder = other_builder.add_raw_state(other_assignment_id, seal, change)?;
+            }
+
+            for (seal, state) in seals {
+                other_builder = other_builder.add_raw_state(other_assignment_id, seal, state)?;
             }
 
-            other_transitions.insert(id, blank_builder.complete_transition(contract_id)?);
+            other_transitions.insert(id, other_builder.complete_transition(id)?);
         }
 
         // 4. Add transitions to PSBT
@@ -342,20 +428,19 @@ pub trait ConsignmentEx: Inventory {
             self.consume_bundle(id, bundle, witness_txid.to_byte_array().into())?;
         }
 
-        let beneficiary = match beneficiary {
-            BuilderSeal::Revealed(seal) => BuilderSeal::Revealed(
-                seal.resolve(Txid::from_byte_array(witness_txid.to_byte_array())),
-            ),
+        let witness_txid = Txid::from_byte_array(witness_txid.to_byte_array());
+        let resolve_seal = |seal: BuilderSeal<GraphSeal>| match seal {
+            BuilderSeal::Revealed(seal) => BuilderSeal::Revealed(seal.resolve(witness_txid)),
             BuilderSeal::Concealed(seal) => BuilderSeal::Concealed(seal),
         };
+        let beneficiary = resolve_seal(beneficiary);
 
         // 6.Prepare strict transfers
         let mut transfers = vec![];
         if options.strict {
             transfers.push(self.transfer(contract_id, vec![beneficiary])?);
-            for prev_seal in previous_states {
-                let transfer =
-                    self.transfer(contract_id, [BuilderSeal::Concealed(prev_seal.seal)])?;
+            for (id, seal) in other_seals {
+                let transfer = self.transfer(id, [resolve_seal(seal)])?;
                 transfers.push(transfer);
             }
         } else {
@@ -365,6 +450,208 @@ pub trait ConsignmentEx: Inventory {
 
         Ok(transfers)
     }
+
+    /// Spend the inflation rights of the PSBT inputs, minting `amount` to
+    /// the beneficiary and assigning the remaining allowance to our change.
+    ///
+    /// # Assumptions
+    ///
+    /// 1. The PSBT inputs only contain inflation rights of the contract.
+    /// 2. The PSBT is final (its inputs and outputs will not be modified).
+    #[allow(clippy::result_large_err, clippy::type_complexity)]
+    fn inflate(
+        &mut self,
+        contract_id: ContractId,
+        iface: TypeName,
+        amount: u64,
+        beneficiary: GraphSeal,
+        psbt: &mut Psbt,
+        method: CloseMethod,
+    ) -> Result<Bindle<Transfer>, PayError<Self::Error, <Self::Stash as Stash>::Error>>
+    where
+        Self::Error: From<<Self::Stash as Stash>::Error>,
+    {
+        // 1. Prepare the data
+        let mut main_builder = self.transition_builder(contract_id, iface, Some(tn!("Issue")))?;
+
+        let asset_name = FieldName::from(RGB_ASSET_OWNER);
+        let asset_id = main_builder
+            .assignments_type(&asset_name)
+            .ok_or(BuilderError::InvalidStateField(asset_name))?;
+        let inflation_name = FieldName::from(RGB_INFLATION_RIGHT);
+        let inflation_id = main_builder
+            .assignments_type(&inflation_name)
+            .ok_or(BuilderError::InvalidStateField(inflation_name))?;
+
+        let prev_outputs = psbt
+            .unsigned_tx
+            .input
+            .iter()
+            .map(|txin| txin.previous_output)
+            .map(|outpoint| Outpoint::new(outpoint.txid.to_byte_array().into(), outpoint.vout))
+            .collect::<Vec<_>>();
+
+        // NB: Here we assume that if output has derivation information it belongs to our wallet.
+        let change_output = psbt
+            .outputs
+            .iter()
+            .position(|outp| !outp.bip32_derivation.is_empty() || !outp.tap_key_origins.is_empty())
+            .map(|no| no as u32);
+
+        // 2. Prepare and self-consume transition
+        let mut allowance = 0u64;
+        for (opout, state) in self.state_for_outpoints(contract_id, prev_outputs.iter().copied())? {
+            if opout.ty != inflation_id {
+                continue;
+            }
+            if let TypedState::Amount(value) = state {
+                main_builder = main_builder.add_input(opout)?;
+                allowance += value;
+            }
+        }
+
+        if allowance < amount {
+            return Err(PayError::InsufficientState);
+        }
+
+        if allowance > amount {
+            let vout = change_output.ok_or(PayError::NoBlankOrChange(
+                VelocityHint::default(),
+                inflation_id,
+            ))?;
+            let seal = BuilderSeal::Revealed(GraphSeal::new_vout(method, vout));
+            main_builder = main_builder.add_raw_state(
+                inflation_id,
+                seal,
+                TypedState::Amount(allowance - amount),
+            )?;
+        }
+
+        let transition = main_builder
+            .add_global_state("issuedSupply", Amount::from(amount))?
+            .add_raw_state(
+                asset_id,
+                BuilderSeal::Revealed(beneficiary),
+                TypedState::Amount(amount),
+            )?
+            .complete_transition(contract_id)?;
+
+        // 3. Add transition to PSBT
+        for (input, txin) in psbt.inputs.iter_mut().zip(&psbt.unsigned_tx.input) {
+            let prevout = txin.previous_output;
+            let outpoint = Outpoint::new(prevout.txid.to_byte_array().into(), prevout.vout);
+            if prev_outputs.contains(&outpoint) {
+                input.set_rgb_consumer(contract_id, transition.id())?;
+            }
+        }
+        psbt.push_rgb_transition(transition)?;
+
+        let bundles = psbt.rgb_bundles()?;
+        psbt.rgb_bundle_to_lnpbp4()?;
+        let anchor = psbt.dbc_conclude(method)?;
+
+        // 4. Prepare transfer
+        let witness_txid = psbt.unsigned_tx.txid();
+        self.consume_anchor(anchor)?;
+        for (id, bundle) in bundles {
+            self.consume_bundle(id, bundle, witness_txid.to_byte_array().into())?;
+        }
+
+        let transfer = self.transfer(contract_id, vec![BuilderSeal::Revealed(beneficiary)])?;
+        Ok(transfer)
+    }
+
+    /// Spend the asset allocations of the PSBT inputs, destroying `amount`
+    /// and assigning the remaining state to our change output.
+    ///
+    /// # Assumptions
+    ///
+    /// 1. The PSBT inputs only contain allocations of the contract.
+    /// 2. The PSBT is final (its inputs and outputs will not be modified).
+    #[allow(clippy::result_large_err, clippy::type_complexity)]
+    fn burn(
+        &mut self,
+        contract_id: ContractId,
+        iface: TypeName,
+        amount: u64,
+        psbt: &mut Psbt,
+        method: CloseMethod,
+    ) -> Result<Bindle<Transfer>, PayError<Self::Error, <Self::Stash as Stash>::Error>>
+    where
+        Self::Error: From<<Self::Stash as Stash>::Error>,
+    {
+        // 1. Prepare the data
+        let mut main_builder = self.transition_builder(contract_id, iface, Some(tn!("Burn")))?;
+
+        let asset_name = FieldName::from(RGB_ASSET_OWNER);
+        let asset_id = main_builder
+            .assignments_type(&asset_name)
+            .ok_or(BuilderError::InvalidStateField(asset_name))?;
+
+        let prev_outputs = psbt
+            .unsigned_tx
+            .input
+            .iter()
+            .map(|txin| txin.previous_output)
+            .map(|outpoint| Outpoint::new(outpoint.txid.to_byte_array().into(), outpoint.vout))
+            .collect::<Vec<_>>();
+
+        // NB: Here we assume that if output has derivation information it belongs to our wallet.
+        let change_output = psbt
+            .outputs
+            .iter()
+            .position(|outp| !outp.bip32_derivation.is_empty() || !outp.tap_key_origins.is_empty())
+            .map(|no| no as u32)
+            .ok_or(PayError::NoBlankOrChange(VelocityHint::default(), asset_id))?;
+
+        // 2. Prepare and self-consume transition
+        let mut sum_inputs = 0u64;
+        for (opout, state) in self.state_for_outpoints(contract_id, prev_outputs.iter().copied())? {
+            if opout.ty != asset_id {
+                continue;
+            }
+            if let TypedState::Amount(value) = state {
+                main_builder = main_builder.add_input(opout)?;
+                sum_inputs += value;
+            }
+        }
+
+        if sum_inputs < amount {
+            return Err(PayError::InsufficientState);
+        }
+
+        // NB: The change is always assigned (even if zero), so the burn
+        // transition is included in the consignment.
+        let change = BuilderSeal::Revealed(GraphSeal::new_vout(method, change_output));
+        let transition = main_builder
+            .add_global_state("burnedSupply", Amount::from(amount))?
+            .add_raw_state(asset_id, change, TypedState::Amount(sum_inputs - amount))?
+            .complete_transition(contract_id)?;
+
+        // 3. Add transition to PSBT
+        for (input, txin) in psbt.inputs.iter_mut().zip(&psbt.unsigned_tx.input) {
+            let prevout = txin.previous_output;
+            let outpoint = Outpoint::new(prevout.txid.to_byte_array().into(), prevout.vout);
+            if prev_outputs.contains(&outpoint) {
+                input.set_rgb_consumer(contract_id, transition.id())?;
+            }
+        }
+        psbt.push_rgb_transition(transition)?;
+
+        let bundles = psbt.rgb_bundles()?;
+        psbt.rgb_bundle_to_lnpbp4()?;
+        let anchor = psbt.dbc_conclude(method)?;
+
+        // 4. Prepare transfer
+        let witness_txid = psbt.unsigned_tx.txid();
+        self.consume_anchor(anchor)?;
+        for (id, bundle) in bundles {
+            self.consume_bundle(id, bundle, witness_txid.to_byte_array().into())?;
+        }
+
+        let transfer = self.transfer(contract_id, vec![change])?;
+        Ok(transfer)
+    }
 }
 
 impl<I> ConsignmentEx for I where I: Inventory {}
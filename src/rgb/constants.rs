@@ -2,11 +2,23 @@
 pub const LIB_NAME_BITMASK: &str = "bitmask";
 pub const RGB_CHANGE_INDEX: &str = "0";
 pub const RGB_PSBT_TAPRET: &str = "TAPRET";
+pub const RGB_PSBT_OPRET: &str = "OPRET";
 pub const RGB_DEFAULT_NAME: &str = "default";
 pub const RGB_OLDEST_VERSION: [u8; 8] = [0; 8];
 pub const RGB_STRICT_TYPE_VERSION: [u8; 8] = *b"rgbst161";
 pub const RGB_DEFAULT_FETCH_LIMIT: u32 = 10;
 pub const BITCOIN_DEFAULT_FETCH_LIMIT: u32 = 20;
+pub const RGB_REORG_DEPTH: u32 = 10;
+// Exported PSBTs keep their inputs locked for one day (seconds)
+pub const RGB_EXPORT_LOCK_EXPIRY: i64 = 86400;
+
+// Fee Estimation (sat/vB)
+pub const RGB_MIN_FEE_RATE: f32 = 1.0;
+pub const RGB_MAX_FEE_RATE: f32 = 500.0;
+
+// Assignments
+pub const RGB_ASSET_OWNER: &str = "assetOwner";
+pub const RGB_INFLATION_RIGHT: &str = "inflationAllowance";
 
 // General Errors
 #[cfg(target_arch = "wasm32")]
@@ -1,21 +1,26 @@
-use amplify::{confinement::U32, hex::ToHex};
+use amplify::{confinement::U32, hex::ToHex, Wrapper};
 use bech32::{encode, ToBase32};
 use rgb::{Resolver, RgbWallet};
 use rgbstd::{
+    containers::{Bindle, Contract},
     contract::ContractId,
-    interface::{rgb21::TokenData, IfaceId, IfacePair},
+    interface::{rgb21::TokenData, ContractIface, IfaceId, IfacePair},
     persistence::{Inventory, InventoryInconsistency, StashInconsistency, Stock},
-    stl::{ContractData, DivisibleAssetSpec, RicardianContract, Timestamp},
+    stl::{
+        Amount, ContractData, Details, DivisibleAssetSpec, Name, Precision, RicardianContract,
+        Timestamp,
+    },
 };
 use std::str::FromStr;
 use strict_encoding::{FieldName, StrictDeserialize, StrictSerialize};
 
 use crate::rgb::{
-    resolvers::ResolveSpent, structs::ContractBoilerplate, wallet::contract_allocations,
+    constants::RGB_INFLATION_RIGHT, resolvers::ResolveSpent, structs::ContractBoilerplate,
+    wallet::contract_allocations,
 };
 use crate::structs::{
-    AllocationValue, ContractFormats, ContractMediaDetail, ContractResponse, GenesisFormats,
-    MediaInfo,
+    AllocationDetail, AllocationValue, ContractFormats, ContractMediaDetail, ContractResponse,
+    GenesisFormats, MediaInfo, UDADetail,
 };
 
 use super::{
@@ -61,9 +66,42 @@ pub fn export_boilerplate(
         .contract_iface(contract_id, iface_id.to_owned())
         .expect("invalid contracts state");
 
+    let specs = extract_specs(&contract_id.to_string(), &contract_iface)?;
+
+    Ok(ContractBoilerplate {
+        contract_id: contract_id.to_string(),
+        iface_id: iface_id.to_string(),
+        precision: specs.precision,
+    })
+}
+
+struct ContractSpecs {
+    ticker: String,
+    name: String,
+    details: String,
+    precision: u8,
+}
+
+/// RGB20/RGB21 store the asset specification in the `spec` global,
+/// RGB25/CFA splits it into `name`, `details` and `precision`
+fn extract_specs(
+    contract_id: &str,
+    contract_iface: &ContractIface,
+) -> Result<ContractSpecs, ExportContractError> {
     let ty: FieldName = FieldName::from("spec");
-    let specs = match contract_iface.global(ty) {
-        Ok(values) => DivisibleAssetSpec::from_strict_val_unchecked(&values[0]),
+    if let Ok(values) = contract_iface.global(ty) {
+        let specs = DivisibleAssetSpec::from_strict_val_unchecked(&values[0]);
+        return Ok(ContractSpecs {
+            ticker: specs.ticker().into(),
+            name: specs.name().into(),
+            details: specs.details().unwrap_or_default().into(),
+            precision: specs.precision.into(),
+        });
+    }
+
+    let ty: FieldName = FieldName::from("name");
+    let name = match contract_iface.global(ty) {
+        Ok(values) => Name::from_strict_val_unchecked(&values[0]),
         Err(err) => {
             return Err(ExportContractError::StrictInconsistency(
                 contract_id.to_string(),
@@ -72,13 +110,45 @@ pub fn export_boilerplate(
         }
     };
 
-    Ok(ContractBoilerplate {
-        contract_id: contract_id.to_string(),
-        iface_id: iface_id.to_string(),
-        precision: specs.precision.into(),
+    let ty: FieldName = FieldName::from("precision");
+    let precision = match contract_iface.global(ty) {
+        Ok(values) => Precision::from_strict_val_unchecked(&values[0]),
+        Err(err) => {
+            return Err(ExportContractError::StrictInconsistency(
+                contract_id.to_string(),
+                err.to_string(),
+            ))
+        }
+    };
+
+    let mut details = String::new();
+    let ty: FieldName = FieldName::from("details");
+    if let Ok(values) = contract_iface.global(ty) {
+        if let Some(value) = values.first() {
+            details = Details::from_strict_val_unchecked(value).to_string();
+        }
+    }
+
+    Ok(ContractSpecs {
+        ticker: String::new(),
+        name: name.to_string(),
+        details,
+        precision: precision.into(),
     })
 }
 
+/// Sum of the supply destroyed by `Burn` transitions (only RGB20)
+pub fn burned_supply(contract_iface: &ContractIface) -> u64 {
+    let ty: FieldName = FieldName::from("burnedSupply");
+    match contract_iface.global(ty) {
+        Ok(values) => values
+            .iter()
+            .map(|value| Amount::from_strict_val_unchecked(value).into_inner())
+            .sum(),
+        Err(_) => 0,
+    }
+}
+
 pub fn export_contract<T>(
     contract_id: ContractId,
     stock: &mut Stock,
@@ -112,70 +182,13 @@ where
     };
 
     let iimpl_id = iimpl.impl_id().to_string();
-    // Formats
-    let contract_serialized = match contract_bindle.to_strict_serialized::<U32>() {
-        Ok(serialized) => serialized,
-        Err(err) => {
-            return Err(ExportContractError::StrictInconsistency(
-                contr_id,
-                err.to_string(),
-            ))
-        }
-    };
-    let contract_strict = contract_serialized.to_hex();
-    let contract_legacy = match encode(
-        "rgb",
-        contract_serialized.to_base32(),
-        bech32::Variant::Bech32m,
-    ) {
-        Ok(legacy) => legacy,
-        _ => {
-            return Err(ExportContractError::StrictInconsistency(
-                contr_id,
-                "There was a problem converting baid58 to bench32".to_string(),
-            ))
-        }
-    };
-
-    let genesis_serialized = match contract_bindle.genesis.to_strict_serialized::<U32>() {
-        Ok(serialized) => serialized,
-        _ => {
-            return Err(ExportContractError::ContractFormat(
-                contr_id,
-                "bench32".to_string(),
-            ))
-        }
-    };
-
-    let genesis_strict = genesis_serialized.to_hex();
-    let genesis_legacy = match encode(
-        "rgb",
-        genesis_serialized.to_base32(),
-        bech32::Variant::Bech32m,
-    ) {
-        Ok(legacy) => legacy,
-        _ => {
-            return Err(ExportContractError::ContractFormat(
-                contr_id,
-                "bench32".to_string(),
-            ))
-        }
-    };
+    let (contract, genesis) = export_formats(&contr_id, &contract_bindle)?;
 
     let contract_iface = stock
         .contract_iface(contract_id, iface_id.to_owned())
         .expect("invalid contracts state");
 
-    let ty: FieldName = FieldName::from("spec");
-    let specs = match contract_iface.global(ty) {
-        Ok(values) => DivisibleAssetSpec::from_strict_val_unchecked(&values[0]),
-        Err(err) => {
-            return Err(ExportContractError::StrictInconsistency(
-                contr_id,
-                err.to_string(),
-            ))
-        }
-    };
+    let specs = extract_specs(&contr_id, &contract_iface)?;
 
     let ty: FieldName = FieldName::from("created");
     let created = match contract_iface.global(ty) {
@@ -188,22 +201,36 @@ where
         }
     };
 
-    let mut description = String::new();
+    // Ricardian contract terms and attached document
+    let mut terms = String::new();
+    let mut attachment = None;
     let ty_data: FieldName = FieldName::from("data");
     if let Ok(values) = contract_iface.global(ty_data) {
         let contract = ContractData::from_strict_val_unchecked(&values[0]);
-        description = contract.terms.to_string();
+        terms = contract.terms.to_string();
+        attachment = contract.media.map(|media| MediaInfo {
+            ty: media.ty.to_string(),
+            source: media.digest.to_hex(),
+        });
     };
 
     let ty_terms: FieldName = FieldName::from("terms");
     if let Ok(values) = contract_iface.global(ty_terms) {
         let contract = RicardianContract::from_strict_val_unchecked(&values[0]);
-        description = contract.to_string();
+        terms = contract.to_string();
+    };
+
+    // The terms are the description, unless the asset details are defined
+    let description = if specs.details.is_empty() {
+        terms.clone()
+    } else {
+        specs.details.clone()
     };
 
     let iface_index = match iface.name.as_str() {
         "RGB20" => 20,
         "RGB21" => 21,
+        "RGB25" => 25,
         _ => 9,
     };
 
@@ -225,7 +252,7 @@ where
             .sum();
     }
 
-    let balance_normalised = ContractAmount::with(balance, specs.precision.into()).to_string();
+    let balance_normalised = ContractAmount::with(balance, specs.precision).to_string();
     let balance_normalised = f64::from_str(&balance_normalised)
         .map_err(|_| ExportContractError::WrongValue(contr_id.clone(), balance_normalised))?;
 
@@ -242,8 +269,32 @@ where
         }
     }
 
+    // Issued supply (genesis plus secondary issuances)
+    let ty: FieldName = FieldName::from("issuedSupply");
+    if let Ok(values) = contract_iface.global(ty) {
+        supply = values
+            .iter()
+            .map(|value| Amount::from_strict_val_unchecked(value).into_inner())
+            .sum();
+    }
+
+    // Max supply (issued supply plus the remaining inflation allowance)
+    let mut max_supply = supply;
+    let ty: FieldName = FieldName::from(RGB_INFLATION_RIGHT);
+    if let Ok(allowances) = contract_iface.fungible(ty, &None) {
+        max_supply += allowances
+            .into_iter()
+            .map(|allowance| allowance.value)
+            .sum::<u64>();
+    }
+
+    // Burned and circulating supply
+    let burned_supply = burned_supply(&contract_iface);
+    let circulating_supply = supply.saturating_sub(burned_supply);
+
     // Only RGB21/UDA
     let mut meta = none!();
+    let mut tokens = vec![];
     let ty: FieldName = FieldName::from("tokens");
     if contract_iface.global(ty.clone()).is_ok() {
         if let Some(type_id) = contract_iface.iface.global_type(&ty) {
@@ -311,50 +362,184 @@ where
                         .to_string()
                         .parse()
                         .expect("invalid token_index"),
-                    ticker: specs.ticker().into(),
-                    name: specs.name().into(),
-                    description: specs.details().unwrap_or_default().into(),
+                    ticker: specs.ticker.clone(),
+                    name: specs.name.clone(),
+                    description: specs.details.clone(),
                     balance,
                     preview,
                     media,
                     allocations: allocations.clone(),
                     attachments,
+                    terms: terms.clone(),
                 };
 
                 meta = Some(single);
+            } else {
+                // RGB21 collections (each token is reported with its own allocations)
+                for token_data in tokens_data {
+                    let token_index: u32 = token_data
+                        .index
+                        .to_string()
+                        .parse()
+                        .expect("invalid token_index");
+
+                    let token_allocations: Vec<AllocationDetail> = allocations
+                        .iter()
+                        .filter(|alloc| match &alloc.value {
+                            AllocationValue::UDA(position) => position.token_index == token_index,
+                            _ => false,
+                        })
+                        .cloned()
+                        .collect();
+
+                    let token_balance = token_allocations
+                        .iter()
+                        .filter(|alloc| alloc.is_mine && !alloc.is_spent)
+                        .map(|alloc| match &alloc.value {
+                            AllocationValue::UDA(position) => position.fraction,
+                            AllocationValue::Value(value) => *value,
+                        })
+                        .sum();
+
+                    let preview = token_data.preview.map(|preview| MediaInfo {
+                        ty: preview.ty.to_string(),
+                        source: base64::encode(&preview.data),
+                    });
+
+                    let media = token_data
+                        .media
+                        .iter()
+                        .chain(token_data.attachments.values())
+                        .map(|attachment| MediaInfo {
+                            ty: attachment.ty.to_string(),
+                            source: attachment.digest.to_hex(),
+                        })
+                        .collect();
+
+                    tokens.push(UDADetail {
+                        token_index,
+                        ticker: specs.ticker.clone(),
+                        name: specs.name.clone(),
+                        description: description.clone(),
+                        balance: token_balance,
+                        preview,
+                        media,
+                        attach: None,
+                        allocations: token_allocations,
+                    });
+                }
             }
         }
+    } else if attachment.is_some() || terms != description {
+        // RGB20 and RGB25/CFA
+        meta = Some(ContractMediaDetail {
+            token_index: 0,
+            ticker: specs.ticker.clone(),
+            name: specs.name.clone(),
+            description: description.clone(),
+            balance,
+            preview: None,
+            media: attachment,
+            attachments: vec![],
+            allocations: allocations.clone(),
+            terms,
+        });
     }
 
     let resp = ContractResponse {
         contract_id: contr_id,
         iimpl_id,
         iface: iface.name.to_string(),
-        ticker: specs.ticker().into(),
-        name: specs.name().into(),
+        ticker: specs.ticker,
+        name: specs.name,
         description,
-        precision: specs.precision.into(),
+        precision: specs.precision,
         supply,
+        max_supply,
+        burned_supply,
+        circulating_supply,
         balance,
         balance_normalised,
         allocations,
         created: created.into(),
-        contract: ContractFormats {
-            legacy: contract_legacy,
-            strict: contract_strict,
-            armored: contract_bindle.to_string(),
-        },
-        genesis: GenesisFormats {
-            legacy: genesis_legacy,
-            strict: genesis_strict,
-            armored: "".to_string(),
-        },
+        contract,
+        genesis,
         meta,
+        tokens,
     };
 
     Ok(resp)
 }
 
+/// Serialize the contract and its genesis in all supported formats
+pub fn export_formats(
+    contract_id: &str,
+    contract_bindle: &Bindle<Contract>,
+) -> Result<(ContractFormats, GenesisFormats), ExportContractError> {
+    let contr_id = contract_id.to_string();
+    let contract_serialized = match contract_bindle.to_strict_serialized::<U32>() {
+        Ok(serialized) => serialized,
+        Err(err) => {
+            return Err(ExportContractError::StrictInconsistency(
+                contr_id,
+                err.to_string(),
+            ))
+        }
+    };
+    let contract_strict = contract_serialized.to_hex();
+    let contract_legacy = match encode(
+        "rgb",
+        contract_serialized.to_base32(),
+        bech32::Variant::Bech32m,
+    ) {
+        Ok(legacy) => legacy,
+        _ => {
+            return Err(ExportContractError::StrictInconsistency(
+                contr_id,
+                "There was a problem converting baid58 to bench32".to_string(),
+            ))
+        }
+    };
+
+    let genesis_serialized = match contract_bindle.genesis.to_strict_serialized::<U32>() {
+        Ok(serialized) => serialized,
+        _ => {
+            return Err(ExportContractError::ContractFormat(
+                contr_id,
+                "bench32".to_string(),
+            ))
+        }
+    };
+
+    let genesis_strict = genesis_serialized.to_hex();
+    let genesis_legacy = match encode(
+        "rgb",
+        genesis_serialized.to_base32(),
+        bech32::Variant::Bech32m,
+    ) {
+        Ok(legacy) => legacy,
+        _ => {
+            return Err(ExportContractError::ContractFormat(
+                contr_id,
+                "bench32".to_string(),
+            ))
+        }
+    };
+
+    let contract = ContractFormats {
+        legacy: contract_legacy,
+        strict: contract_strict,
+        armored: contract_bindle.to_string(),
+    };
+    let genesis = GenesisFormats {
+        legacy: genesis_legacy,
+        strict: genesis_strict,
+        armored: "".to_string(),
+    };
+
+    Ok((contract, genesis))
+}
+
 pub async fn extract_metadata(
     metadata: ContractMediaDetail,
 ) -> Result<ContractMediaDetail, ProxyError> {
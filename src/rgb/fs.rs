@@ -1,22 +1,27 @@
 use rgbstd::persistence::Stock;
 
 use crate::constants::storage_keys::{
-    ASSETS_BIDS, ASSETS_OFFERS, ASSETS_STOCK, ASSETS_TRANSFERS, ASSETS_WALLETS, MARKETPLACE_OFFERS,
+    ASSETS_ANCHORS, ASSETS_BIDS, ASSETS_INVOICES, ASSETS_LOCKS, ASSETS_OFFERS, ASSETS_RESERVATIONS,
+    ASSETS_STOCK, ASSETS_TRANSFERS, ASSETS_TRANSFERS_ARCHIVE, ASSETS_WALLETS, MARKETPLACE_OFFERS,
 };
 use crate::rgb::{
     carbonado::{
-        cdrt_retrieve_wallets, cdrt_store_wallets, retrieve_bids as retrieve_rgb_bids,
-        retrieve_offers as retrieve_rgb_offers,
-        retrieve_public_offers as retrieve_rgb_public_offers, retrieve_stock as retrieve_rgb_stock,
+        cdrt_retrieve_wallets, cdrt_store_wallets, retrieve_anchors as retrieve_rgb_anchors,
+        retrieve_bids as retrieve_rgb_bids, retrieve_invoices as retrieve_rgb_invoices,
+        retrieve_locks as retrieve_rgb_locks, retrieve_offers as retrieve_rgb_offers,
+        retrieve_public_offers as retrieve_rgb_public_offers,
+        retrieve_reservations as retrieve_rgb_reservations, retrieve_stock as retrieve_rgb_stock,
         retrieve_swap_offer_bid as retrieve_rgb_swap_offer_bid,
         retrieve_transfers as retrieve_rgb_transfers, retrieve_wallets,
-        store_bids as store_rgb_bids, store_offers as store_rgb_offers,
-        store_public_offers as store_rgb_public_offers, store_stock as store_rgb_stock,
+        store_anchors as store_rgb_anchors, store_bids as store_rgb_bids,
+        store_invoices as store_rgb_invoices, store_locks as store_rgb_locks,
+        store_offers as store_rgb_offers, store_public_offers as store_rgb_public_offers,
+        store_reservations as store_rgb_reservations, store_stock as store_rgb_stock,
         store_swap_offer_bid, store_transfers as store_rgb_transfer, store_wallets,
     },
     crdt::LocalRgbAccount,
     crdt::{LocalRgbOfferBid, LocalRgbOffers},
-    structs::{RgbAccountV1, RgbTransfersV1},
+    structs::{RgbAccountV1, RgbAnchors, RgbInvoices, RgbLocks, RgbReservations, RgbTransfersV1},
     swap::{RgbBids, RgbOffers},
 };
 
@@ -39,6 +44,14 @@ pub enum RgbPersistenceError {
     RetrieveSwapBids(String),
     // Retrieve Public Offers Error. {0}
     RetrievePublicOffers(String),
+    // Retrieve Invoices Error. {0}
+    RetrieveRgbInvoices(String),
+    // Retrieve Locks Error. {0}
+    RetrieveRgbLocks(String),
+    // Retrieve Reservations Error. {0}
+    RetrieveRgbReservations(String),
+    // Retrieve Anchors Error. {0}
+    RetrieveRgbAnchors(String),
     // Store Stock Error. {0}
     WriteStock(String),
     // Store RgbAccountV1 Error. {0}
@@ -55,6 +68,14 @@ pub enum RgbPersistenceError {
     WriteRgbPublicOffers(String),
     // Store Swap Bid Error. {0}
     WriteSwapBids(String),
+    // Store Invoices Error. {0}
+    WriteRgbInvoices(String),
+    // Store Locks Error. {0}
+    WriteRgbLocks(String),
+    // Store Reservations Error. {0}
+    WriteRgbReservations(String),
+    // Store Anchors Error. {0}
+    WriteRgbAnchors(String),
 }
 
 pub async fn retrieve_stock(sk: &str) -> Result<Stock, RgbPersistenceError> {
@@ -73,6 +94,15 @@ pub async fn retrieve_transfers(sk: &str) -> Result<RgbTransfersV1, RgbPersisten
     Ok(rgb_account)
 }
 
+/// Accepted transfers (kept for the transfer history)
+pub async fn retrieve_transfers_archive(sk: &str) -> Result<RgbTransfersV1, RgbPersistenceError> {
+    let rgb_archive = retrieve_rgb_transfers(sk, ASSETS_TRANSFERS_ARCHIVE)
+        .await
+        .map_err(|op| RgbPersistenceError::RetrieveRgbTransfersV1(op.to_string()))?;
+
+    Ok(rgb_archive)
+}
+
 pub async fn retrieve_account(sk: &str) -> Result<RgbAccountV1, RgbPersistenceError> {
     let rgb_account = retrieve_wallets(sk, ASSETS_WALLETS)
         .await
@@ -125,6 +155,38 @@ pub async fn retrieve_bids(sk: &str) -> Result<RgbBids, RgbPersistenceError> {
     Ok(bids)
 }
 
+pub async fn retrieve_invoices(sk: &str) -> Result<RgbInvoices, RgbPersistenceError> {
+    let invoices = retrieve_rgb_invoices(sk, ASSETS_INVOICES)
+        .await
+        .map_err(|op| RgbPersistenceError::RetrieveRgbInvoices(op.to_string()))?;
+
+    Ok(invoices)
+}
+
+pub async fn retrieve_locks(sk: &str) -> Result<RgbLocks, RgbPersistenceError> {
+    let locks = retrieve_rgb_locks(sk, ASSETS_LOCKS)
+        .await
+        .map_err(|op| RgbPersistenceError::RetrieveRgbLocks(op.to_string()))?;
+
+    Ok(locks)
+}
+
+pub async fn retrieve_reservations(sk: &str) -> Result<RgbReservations, RgbPersistenceError> {
+    let reservations = retrieve_rgb_reservations(sk, ASSETS_RESERVATIONS)
+        .await
+        .map_err(|op| RgbPersistenceError::RetrieveRgbReservations(op.to_string()))?;
+
+    Ok(reservations)
+}
+
+pub async fn retrieve_anchors(sk: &str) -> Result<RgbAnchors, RgbPersistenceError> {
+    let anchors = retrieve_rgb_anchors(sk, ASSETS_ANCHORS)
+        .await
+        .map_err(|op| RgbPersistenceError::RetrieveRgbAnchors(op.to_string()))?;
+
+    Ok(anchors)
+}
+
 pub async fn retrieve_stock_account(
     sk: &str,
 ) -> Result<(Stock, RgbAccountV1), RgbPersistenceError> {
@@ -162,6 +224,15 @@ pub async fn store_transfers(
         .map_err(|op| RgbPersistenceError::WriteRgbTransfersV1(op.to_string()))
 }
 
+pub async fn store_transfers_archive(
+    sk: &str,
+    transfers: RgbTransfersV1,
+) -> Result<(), RgbPersistenceError> {
+    store_rgb_transfer(sk, ASSETS_TRANSFERS_ARCHIVE, &transfers)
+        .await
+        .map_err(|op| RgbPersistenceError::WriteRgbTransfersV1(op.to_string()))
+}
+
 pub async fn store_account(sk: &str, account: RgbAccountV1) -> Result<(), RgbPersistenceError> {
     store_wallets(sk, ASSETS_WALLETS, &account)
         .await
@@ -186,6 +257,36 @@ pub async fn store_bids(sk: &str, rgb_bids: RgbBids) -> Result<(), RgbPersistenc
         .map_err(|op| RgbPersistenceError::WriteRgbBids(op.to_string()))
 }
 
+pub async fn store_invoices(
+    sk: &str,
+    rgb_invoices: RgbInvoices,
+) -> Result<(), RgbPersistenceError> {
+    store_rgb_invoices(sk, ASSETS_INVOICES, &rgb_invoices)
+        .await
+        .map_err(|op| RgbPersistenceError::WriteRgbInvoices(op.to_string()))
+}
+
+pub async fn store_locks(sk: &str, rgb_locks: RgbLocks) -> Result<(), RgbPersistenceError> {
+    store_rgb_locks(sk, ASSETS_LOCKS, &rgb_locks)
+        .await
+        .map_err(|op| RgbPersistenceError::WriteRgbLocks(op.to_string()))
+}
+
+pub async fn store_reservations(
+    sk: &str,
+    rgb_reservations: RgbReservations,
+) -> Result<(), RgbPersistenceError> {
+    store_rgb_reservations(sk, ASSETS_RESERVATIONS, &rgb_reservations)
+        .await
+        .map_err(|op| RgbPersistenceError::WriteRgbReservations(op.to_string()))
+}
+
+pub async fn store_anchors(sk: &str, rgb_anchors: RgbAnchors) -> Result<(), RgbPersistenceError> {
+    store_rgb_anchors(sk, ASSETS_ANCHORS, &rgb_anchors)
+        .await
+        .map_err(|op| RgbPersistenceError::WriteRgbAnchors(op.to_string()))
+}
+
 pub async fn store_swap_bids(
     sk: &str,
     name: &str,
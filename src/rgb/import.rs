@@ -5,13 +5,14 @@ use amplify::{
     hex::FromHex,
 };
 use bech32::{decode, FromBase32};
-use rgb_schemata::{nia_rgb20, nia_schema, uda_rgb21, uda_schema};
+use rgb_schemata::{cfa_rgb25, cfa_schema, nia_rgb20, nia_schema, uda_rgb21, uda_schema};
 use rgbstd::{
-    containers::{Bindle, Contract},
+    containers::{Bindle, BindleContent, Contract},
     contract::Genesis,
-    interface::{rgb20, rgb21, IfacePair},
+    interface::{rgb20, rgb21, rgb25, Iface, IfaceImpl, IfacePair},
     persistence::{Inventory, Stash, Stock},
     resolvers::ResolveHeight,
+    schema::SubSchema,
     validation::ResolveTx,
 };
 use strict_encoding::StrictDeserialize;
@@ -23,6 +24,15 @@ use crate::structs::AssetType;
 // TODO: Complete errors
 pub enum ImportContractError {}
 
+#[derive(Clone, Eq, PartialEq, Debug, Display, Error, From)]
+#[display(doc_comments)]
+pub enum ImportSchemaError {
+    /// The {0} data cannot be decoded. {1}
+    WrongFormat(String, String),
+    /// The {0} cannot be imported into the stock. {1}
+    NoImport(String, String),
+}
+
 pub fn import_contract<R>(
     contract: &str,
     asset_type: AssetType,
@@ -98,6 +108,7 @@ pub fn contract_from_genesis(
     let (schema, iface, iimpl) = match asset_type {
         AssetType::RGB20 => (nia_schema(), rgb20(), nia_rgb20()),
         AssetType::RGB21 => (uda_schema(), rgb21(), uda_rgb21()),
+        AssetType::RGB25 => (cfa_schema(), rgb25(), cfa_rgb25()),
         _ => (nia_schema(), rgb20(), nia_rgb20()),
     };
 
@@ -114,3 +125,54 @@ pub fn contract_from_genesis(
 
     contract
 }
+
+/// Import a schema (armored or strict hex) into the stock
+pub fn import_schema(data: &str, stock: &mut Stock) -> Result<SubSchema, ImportSchemaError> {
+    let schema = bindle_content_from_str::<SubSchema>(data, "schema")?;
+    stock
+        .import_schema(schema.clone())
+        .map_err(|err| ImportSchemaError::NoImport("schema".to_string(), err.to_string()))?;
+
+    Ok(schema)
+}
+
+/// Import an interface (armored or strict hex) into the stock
+pub fn import_iface(data: &str, stock: &mut Stock) -> Result<Iface, ImportSchemaError> {
+    let iface = bindle_content_from_str::<Iface>(data, "interface")?;
+    stock
+        .import_iface(iface.clone())
+        .map_err(|err| ImportSchemaError::NoImport("interface".to_string(), err.to_string()))?;
+
+    Ok(iface)
+}
+
+/// Import an interface implementation (armored or strict hex) into the stock.
+/// The schema and the interface must be imported before.
+pub fn import_iface_impl(data: &str, stock: &mut Stock) -> Result<IfaceImpl, ImportSchemaError> {
+    let iimpl = bindle_content_from_str::<IfaceImpl>(data, "implementation")?;
+    stock.import_iface_impl(iimpl.clone()).map_err(|err| {
+        ImportSchemaError::NoImport("implementation".to_string(), err.to_string())
+    })?;
+
+    Ok(iimpl)
+}
+
+fn bindle_content_from_str<T>(data: &str, kind: &str) -> Result<T, ImportSchemaError>
+where
+    T: BindleContent,
+{
+    if data.starts_with("-----BEGIN RGB") {
+        let bindle = Bindle::<T>::from_str(data)
+            .map_err(|err| ImportSchemaError::WrongFormat(kind.to_string(), err.to_string()))?;
+        return Ok(bindle.unbindle());
+    }
+
+    let serialized = Vec::<u8>::from_hex(data)
+        .map_err(|err| ImportSchemaError::WrongFormat(kind.to_string(), err.to_string()))?;
+    let confined: Confined<Vec<u8>, 0, { U32 }> =
+        Confined::try_from_iter(serialized.iter().copied())
+            .map_err(|err| ImportSchemaError::WrongFormat(kind.to_string(), err.to_string()))?;
+
+    T::from_strict_serialized::<{ U32 }>(confined)
+        .map_err(|err| ImportSchemaError::WrongFormat(kind.to_string(), err.to_string()))
+}
@@ -1,29 +1,45 @@
 use amplify::{
     confinement::{Confined, SmallBlob},
+    hex::ToHex,
     Wrapper,
 };
+use bitcoin_30::psbt::Psbt as PSBT;
 use bp::{seals::txout::ExplicitSeal, Chain, Txid};
-use rgb_schemata::{nia_rgb20, nia_schema, uda_rgb21, uda_schema};
+use indexmap::IndexMap;
+use psbt::{serialize::Serialize, Psbt};
+use rgb_schemata::{cfa_rgb25, cfa_schema, nia_rgb20, nia_schema, uda_rgb21, uda_schema};
 use rgbstd::{
-    containers::Contract,
-    contract::GenesisSeal,
+    containers::{Bindle, Contract, Transfer},
+    contract::{ContractId, GenesisSeal, GraphSeal},
     interface::{
         rgb20, rgb21,
-        rgb21::{Allocation, EmbeddedMedia, OwnedFraction, TokenData, TokenIndex},
-        BuilderError, ContractBuilder,
+        rgb21::{rgb21_stl, Allocation, EmbeddedMedia, OwnedFraction, TokenData, TokenIndex},
+        rgb25, BuilderError, ContractBuilder,
     },
-    persistence::{Inventory, Stash, Stock},
+    persistence::{Inventory, SchemaIfaces, Stash, Stock},
     resolvers::ResolveHeight,
+    schema::{SchemaId, StateSchema, SubSchema},
     stl::{
-        Amount, Attachment, ContractData, DivisibleAssetSpec, MediaType, Precision,
-        RicardianContract, Timestamp,
+        Amount, Attachment, ContractData, Details, DivisibleAssetSpec, MediaType, Name, Precision,
+        RicardianContract, StandardTypes, Timestamp,
     },
     validation::{Failure, ResolveTx},
 };
 use std::str::FromStr;
-use strict_types::encoding::TypeName;
+use strict_encoding::{FieldName, StrictDeserialize, VariantName};
+use strict_types::{encoding::TypeName, value::EnumTag, SemId, StrictNum, StrictVal};
 
-use crate::structs::IssueMediaRequest;
+use crate::{
+    rgb::{
+        consignment::ConsignmentEx,
+        psbt::psbt_close_method,
+        schemata::{ifa_rgb20, ifa_schema, uca_rgb21, uca_schema},
+    },
+    structs::{
+        IssueAllocationRequest, IssueAssignmentRequest, IssueGlobalStateRequest,
+        IssueInflationRequest, IssueMediaRequest, IssueTokenRequest, MediaInfo,
+    },
+};
 
 #[derive(Clone, PartialEq, Debug, Display, Error, From)]
 #[display(doc_comments)]
@@ -32,10 +48,28 @@ pub enum IssueContractError {
     Forge(BuilderError),
     /// The contract interface {0} is not supported in issuer operation
     NoContractSupport(String),
+    /// The contract interface {0} does not support inflation
+    NoInflationSupport(String),
+    /// The contract interface {0} does not support multiple genesis allocations
+    NoAllocationSupport(String),
+    /// The contract interface {0} does not support collections
+    NoCollectionSupport(String),
+    /// The genesis allocations ({0}) exceed the contract supply ({1})
+    WrongAllocation(u64, u64),
+    /// The genesis allocations overflow the maximum amount
+    AllocationOverflow,
+    /// The contract {0} cannot be inflated (reason: {1})
+    NoInflation(String, String),
+    /// PSBT file cannot be decoded. {0}
+    WrongPSBT(String),
     /// The contract {0} contains failures {1:?}
     ContractInvalid(String, Vec<Failure>),
     /// The contract {0} cannot be imported (reason: {1})
     NoImport(String, String),
+    /// The schema {0} is not available in the stock
+    NoSchema(String),
+    /// The state {0} cannot be assigned (reason: {1})
+    WrongState(String, String),
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -47,8 +81,12 @@ pub fn issue_contract<T>(
     supply: u64,
     iface: &str,
     seal: &str,
+    allocations: Vec<IssueAllocationRequest>,
     network: &str,
     meta: Option<IssueMediaRequest>,
+    terms: Option<String>,
+    inflation: Option<IssueInflationRequest>,
+    collection: Vec<IssueTokenRequest>,
     resolver: &mut T,
     stock: &mut Stock,
 ) -> Result<Contract, IssueContractError>
@@ -63,10 +101,82 @@ where
         .iface_by_name(&iface_name)
         .map_err(|_| IssueContractError::Forge(BuilderError::InterfaceMismatch))?;
 
+    if inflation.is_some() && iface.name.as_str() != "RGB20" {
+        return Err(IssueContractError::NoInflationSupport(
+            iface.name.to_string(),
+        ));
+    }
+
+    if !allocations.is_empty() && iface.name.as_str() == "RGB21" {
+        return Err(IssueContractError::NoAllocationSupport(
+            iface.name.to_string(),
+        ));
+    }
+
+    if !collection.is_empty() && iface.name.as_str() != "RGB21" {
+        return Err(IssueContractError::NoCollectionSupport(
+            iface.name.to_string(),
+        ));
+    }
+
+    // Genesis Allocations (the issuer seal receives the unallocated supply)
+    let allocated = match allocations
+        .iter()
+        .try_fold(0u64, |total, alloc| total.checked_add(alloc.amount))
+    {
+        Some(allocated) => allocated,
+        _ => return Err(IssueContractError::AllocationOverflow),
+    };
+    if allocated > supply {
+        return Err(IssueContractError::WrongAllocation(allocated, supply));
+    }
+
+    let mut owners = vec![];
+    if supply > allocated || allocations.is_empty() {
+        owners.push((seal.to_string(), supply - allocated));
+    }
+    owners.extend(
+        allocations
+            .into_iter()
+            .map(|alloc| (alloc.seal, alloc.amount)),
+    );
+
     let contract_issued = match iface.name.as_str() {
-        "RGB20" => {
-            issue_fungible_asset(ticker, name, description, precision, supply, seal, network)
-        }
+        "RGB20" => match inflation {
+            Some(inflation) => issue_inflatable_asset(
+                ticker,
+                name,
+                description,
+                precision,
+                supply,
+                owners,
+                network,
+                meta,
+                terms,
+                inflation,
+            ),
+            None => issue_fungible_asset(
+                ticker,
+                name,
+                description,
+                precision,
+                supply,
+                owners,
+                network,
+                meta,
+                terms,
+            ),
+        },
+        "RGB21" if !collection.is_empty() => issue_uda_collection(
+            ticker,
+            name,
+            description,
+            precision,
+            supply,
+            collection,
+            network,
+            terms,
+        ),
         "RGB21" => issue_uda_asset(
             ticker,
             name,
@@ -76,6 +186,17 @@ where
             seal,
             network,
             meta,
+            terms,
+        ),
+        "RGB25" => issue_collectible_fungible(
+            name,
+            description,
+            precision,
+            supply,
+            owners,
+            network,
+            meta,
+            terms,
         ),
         _ => {
             return Err(IssueContractError::NoContractSupport(
@@ -100,15 +221,322 @@ where
     Ok(resp)
 }
 
+/// Issue a contract against any schema (and interface implementation)
+/// available in the stock. The state is given as typed JSON and checked
+/// against the schema type system.
+pub fn issue_custom_contract<T>(
+    schema_id: &str,
+    iface: &str,
+    network: &str,
+    globals: Vec<IssueGlobalStateRequest>,
+    assignments: Vec<IssueAssignmentRequest>,
+    resolver: &mut T,
+    stock: &mut Stock,
+) -> Result<Contract, IssueContractError>
+where
+    T: ResolveHeight + ResolveTx,
+    T::Error: 'static,
+{
+    let schema_id = SchemaId::from_str(schema_id)
+        .map_err(|_| IssueContractError::NoSchema(schema_id.to_string()))?;
+    let iface_name = TypeName::from_str(iface)
+        .map_err(|_| IssueContractError::Forge(BuilderError::InterfaceMismatch))?;
+
+    let iface = stock
+        .iface_by_name(&iface_name)
+        .map_err(|_| IssueContractError::Forge(BuilderError::InterfaceMismatch))?
+        .clone();
+    let SchemaIfaces { schema, iimpls } = stock
+        .schema(schema_id)
+        .map_err(|_| IssueContractError::NoSchema(schema_id.to_string()))?
+        .clone();
+    let iimpl = iimpls
+        .get(&iface.iface_id())
+        .ok_or(IssueContractError::Forge(BuilderError::InterfaceMismatch))?
+        .clone();
+
+    let chain = Chain::from_str(network)
+        .map_err(|err| IssueContractError::WrongState("chain".to_string(), err.to_string()))?;
+    let mut builder = ContractBuilder::with(iface, schema.clone(), iimpl.clone())?.set_chain(chain);
+
+    // Global State
+    for global in globals {
+        let name = FieldName::from_str(&global.name)
+            .map_err(|err| IssueContractError::WrongState(global.name.clone(), err.to_string()))?;
+        let sem_id = iimpl
+            .global_type(&name)
+            .and_then(|type_id| schema.global_types.get(&type_id))
+            .map(|global_schema| global_schema.sem_id)
+            .ok_or(IssueContractError::WrongState(
+                global.name.clone(),
+                "unknown global state".to_string(),
+            ))?;
+
+        let value = typed_json_to_strict(&schema, sem_id, &global.name, global.value)?;
+        builder = value.add_global_state(builder, name)?;
+    }
+
+    // Owned State
+    for assignment in assignments {
+        let name = FieldName::from_str(&assignment.name).map_err(|err| {
+            IssueContractError::WrongState(assignment.name.clone(), err.to_string())
+        })?;
+        let seal = ExplicitSeal::<Txid>::from_str(&assignment.seal).map_err(|err| {
+            IssueContractError::WrongState(assignment.name.clone(), err.to_string())
+        })?;
+        let seal = GenesisSeal::from(seal);
+
+        builder = match (assignment.amount, assignment.data) {
+            (Some(amount), None) => builder.add_fungible_state(name, seal, amount)?,
+            (None, Some(data)) => {
+                let sem_id = match iimpl
+                    .assignments_type(&name)
+                    .and_then(|type_id| schema.owned_types.get(&type_id))
+                {
+                    Some(StateSchema::Structured(sem_id)) => *sem_id,
+                    _ => {
+                        return Err(IssueContractError::WrongState(
+                            assignment.name,
+                            "unknown structured state".to_string(),
+                        ))
+                    }
+                };
+
+                let value = typed_json_to_strict(&schema, sem_id, &assignment.name, data)?;
+                value.add_data_state(builder, name, seal)?
+            }
+            _ => {
+                return Err(IssueContractError::WrongState(
+                    assignment.name,
+                    "either amount or data must be defined".to_string(),
+                ))
+            }
+        };
+    }
+
+    let resp = builder.issue_contract()?;
+    let contract_id = resp.contract_id().to_string();
+    let resp = resp.validate(resolver).map_err(|consig| {
+        IssueContractError::ContractInvalid(
+            contract_id.clone(),
+            consig.into_validation_status().unwrap_or_default().failures,
+        )
+    })?;
+
+    stock
+        .import_contract(resp.clone(), resolver)
+        .map_err(|err| IssueContractError::NoImport(contract_id, err.to_string()))?;
+
+    Ok(resp)
+}
+
+/// Custom state typed by the RGB standard library
+///
+/// The builder only commits to real strict types, so the typed JSON is
+/// decoded into the standard type declared by the schema.
+enum StdState {
+    Amount(Amount),
+    ContractData(ContractData),
+    Details(Details),
+    DivisibleAssetSpec(DivisibleAssetSpec),
+    Name(Name),
+    Precision(Precision),
+    RicardianContract(RicardianContract),
+    Timestamp(Timestamp),
+    TokenData(TokenData),
+    Allocation(Allocation),
+}
+
+impl StdState {
+    fn add_global_state(
+        self,
+        builder: ContractBuilder,
+        name: FieldName,
+    ) -> Result<ContractBuilder, BuilderError> {
+        match self {
+            StdState::Amount(value) => builder.add_global_state(name, value),
+            StdState::ContractData(value) => builder.add_global_state(name, value),
+            StdState::Details(value) => builder.add_global_state(name, value),
+            StdState::DivisibleAssetSpec(value) => builder.add_global_state(name, value),
+            StdState::Name(value) => builder.add_global_state(name, value),
+            StdState::Precision(value) => builder.add_global_state(name, value),
+            StdState::RicardianContract(value) => builder.add_global_state(name, value),
+            StdState::Timestamp(value) => builder.add_global_state(name, value),
+            StdState::TokenData(value) => builder.add_global_state(name, value),
+            StdState::Allocation(value) => builder.add_global_state(name, value),
+        }
+    }
+
+    fn add_data_state(
+        self,
+        builder: ContractBuilder,
+        name: FieldName,
+        seal: GenesisSeal,
+    ) -> Result<ContractBuilder, BuilderError> {
+        match self {
+            StdState::Amount(value) => builder.add_data_state(name, seal, value),
+            StdState::ContractData(value) => builder.add_data_state(name, seal, value),
+            StdState::Details(value) => builder.add_data_state(name, seal, value),
+            StdState::DivisibleAssetSpec(value) => builder.add_data_state(name, seal, value),
+            StdState::Name(value) => builder.add_data_state(name, seal, value),
+            StdState::Precision(value) => builder.add_data_state(name, seal, value),
+            StdState::RicardianContract(value) => builder.add_data_state(name, seal, value),
+            StdState::Timestamp(value) => builder.add_data_state(name, seal, value),
+            StdState::TokenData(value) => builder.add_data_state(name, seal, value),
+            StdState::Allocation(value) => builder.add_data_state(name, seal, value),
+        }
+    }
+}
+
+fn typed_json_to_strict(
+    schema: &SubSchema,
+    sem_id: SemId,
+    name: &str,
+    value: serde_json::Value,
+) -> Result<StdState, IssueContractError> {
+    let value = json_to_strict_val(name, value)?;
+    let typed = schema
+        .type_system
+        .typify(value, sem_id)
+        .map_err(|err| IssueContractError::WrongState(name.to_string(), err.to_string()))?;
+    let serialized = schema
+        .type_system
+        .strict_serialize_type::<{ u16::MAX as usize }>(&typed)
+        .map_err(|err| IssueContractError::WrongState(name.to_string(), err.to_string()))?;
+
+    // Values out of the declared type (ex: numbers wider than the field) do not roundtrip
+    let checked = schema
+        .type_system
+        .strict_deserialize_type(sem_id, &serialized)
+        .map_err(|err| IssueContractError::WrongState(name.to_string(), err.to_string()))?;
+    if checked.val != typed.val {
+        return Err(IssueContractError::WrongState(
+            name.to_string(),
+            format!("value does not match the type {sem_id}"),
+        ));
+    }
+
+    let strict_err = |err: strict_encoding::DeserializeError| {
+        IssueContractError::WrongState(name.to_string(), err.to_string())
+    };
+    let types = StandardTypes::with(rgb21_stl());
+    let state = if sem_id == types.get("RGBContract.Amount") {
+        StdState::Amount(Amount::from_strict_val_unchecked(&typed.val))
+    } else if sem_id == types.get("RGBContract.ContractData") {
+        StdState::ContractData(ContractData::from_strict_val_unchecked(&typed.val))
+    } else if sem_id == types.get("RGBContract.Details") {
+        StdState::Details(Details::from_strict_val_unchecked(&typed.val))
+    } else if sem_id == types.get("RGBContract.DivisibleAssetSpec") {
+        StdState::DivisibleAssetSpec(DivisibleAssetSpec::from_strict_val_unchecked(&typed.val))
+    } else if sem_id == types.get("RGBContract.Name") {
+        StdState::Name(Name::from_strict_val_unchecked(&typed.val))
+    } else if sem_id == types.get("RGBContract.Precision") {
+        StdState::Precision(Precision::from_strict_val_unchecked(&typed.val))
+    } else if sem_id == types.get("RGBContract.RicardianContract") {
+        StdState::RicardianContract(RicardianContract::from_strict_val_unchecked(&typed.val))
+    } else if sem_id == types.get("RGBContract.Timestamp") {
+        StdState::Timestamp(Timestamp::from_strict_val_unchecked(&typed.val))
+    } else if sem_id == types.get("RGB21.TokenData") {
+        StdState::TokenData(
+            TokenData::from_strict_serialized::<{ u16::MAX as usize }>(serialized)
+                .map_err(strict_err)?,
+        )
+    } else if sem_id == types.get("RGB21.Allocation") {
+        StdState::Allocation(
+            Allocation::from_strict_serialized::<{ u16::MAX as usize }>(serialized)
+                .map_err(strict_err)?,
+        )
+    } else {
+        return Err(IssueContractError::WrongState(
+            name.to_string(),
+            format!("type {sem_id} is not a standard RGB type"),
+        ));
+    };
+
+    Ok(state)
+}
+
+fn json_to_strict_val(
+    name: &str,
+    value: serde_json::Value,
+) -> Result<StrictVal, IssueContractError> {
+    let val = match value {
+        serde_json::Value::Null => StrictVal::Unit,
+        // Booleans are the `false`/`true` variants of the declared enum (resolved by typify)
+        serde_json::Value::Bool(flag) => {
+            let variant = if flag { "true" } else { "false" };
+            StrictVal::Enum(EnumTag::Name(VariantName::from(variant)))
+        }
+        serde_json::Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+            (Some(uint), _) => StrictVal::num(uint),
+            (None, Some(int)) => StrictVal::Number(StrictNum::Int(int as i128)),
+            _ => {
+                return Err(IssueContractError::WrongState(
+                    name.to_string(),
+                    format!("unsupported number {number}"),
+                ))
+            }
+        },
+        serde_json::Value::String(text) => StrictVal::String(text),
+        serde_json::Value::Array(items) => StrictVal::List(
+            items
+                .into_iter()
+                .map(|item| json_to_strict_val(name, item))
+                .collect::<Result<_, _>>()?,
+        ),
+        serde_json::Value::Object(fields) => {
+            let mut strict_fields = IndexMap::new();
+            for (field, item) in fields {
+                let field_name = FieldName::from_str(&field)
+                    .map_err(|err| IssueContractError::WrongState(field, err.to_string()))?;
+                strict_fields.insert(field_name, json_to_strict_val(name, item)?);
+            }
+            StrictVal::Struct(strict_fields)
+        }
+    };
+
+    Ok(val)
+}
+
+/// Ricardian terms (the description, if not defined) and the hashed document
+/// attached to the contract (only the main media is stored in the contract data)
+fn contract_data(
+    description: &str,
+    terms: Option<String>,
+    meta: Option<IssueMediaRequest>,
+) -> Result<ContractData, IssueError> {
+    let terms = terms.unwrap_or(description.to_string());
+    let terms = RicardianContract::from_str(&terms).expect("invalid contract text");
+
+    let mut media = None;
+    if let Some(media_data) = meta {
+        if let Some(info) = media_data.media {
+            let digest = <[u8; 32]>::try_from(hex::decode(&info.source)?)
+                .map_err(|_| IssueError::WrongDigest(info.source.clone()))?;
+            let ty: &'static str = Box::leak(info.ty.to_string().into_boxed_str());
+
+            media = Some(Attachment {
+                ty: MediaType::with(ty),
+                digest,
+            });
+        }
+    }
+
+    Ok(ContractData { terms, media })
+}
+
 /// RGB20 interface
+#[allow(clippy::too_many_arguments)]
 fn issue_fungible_asset(
     ticker: &str,
     name: &str,
     description: &str,
     precision: u8,
     supply: u64,
-    seal: &str,
+    owners: Vec<(String, u64)>,
     network: &str,
+    meta: Option<IssueMediaRequest>,
+    terms: Option<String>,
 ) -> Result<Contract, IssueError> {
     let iface = rgb20();
     let schema = nia_schema();
@@ -120,15 +548,135 @@ fn issue_fungible_asset(
     let created = Timestamp::now();
 
     let precision = Precision::try_from(precision).expect("invalid precision");
-    let spec = DivisibleAssetSpec::new(ticker, name, precision);
-    let terms = RicardianContract::from_str(description).expect("invalid contract text");
-    let contract_data = ContractData { terms, media: None };
+    let mut spec = DivisibleAssetSpec::new(ticker, name, precision);
+    if terms.is_some() && !description.is_empty() {
+        spec.naming.details = Some(Details::from_str(description).expect("invalid details"));
+    }
+    let contract_data = contract_data(description, terms, meta)?;
+
+    let mut builder = ContractBuilder::with(iface, schema, iimpl)
+        .expect("schema fails to implement RGB20 interface")
+        .set_chain(Chain::from_str(network).expect("invalid network"))
+        .add_global_state("spec", spec)
+        .expect("invalid spec")
+        .add_global_state("created", created)
+        .expect("invalid created")
+        .add_global_state("data", contract_data)
+        .expect("invalid contract text")
+        .add_global_state("issuedSupply", Amount::from(supply))
+        .expect("invalid issued supply");
 
     // Issuer State
-    let seal = ExplicitSeal::<Txid>::from_str(seal).expect("invalid seal definition");
-    let seal = GenesisSeal::from(seal);
+    for (seal, amount) in owners {
+        let seal = ExplicitSeal::<Txid>::from_str(&seal).expect("invalid seal definition");
+        builder = builder
+            .add_fungible_state("assetOwner", GenesisSeal::from(seal), amount)
+            .expect("invalid asset amount");
+    }
+
+    let contract = builder
+        .issue_contract()
+        .expect("contract doesn't fit schema requirements");
+    Ok(contract)
+}
 
-    let contract = ContractBuilder::with(iface, schema, iimpl)
+/// RGB25 interface
+#[allow(clippy::too_many_arguments)]
+fn issue_collectible_fungible(
+    name: &str,
+    description: &str,
+    precision: u8,
+    supply: u64,
+    owners: Vec<(String, u64)>,
+    network: &str,
+    meta: Option<IssueMediaRequest>,
+    terms: Option<String>,
+) -> Result<Contract, IssueError> {
+    let iface = rgb25();
+    let schema = cfa_schema();
+    let iimpl = cfa_rgb25();
+
+    let description: &'static str = Box::leak(description.to_string().into_boxed_str());
+    let created = Timestamp::now();
+
+    let name = Name::from_str(name).expect("invalid name");
+    let precision = Precision::try_from(precision).expect("invalid precision");
+    let contract_data = contract_data(description, terms, meta)?;
+
+    let mut builder = ContractBuilder::with(iface, schema, iimpl)
+        .expect("schema fails to implement RGB25 interface")
+        .set_chain(Chain::from_str(network).expect("invalid network"))
+        .add_global_state("name", name)
+        .expect("invalid name");
+
+    // Details are optional in RGB25
+    if !description.is_empty() {
+        let details = Details::from_str(description).expect("invalid details");
+        builder = builder
+            .add_global_state("details", details)
+            .expect("invalid details");
+    }
+
+    let mut builder = builder
+        .add_global_state("precision", precision)
+        .expect("invalid precision")
+        .add_global_state("created", created)
+        .expect("invalid created")
+        .add_global_state("data", contract_data)
+        .expect("invalid contract text")
+        .add_global_state("issuedSupply", Amount::from(supply))
+        .expect("invalid issued supply");
+
+    // Issuer State
+    for (seal, amount) in owners {
+        let seal = ExplicitSeal::<Txid>::from_str(&seal).expect("invalid seal definition");
+        builder = builder
+            .add_fungible_state("assetOwner", GenesisSeal::from(seal), amount)
+            .expect("invalid asset amount");
+    }
+
+    let contract = builder
+        .issue_contract()
+        .expect("contract doesn't fit schema requirements");
+    Ok(contract)
+}
+
+/// RGB20 interface (with inflation right)
+#[allow(clippy::too_many_arguments)]
+fn issue_inflatable_asset(
+    ticker: &str,
+    name: &str,
+    description: &str,
+    precision: u8,
+    supply: u64,
+    owners: Vec<(String, u64)>,
+    network: &str,
+    meta: Option<IssueMediaRequest>,
+    terms: Option<String>,
+    inflation: IssueInflationRequest,
+) -> Result<Contract, IssueError> {
+    let iface = rgb20();
+    let schema = ifa_schema();
+    let iimpl = ifa_rgb20();
+
+    let ticker: &'static str = Box::leak(ticker.to_string().into_boxed_str());
+    let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+    let description: &'static str = Box::leak(description.to_string().into_boxed_str());
+    let created = Timestamp::now();
+
+    let precision = Precision::try_from(precision).expect("invalid precision");
+    let mut spec = DivisibleAssetSpec::new(ticker, name, precision);
+    if terms.is_some() && !description.is_empty() {
+        spec.naming.details = Some(Details::from_str(description).expect("invalid details"));
+    }
+    let contract_data = contract_data(description, terms, meta)?;
+
+    // Inflation Right
+    let inflation_seal =
+        ExplicitSeal::<Txid>::from_str(&inflation.seal).expect("invalid seal definition");
+    let inflation_seal = GenesisSeal::from(inflation_seal);
+
+    let mut builder = ContractBuilder::with(iface, schema, iimpl)
         .expect("schema fails to implement RGB20 interface")
         .set_chain(Chain::from_str(network).expect("invalid network"))
         .add_global_state("spec", spec)
@@ -138,20 +686,72 @@ fn issue_fungible_asset(
         .add_global_state("data", contract_data)
         .expect("invalid contract text")
         .add_global_state("issuedSupply", Amount::from(supply))
-        .expect("invalid issued supply")
-        .add_fungible_state("assetOwner", seal, supply)
-        .expect("invalid asset amount")
+        .expect("invalid issued supply");
+
+    // Issuer State
+    for (seal, amount) in owners {
+        let seal = ExplicitSeal::<Txid>::from_str(&seal).expect("invalid seal definition");
+        builder = builder
+            .add_fungible_state("assetOwner", GenesisSeal::from(seal), amount)
+            .expect("invalid asset amount");
+    }
+
+    let contract = builder
+        .add_fungible_state("inflationAllowance", inflation_seal, inflation.amount)
+        .expect("invalid inflation allowance")
         .issue_contract()
         .expect("contract doesn't fit schema requirements");
     Ok(contract)
 }
 
+pub fn inflate_contract(
+    contract_id: &str,
+    iface: &str,
+    amount: u64,
+    seal: &str,
+    psbt: String,
+    stock: &mut Stock,
+) -> Result<(Psbt, Bindle<Transfer>), IssueContractError> {
+    let contr_id = ContractId::from_str(contract_id).map_err(|_| {
+        IssueContractError::NoInflation(contract_id.to_string(), "invalid contract id".to_string())
+    })?;
+    let iface = TypeName::from_str(iface)
+        .map_err(|_| IssueContractError::Forge(BuilderError::InterfaceMismatch))?;
+
+    let seal = ExplicitSeal::<Txid>::from_str(seal).map_err(|_| {
+        IssueContractError::NoInflation(contract_id.to_string(), format!("invalid seal {seal}"))
+    })?;
+    let seal = GraphSeal::new(seal.method, seal.txid, seal.vout);
+
+    let psbt_file =
+        Psbt::from_str(&psbt).map_err(|err| IssueContractError::WrongPSBT(err.to_string()))?;
+    let method = psbt_close_method(&psbt_file);
+    let psbt = base64::decode(&base64::encode(&psbt_file.serialize()))
+        .map_err(|err| IssueContractError::WrongPSBT(err.to_string()))?;
+    let mut psbt_final =
+        PSBT::deserialize(&psbt).map_err(|err| IssueContractError::WrongPSBT(err.to_string()))?;
+
+    let transfer = stock
+        .inflate(contr_id, iface, amount, seal, &mut psbt_final, method)
+        .map_err(|err| IssueContractError::NoInflation(contract_id.to_string(), err.to_string()))?;
+
+    let psbt_file = Psbt::from_str(&PSBT::serialize(&psbt_final).to_hex())
+        .map_err(|err| IssueContractError::WrongPSBT(err.to_string()))?;
+    Ok((psbt_file, transfer))
+}
+
 #[derive(Clone, PartialEq, Debug, Display, Error, From)]
 #[display(doc_comments)]
 pub enum IssueError {
     RgbError(#[from] BuilderError),
 
     HexError(#[from] hex::FromHexError),
+
+    /// The media preview cannot be embedded in the contract. {0}
+    WrongPreview(String),
+
+    /// '{0}' is invalid media digest (sha256 in hexadecimal)
+    WrongDigest(String),
 }
 
 /// RGB21 interface
@@ -165,6 +765,7 @@ fn issue_uda_asset(
     seal: &str,
     network: &str,
     meta: Option<IssueMediaRequest>,
+    terms: Option<String>,
 ) -> Result<Contract, IssueError> {
     let iface = rgb21();
     let schema = uda_schema();
@@ -175,7 +776,8 @@ fn issue_uda_asset(
     let description: &'static str = Box::leak(description.to_string().into_boxed_str());
     let precision = Precision::try_from(precision).expect("invalid precision");
     let spec = DivisibleAssetSpec::new(ticker, name, precision);
-    let terms = RicardianContract::from_str(description).expect("invalid terms");
+    let terms = terms.unwrap_or(description.to_string());
+    let terms = RicardianContract::from_str(&terms).expect("invalid terms");
     let created = Timestamp::now();
     let fraction = OwnedFraction::from_inner(supply);
 
@@ -185,60 +787,13 @@ fn issue_uda_asset(
     // Toke Data
     let token_index = TokenIndex::from_inner(0);
     if let Some(media_data) = meta {
-        // Preview
-        let preview = if let Some(media_preview) = media_data.preview {
-            let ty_preview: &'static str = Box::leak(media_preview.ty.to_string().into_boxed_str());
-            let preview = base64::decode(&media_preview.source).expect("invalid preview data");
-
-            Some(EmbeddedMedia {
-                ty: MediaType::with(ty_preview),
-                data: SmallBlob::try_from_iter::<Vec<u8>>(preview).expect("invalid preview data"),
-            })
-        } else {
-            None
-        };
-
-        // Media
-        let media = if let Some(media) = media_data.media {
-            let mut digest: [u8; 32] = [0; 32];
-            digest.copy_from_slice(&hex::decode(&media.source)?);
-            let ty: &'static str = Box::leak(media.ty.to_string().into_boxed_str());
-
-            Some(Attachment {
-                ty: MediaType::with(ty),
-                digest,
-            })
-        } else {
-            None
-        };
-
-        // Attachments
-        let mut attachments = bmap![];
-        for (index, attach) in media_data.attachments.iter().enumerate() {
-            let mut digest: [u8; 32] = [0; 32];
-            digest.copy_from_slice(&hex::decode(&attach.source)?);
-            let ty: &'static str = Box::leak(attach.ty.to_string().into_boxed_str());
-
-            attachments.insert(
-                index as u8,
-                Attachment {
-                    ty: MediaType::with(ty),
-                    digest,
-                },
-            );
-        }
-
-        let attachments = Confined::from_collection_unsafe(attachments);
-        let naming = spec.naming.clone();
-        let token_data = TokenData {
-            index: token_index,
-            name: Some(naming.name),
-            ticker: Some(naming.ticker),
-            preview,
-            media,
-            attachments,
-            ..Default::default()
-        };
+        let token_data = token_data(
+            token_index,
+            &spec,
+            media_data.preview,
+            media_data.media,
+            media_data.attachments,
+        )?;
 
         let allocation = Allocation::with(token_index, fraction);
         tokens_data.push(token_data);
@@ -275,3 +830,132 @@ fn issue_uda_asset(
         .expect("contract doesn't fit schema requirements");
     Ok(contract)
 }
+
+/// RGB21 interface (many tokens, each one allocated to its own seal)
+#[allow(clippy::too_many_arguments)]
+fn issue_uda_collection(
+    ticker: &str,
+    name: &str,
+    description: &str,
+    precision: u8,
+    supply: u64,
+    collection: Vec<IssueTokenRequest>,
+    network: &str,
+    terms: Option<String>,
+) -> Result<Contract, IssueError> {
+    let iface = rgb21();
+    let schema = uca_schema();
+    let iimpl = uca_rgb21();
+
+    let ticker: &'static str = Box::leak(ticker.to_string().into_boxed_str());
+    let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+    let precision = Precision::try_from(precision).expect("invalid precision");
+    let spec = DivisibleAssetSpec::new(ticker, name, precision);
+    let terms = terms.unwrap_or(description.to_string());
+    let terms = RicardianContract::from_str(&terms).expect("invalid terms");
+    let created = Timestamp::now();
+    let fraction = OwnedFraction::from_inner(supply);
+
+    let mut contract = ContractBuilder::with(iface, schema, iimpl)
+        .expect("schema fails to implement RGB21 interface")
+        .set_chain(Chain::from_str(network).expect("invalid network"))
+        .add_global_state("spec", spec.clone())
+        .expect("invalid spec")
+        .add_global_state("created", created)
+        .expect("invalid created")
+        .add_global_state("terms", terms)
+        .expect("invalid contract text");
+
+    for (index, token) in collection.into_iter().enumerate() {
+        let token_index = TokenIndex::from_inner(index as u32);
+        let token_data = token_data(
+            token_index,
+            &spec,
+            token.preview,
+            token.media,
+            token.attachments,
+        )?;
+
+        let seal = ExplicitSeal::<Txid>::from_str(&token.seal).expect("invalid seal definition");
+        contract = contract
+            .add_global_state("tokens", token_data)
+            .expect("invalid tokens")
+            .add_data_state(
+                "assetOwner",
+                GenesisSeal::from(seal),
+                Allocation::with(token_index, fraction),
+            )
+            .expect("invalid asset blob");
+    }
+
+    let contract = contract
+        .issue_contract()
+        .expect("contract doesn't fit schema requirements");
+    Ok(contract)
+}
+
+fn token_data(
+    index: TokenIndex,
+    spec: &DivisibleAssetSpec,
+    preview: Option<MediaInfo>,
+    media: Option<MediaInfo>,
+    attachments: Vec<MediaInfo>,
+) -> Result<TokenData, IssueError> {
+    // Preview
+    let preview = if let Some(media_preview) = preview {
+        let ty_preview: &'static str = Box::leak(media_preview.ty.to_string().into_boxed_str());
+        let preview = base64::decode(&media_preview.source)
+            .map_err(|err| IssueError::WrongPreview(err.to_string()))?;
+        let data = SmallBlob::try_from_iter::<Vec<u8>>(preview)
+            .map_err(|err| IssueError::WrongPreview(err.to_string()))?;
+
+        Some(EmbeddedMedia {
+            ty: MediaType::with(ty_preview),
+            data,
+        })
+    } else {
+        None
+    };
+
+    // Media
+    let media = if let Some(media) = media {
+        let digest = <[u8; 32]>::try_from(hex::decode(&media.source)?)
+            .map_err(|_| IssueError::WrongDigest(media.source.clone()))?;
+        let ty: &'static str = Box::leak(media.ty.to_string().into_boxed_str());
+
+        Some(Attachment {
+            ty: MediaType::with(ty),
+            digest,
+        })
+    } else {
+        None
+    };
+
+    // Attachments
+    let mut attachments_data = bmap![];
+    for (index, attach) in attachments.iter().enumerate() {
+        let digest = <[u8; 32]>::try_from(hex::decode(&attach.source)?)
+            .map_err(|_| IssueError::WrongDigest(attach.source.clone()))?;
+        let ty: &'static str = Box::leak(attach.ty.to_string().into_boxed_str());
+
+        attachments_data.insert(
+            index as u8,
+            Attachment {
+                ty: MediaType::with(ty),
+                digest,
+            },
+        );
+    }
+
+    let attachments = Confined::from_collection_unsafe(attachments_data);
+    let naming = spec.naming.clone();
+    Ok(TokenData {
+        index,
+        name: Some(naming.name),
+        ticker: Some(naming.ticker),
+        preview,
+        media,
+        attachments,
+        ..Default::default()
+    })
+}
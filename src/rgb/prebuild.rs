@@ -4,6 +4,7 @@ use amplify::{confinement::Confined, hex::FromHex};
 use bech32::{decode, FromBase32};
 use bitcoin::Network;
 use bitcoin_scripts::address::AddressNetwork;
+use chrono::Utc;
 use garde::Validate;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use rgb::{RgbWallet, TerminalPath};
@@ -12,15 +13,16 @@ use rgbstd::{
     interface::TypedState,
     persistence::{Inventory, Stash, Stock},
 };
-use rgbwallet::RgbInvoice;
+use rgbwallet::{Beneficiary, RgbInvoice};
 use strict_encoding::tn;
 
 use crate::{
     bitcoin::get_swap_new_address,
     constants::{get_marketplace_fee_percentage, NETWORK},
     structs::{
-        AllocationDetail, AllocationValue, AssetType, FullRgbTransferRequest, PsbtFeeRequest,
-        PsbtInputRequest, PsbtSigHashRequest, RgbBidRequest, RgbOfferRequest, SecretString,
+        AllocationDetail, AllocationValue, AssetType, CoinSelectionStrategy,
+        FullRgbTransferRequest, PsbtFeeRequest, PsbtInputRequest, PsbtSigHashRequest,
+        RgbBidRequest, RgbOfferRequest, SecretString,
     },
     validators::RGBContext,
 };
@@ -31,14 +33,17 @@ use crate::rgb::{
     fs::RgbPersistenceError,
     prefetch::prefetch_resolver_txs,
     prefetch::{
-        prefetch_resolver_allocations, prefetch_resolver_user_utxo_status, prefetch_resolver_utxos,
+        prefetch_resolver_allocations, prefetch_resolver_fee_estimates,
+        prefetch_resolver_user_utxo_status, prefetch_resolver_utxos,
     },
-    psbt::estimate_fee,
+    psbt::{estimate_fee, estimate_fee_rate},
     resolvers::ExplorerResolver,
+    selection::{find_missing_outpoint, select_coins},
     structs::AddressAmount,
-    structs::RgbExtractTransfer,
+    structs::{RgbExtractTransfer, RgbLocks},
     swap::{get_public_offer, RgbBid, RgbOfferSwap},
     transfer::extract_transfer,
+    uri::extract_rgb_invoice,
     wallet::sync_wallet,
     wallet::{get_address, next_utxos},
     RgbSwapError, SaveTransferError, TransferError,
@@ -53,6 +58,7 @@ pub async fn prebuild_transfer_asset(
     stock: &mut Stock,
     rgb_wallet: &mut RgbWallet,
     resolver: &mut ExplorerResolver,
+    rgb_locks: &RgbLocks,
 ) -> Result<
     (
         Vec<PsbtInputRequest>,
@@ -85,17 +91,22 @@ pub async fn prebuild_transfer_asset(
         TransferError::Validation(errors)
     })?;
 
-    let target_amount = match invoice.owned_state {
-        TypedState::Amount(target_amount) => target_amount,
-        _ => {
-            let mut errors = BTreeMap::new();
-            errors.insert(
-                "rgb_invoice".to_string(),
-                "invalid rgb invoice data".to_string(),
-            );
-            return Err(TransferError::Validation(errors));
-        }
-    };
+    let network = NETWORK.read().await.to_string();
+    let mut other_invoices = vec![];
+    for other_invoice in &request.other_invoices {
+        let other_invoice = extract_rgb_invoice(other_invoice, &network)
+            .ok()
+            .and_then(|other_invoice| RgbInvoice::from_str(&other_invoice).ok())
+            .ok_or_else(|| {
+                let mut errors = BTreeMap::new();
+                errors.insert(
+                    "other_invoices".to_string(),
+                    format!("invalid rgb invoice data: {other_invoice}"),
+                );
+                TransferError::Validation(errors)
+            })?;
+        other_invoices.push(other_invoice);
+    }
 
     let FullRgbTransferRequest {
         contract_id: _,
@@ -105,13 +116,85 @@ pub async fn prebuild_transfer_asset(
         change_terminal,
         fee,
         mut bitcoin_changes,
+        other_invoices: _,
+        coin_selection,
+        close_method: _,
     } = request;
 
+    // Resolve the confirmation target into a fee rate
+    let fee = match fee {
+        PsbtFeeRequest::Target { blocks } => {
+            prefetch_resolver_fee_estimates(resolver).await;
+            let fee_rate = estimate_fee_rate(blocks, &resolver.fee_estimates)
+                .ok_or(TransferError::NoFeeEstimate(blocks))?;
+            PsbtFeeRequest::FeeRate(fee_rate)
+        }
+        fee => fee,
+    };
+
+    // Amount to be paid in each contract (the main invoice belongs to the requested contract)
+    let mut targets = BTreeMap::<ContractId, (String, u64)>::new();
+    let all_invoices = [(Some(contract_id), invoice)]
+        .into_iter()
+        .chain(other_invoices.into_iter().map(|x| (x.contract, x)));
+    for (invoice_contract, invoice) in all_invoices {
+        if let Some(expiry) = invoice.expiry {
+            if expiry < Utc::now().timestamp() {
+                let mut errors = BTreeMap::new();
+                errors.insert("rgb_invoice".to_string(), "expired rgb invoice".to_string());
+                return Err(TransferError::Validation(errors));
+            }
+        }
+
+        let target_amount = match invoice.owned_state {
+            TypedState::Amount(target_amount) => target_amount,
+            _ => {
+                let mut errors = BTreeMap::new();
+                errors.insert(
+                    "rgb_invoice".to_string(),
+                    "invalid rgb invoice data".to_string(),
+                );
+                return Err(TransferError::Validation(errors));
+            }
+        };
+
+        // Witness-vout invoices are paid to a new output of the witness transaction
+        if let Beneficiary::WitnessUtxo(address) = &invoice.beneficiary {
+            let beneficiary = address.to_string();
+            if !bitcoin_changes
+                .iter()
+                .any(|change| change.starts_with(&format!("{beneficiary}:")))
+            {
+                bitcoin_changes.push(format!("{beneficiary}:{DUST_LIMIT_SATOSHI}"));
+            }
+        }
+
+        let invoice_contract = invoice_contract.unwrap_or(contract_id);
+        let invoice_iface = invoice
+            .iface
+            .map(|iface| iface.to_string())
+            .unwrap_or(iface_name.clone());
+        targets
+            .entry(invoice_contract)
+            .or_insert((invoice_iface, 0))
+            .1 += target_amount;
+    }
+
+    if coin_selection == CoinSelectionStrategy::Privacy && targets.len() > 1 {
+        let mut errors = BTreeMap::new();
+        errors.insert(
+            "coin_selection".to_string(),
+            "privacy coin selection cannot mix contracts in the same transaction".to_string(),
+        );
+        return Err(TransferError::Validation(errors));
+    }
+
     let wildcard_terminal = "/*/*";
     let mut universal_desc = descriptor.to_string();
     for contract_type in [
         AssetType::RGB20,
         AssetType::RGB21,
+        AssetType::RGB25,
         AssetType::Contract,
         AssetType::Bitcoin,
         AssetType::Change,
@@ -128,14 +211,190 @@ pub async fn prebuild_transfer_asset(
     let mut all_unspents = vec![];
 
     // Get All Assets UTXOs
-    let contract_index = if let "RGB20" = iface_name.as_str() {
-        AssetType::RGB20
-    } else {
-        AssetType::RGB21
+    let mut assets_inputs = vec![];
+    let mut total_asset_bitcoin_unspend: u64 = 0;
+    for (contract_id, (iface_name, target_amount)) in targets {
+        total_asset_bitcoin_unspend += prebuild_asset_inputs(
+            contract_id,
+            &iface_name,
+            target_amount,
+            &universal_desc,
+            stock,
+            rgb_wallet,
+            resolver,
+            rgb_locks,
+            &coin_selection,
+            &mut assets_inputs,
+        )
+        .await?;
+    }
+
+    let mut rng = StdRng::from_entropy();
+    let rnd_amount = rng.gen_range(600..1500);
+
+    // Get All Bitcoin UTXOs
+    let total_bitcoin_spend: u64 = bitcoin_changes
+        .clone()
+        .into_iter()
+        .map(|x| {
+            let recipient = AddressAmount::from_str(&x).expect("invalid address amount format");
+            recipient.amount
+        })
+        .sum();
+    let mut bitcoin_inputs = vec![];
+
+    let bitcoin_indexes = [0, 1];
+    for bitcoin_index in bitcoin_indexes {
+        sync_wallet(bitcoin_index, rgb_wallet, resolver);
+        prefetch_resolver_utxos(
+            bitcoin_index,
+            rgb_wallet,
+            resolver,
+            Some(BITCOIN_DEFAULT_FETCH_LIMIT),
+        )
+        .await;
+        prefetch_resolver_user_utxo_status(bitcoin_index, rgb_wallet, resolver, false).await;
+
+        let mut unspent_utxos =
+            next_utxos(bitcoin_index, rgb_wallet.clone(), resolver).map_err(|_| {
+                TransferError::IO(RgbPersistenceError::RetrieveRgbAccount("".to_string()))
+            })?;
+
+        all_unspents.append(&mut unspent_utxos);
+    }
+    all_unspents.retain(|utxo| !rgb_locks.is_locked(&utxo.outpoint.to_string()));
+
+    let mut bitcoin_total = total_asset_bitcoin_unspend;
+    let selection_target = match fee {
+        PsbtFeeRequest::Value(fee_value) => fee_value + rnd_amount + total_bitcoin_spend,
+        // Increase dust limit to avoid dust change
+        PsbtFeeRequest::FeeRate(_) | PsbtFeeRequest::Target { .. } => {
+            rnd_amount + total_bitcoin_spend + DUST_LIMIT_SATOSHI
+        }
+    };
+
+    // Bitcoin inputs must exceed the spendable amount
+    let bitcoin_target = (selection_target + 1).saturating_sub(bitcoin_total);
+    let all_unspents = select_coins(
+        all_unspents,
+        bitcoin_target,
+        &coin_selection,
+        |x| x.outpoint.to_string(),
+        |x| x.amount,
+    );
+    for utxo in all_unspents {
+        let TerminalPath { app, index } = utxo.derivation.terminal;
+        let btc_input = PsbtInputRequest {
+            descriptor: universal_desc.clone(),
+            utxo: utxo.outpoint.to_string(),
+            utxo_terminal: format!("/{app}/{index}"),
+            tapret: None,
+            sigh_hash: None,
+        };
+        if !bitcoin_inputs
+            .clone()
+            .into_iter()
+            .any(|x: PsbtInputRequest| x.utxo == utxo.outpoint.to_string())
+        {
+            bitcoin_inputs.push(btc_input);
+            bitcoin_total += utxo.amount;
+        }
+    }
+
+    let all_inputs: Vec<String> = assets_inputs
+        .iter()
+        .chain(bitcoin_inputs.iter())
+        .map(|x| x.utxo.clone())
+        .collect();
+    if let Some(outpoint) = find_missing_outpoint(&coin_selection, &all_inputs) {
+        let mut errors = BTreeMap::new();
+        errors.insert(
+            "coin_selection".to_string(),
+            format!("outpoint {outpoint} is not spendable"),
+        );
+        return Err(TransferError::Validation(errors));
+    }
+
+    let (change_value, fee_value) = match fee.clone() {
+        PsbtFeeRequest::Value(fee_value) => {
+            let change_value = bitcoin_total.saturating_sub(selection_target);
+            (change_value, fee_value)
+        }
+        PsbtFeeRequest::FeeRate(fee_rate) => {
+            let mut all_inputs = assets_inputs.clone();
+            all_inputs.extend(bitcoin_inputs.clone());
+
+            let txids = all_inputs
+                .clone()
+                .into_iter()
+                .map(|x| bitcoin::Txid::from_str(&x.utxo[..64]).expect("wrong txid"))
+                .collect();
+            prefetch_resolver_txs(txids, resolver).await;
+
+            let (change_value, fee) = estimate_fee(
+                assets_inputs.clone(),
+                bitcoin_inputs.clone(),
+                bitcoin_changes.clone(),
+                fee_rate,
+                rgb_wallet,
+                Some(rnd_amount),
+                Some(change_terminal),
+                resolver,
+            )
+            .map_err(TransferError::Estimate)?;
+
+            (change_value, fee)
+        }
+        PsbtFeeRequest::Target { blocks } => return Err(TransferError::NoFeeEstimate(blocks)),
+    };
+
+    let total_spendable = fee_value + rnd_amount + total_bitcoin_spend;
+    if bitcoin_total < total_spendable {
+        return Err(TransferError::Inflation {
+            input: bitcoin_total,
+            output: total_spendable,
+        });
+    } else if change_value > 0 {
+        let network = NETWORK.read().await.to_string();
+        let network = Network::from_str(&network)
+            .map_err(|err| TransferError::WrongNetwork(err.to_string()))?;
+
+        let network = AddressNetwork::from(network);
+        // TODO: Use New Address
+        let change_address = get_address(1, 0, rgb_wallet.clone(), network)
+            .map_err(|err| TransferError::WrongNetwork(err.to_string()))?
+            .address;
+
+        let change_bitcoin = format!("{change_address}:{change_value}");
+        bitcoin_changes.push(change_bitcoin);
+    }
+
+    Ok((assets_inputs, bitcoin_inputs, bitcoin_changes, fee_value))
+}
+
+/// Select the asset UTXOs of the contract required to pay `target_amount`,
+/// returning the bitcoin amount held by the selected UTXOs
+#[allow(clippy::too_many_arguments)]
+async fn prebuild_asset_inputs(
+    contract_id: ContractId,
+    iface_name: &str,
+    target_amount: u64,
+    universal_desc: &SecretString,
+    stock: &mut Stock,
+    rgb_wallet: &mut RgbWallet,
+    resolver: &mut ExplorerResolver,
+    rgb_locks: &RgbLocks,
+    coin_selection: &CoinSelectionStrategy,
+    assets_inputs: &mut Vec<PsbtInputRequest>,
+) -> Result<u64, TransferError> {
+    let contract_index = match iface_name {
+        "RGB20" => AssetType::RGB20,
+        "RGB25" => AssetType::RGB25,
+        _ => AssetType::RGB21,
     };
 
     let iface = stock
-        .iface_by_name(&tn!(iface_name))
+        .iface_by_name(&tn!(iface_name.to_string()))
         .map_err(|_| TransferError::NoIface)?;
     let contract_iface = stock
         .contract_iface(contract_id, iface.iface_id())
@@ -155,10 +414,11 @@ pub async fn prebuild_transfer_asset(
     let contract = export_contract(contract_id, stock, resolver, &mut Some(rgb_wallet.clone()))
         .map_err(TransferError::Export)?;
 
+    // Outpoints reserved by pending transfers and open offers cannot be spent
     let allocations: Vec<AllocationDetail> = contract
         .allocations
         .into_iter()
-        .filter(|x| x.is_mine && !x.is_spent)
+        .filter(|x| x.is_mine && !x.is_spent && !rgb_locks.is_locked(&x.utxo))
         .collect();
 
     let asset_total: u64 = allocations
@@ -180,19 +440,32 @@ pub async fn prebuild_transfer_asset(
     let asset_unspent_utxos = &mut next_utxos(contract_index, rgb_wallet.clone(), resolver)
         .map_err(|_| TransferError::IO(RgbPersistenceError::RetrieveRgbAccount("".to_string())))?;
 
-    let mut asset_total = 0;
-    let mut assets_inputs = vec![];
-    let mut rng = StdRng::from_entropy();
-    let rnd_amount = rng.gen_range(600..1500);
+    let alloc_amount = |x: &AllocationDetail| match x.value {
+        AllocationValue::Value(value) => value,
+        AllocationValue::UDA(_) => 1,
+    };
+    let allocations = select_coins(
+        allocations,
+        target_amount,
+        coin_selection,
+        |x| x.utxo.clone(),
+        alloc_amount,
+    );
+
+    // Manual outpoints may not hold enough state
+    if allocations.iter().map(alloc_amount).sum::<u64>() < target_amount {
+        let mut errors = BTreeMap::new();
+        errors.insert(
+            "coin_selection".to_string(),
+            "insufficient state in the selected outpoints".to_string(),
+        );
+        return Err(TransferError::Validation(errors));
+    }
 
     let mut total_asset_bitcoin_unspend: u64 = 0;
     for alloc in allocations.into_iter() {
         match alloc.value {
-            AllocationValue::Value(alloc_value) => {
-                if asset_total >= target_amount {
-                    break;
-                }
-
+            AllocationValue::Value(_) => {
                 let input = PsbtInputRequest {
                     descriptor: universal_desc.clone(),
                     utxo: alloc.utxo.clone(),
@@ -216,7 +489,6 @@ pub async fn prebuild_transfer_asset(
                         })
                         .map(|x| x.amount)
                         .sum::<u64>();
-                    asset_total += alloc_value;
                 }
             }
             AllocationValue::UDA(_) => {
@@ -249,141 +521,7 @@ pub async fn prebuild_transfer_asset(
         }
     }
 
-    // Get All Bitcoin UTXOs
-    let total_bitcoin_spend: u64 = bitcoin_changes
-        .clone()
-        .into_iter()
-        .map(|x| {
-            let recipient = AddressAmount::from_str(&x).expect("invalid address amount format");
-            recipient.amount
-        })
-        .sum();
-    let mut bitcoin_inputs = vec![];
-
-    let bitcoin_indexes = [0, 1];
-    for bitcoin_index in bitcoin_indexes {
-        sync_wallet(bitcoin_index, rgb_wallet, resolver);
-        prefetch_resolver_utxos(
-            bitcoin_index,
-            rgb_wallet,
-            resolver,
-            Some(BITCOIN_DEFAULT_FETCH_LIMIT),
-        )
-        .await;
-        prefetch_resolver_user_utxo_status(bitcoin_index, rgb_wallet, resolver, false).await;
-
-        let mut unspent_utxos =
-            next_utxos(bitcoin_index, rgb_wallet.clone(), resolver).map_err(|_| {
-                TransferError::IO(RgbPersistenceError::RetrieveRgbAccount("".to_string()))
-            })?;
-
-        all_unspents.append(&mut unspent_utxos);
-    }
-
-    let mut bitcoin_total = total_asset_bitcoin_unspend;
-    let (change_value, fee_value) = match fee.clone() {
-        PsbtFeeRequest::Value(fee_value) => {
-            let total_spendable = fee_value + rnd_amount + total_bitcoin_spend;
-            for utxo in all_unspents {
-                if bitcoin_total > total_spendable {
-                    break;
-                } else {
-                    let TerminalPath { app, index } = utxo.derivation.terminal;
-                    let btc_input = PsbtInputRequest {
-                        descriptor: universal_desc.clone(),
-                        utxo: utxo.outpoint.to_string(),
-                        utxo_terminal: format!("/{app}/{index}"),
-                        tapret: None,
-                        sigh_hash: None,
-                    };
-                    if !bitcoin_inputs
-                        .clone()
-                        .into_iter()
-                        .any(|x: PsbtInputRequest| x.utxo == utxo.outpoint.to_string())
-                    {
-                        bitcoin_inputs.push(btc_input);
-                        bitcoin_total += utxo.amount;
-                    }
-                }
-            }
-
-            let change_value = bitcoin_total - total_spendable;
-            (change_value, fee_value)
-        }
-        PsbtFeeRequest::FeeRate(fee_rate) => {
-            // Increase dust limit to avoid dust change
-            let total_spendable = rnd_amount + total_bitcoin_spend + DUST_LIMIT_SATOSHI;
-            for utxo in all_unspents {
-                if total_spendable < bitcoin_total {
-                    break;
-                } else {
-                    let TerminalPath { app, index } = utxo.derivation.terminal;
-                    let btc_input = PsbtInputRequest {
-                        descriptor: universal_desc.clone(),
-                        utxo: utxo.outpoint.to_string(),
-                        utxo_terminal: format!("/{app}/{index}"),
-                        tapret: None,
-                        sigh_hash: None,
-                    };
-                    if !bitcoin_inputs
-                        .clone()
-                        .into_iter()
-                        .any(|x: PsbtInputRequest| x.utxo == utxo.outpoint.to_string())
-                    {
-                        bitcoin_inputs.push(btc_input);
-                        bitcoin_total += utxo.amount;
-                    }
-                }
-            }
-
-            let mut all_inputs = assets_inputs.clone();
-            all_inputs.extend(bitcoin_inputs.clone());
-
-            let txids = all_inputs
-                .clone()
-                .into_iter()
-                .map(|x| bitcoin::Txid::from_str(&x.utxo[..64]).expect("wrong txid"))
-                .collect();
-            prefetch_resolver_txs(txids, resolver).await;
-
-            let (change_value, fee) = estimate_fee(
-                assets_inputs.clone(),
-                bitcoin_inputs.clone(),
-                bitcoin_changes.clone(),
-                fee_rate,
-                rgb_wallet,
-                Some(rnd_amount),
-                Some(change_terminal),
-                resolver,
-            )
-            .map_err(TransferError::Estimate)?;
-
-            (change_value, fee)
-        }
-    };
-
-    let total_spendable = fee_value + rnd_amount + total_bitcoin_spend;
-    if bitcoin_total < total_spendable {
-        return Err(TransferError::Inflation {
-            input: bitcoin_total,
-            output: total_spendable,
-        });
-    } else if change_value > 0 {
-        let network = NETWORK.read().await.to_string();
-        let network = Network::from_str(&network)
-            .map_err(|err| TransferError::WrongNetwork(err.to_string()))?;
-
-        let network = AddressNetwork::from(network);
-        // TODO: Use New Address
-        let change_address = get_address(1, 0, rgb_wallet.clone(), network)
-            .map_err(|err| TransferError::WrongNetwork(err.to_string()))?
-            .address;
-
-        let change_bitcoin = format!("{change_address}:{change_value}");
-        bitcoin_changes.push(change_bitcoin);
-    }
-
-    Ok((assets_inputs, bitcoin_inputs, bitcoin_changes, fee_value))
+    Ok(total_asset_bitcoin_unspend)
 }
 
 pub async fn prebuild_seller_swap(
@@ -391,6 +529,7 @@ pub async fn prebuild_seller_swap(
     stock: &mut Stock,
     rgb_wallet: &mut RgbWallet,
     resolver: &mut ExplorerResolver,
+    rgb_locks: &RgbLocks,
 ) -> Result<
     (
         Vec<AllocationDetail>,
@@ -423,6 +562,7 @@ pub async fn prebuild_seller_swap(
         iface: iface_name,
         contract_amount: target_amount,
         bitcoin_changes,
+        coin_selection,
         ..
     } = request;
 
@@ -440,6 +580,7 @@ pub async fn prebuild_seller_swap(
     for contract_type in [
         AssetType::RGB20,
         AssetType::RGB21,
+        AssetType::RGB25,
         AssetType::Contract,
         AssetType::Bitcoin,
         AssetType::Change,
@@ -456,10 +597,10 @@ pub async fn prebuild_seller_swap(
     let mut all_unspents = vec![];
 
     // Get All Assets UTXOs
-    let contract_index = if let "RGB20" = iface_name.as_str() {
-        AssetType::RGB20
-    } else {
-        AssetType::RGB21
+    let contract_index = match iface_name.as_str() {
+        "RGB20" => AssetType::RGB20,
+        "RGB25" => AssetType::RGB25,
+        _ => AssetType::RGB21,
     };
 
     let iface = stock
@@ -483,10 +624,11 @@ pub async fn prebuild_seller_swap(
     let contract = export_contract(contract_id, stock, resolver, &mut Some(rgb_wallet.clone()))
         .map_err(RgbSwapError::Export)?;
 
+    // Outpoints reserved by pending transfers and open offers cannot be spent
     let allocations: Vec<AllocationDetail> = contract
         .allocations
         .into_iter()
-        .filter(|x| x.is_mine && !x.is_spent)
+        .filter(|x| x.is_mine && !x.is_spent && !rgb_locks.is_locked(&x.utxo))
         .collect();
 
     let asset_total: u64 = allocations
@@ -508,7 +650,28 @@ pub async fn prebuild_seller_swap(
     let asset_unspent_utxos = &mut next_utxos(contract_index, rgb_wallet.clone(), resolver)
         .map_err(|_| RgbSwapError::IO(RgbPersistenceError::RetrieveRgbAccount("".to_string())))?;
 
-    let mut asset_total = 0;
+    let alloc_amount = |x: &AllocationDetail| match x.value {
+        AllocationValue::Value(value) => value,
+        AllocationValue::UDA(_) => 1,
+    };
+    let allocations = select_coins(
+        allocations,
+        target_amount,
+        &coin_selection,
+        |x| x.utxo.clone(),
+        alloc_amount,
+    );
+
+    // Manual outpoints may not hold enough state
+    if allocations.iter().map(alloc_amount).sum::<u64>() < target_amount {
+        let mut errors = BTreeMap::new();
+        errors.insert(
+            "coin_selection".to_string(),
+            "insufficient state in the selected outpoints".to_string(),
+        );
+        return Err(RgbSwapError::Validation(errors));
+    }
+
     let mut assets_inputs = vec![];
     let mut assets_allocs = vec![];
 
@@ -523,11 +686,7 @@ pub async fn prebuild_seller_swap(
         let sig_hash = PsbtSigHashRequest::NonePlusAnyoneCanPay;
 
         match alloc.value {
-            AllocationValue::Value(alloc_value) => {
-                if asset_total >= target_amount {
-                    break;
-                }
-
+            AllocationValue::Value(_) => {
                 let input = PsbtInputRequest {
                     descriptor: universal_desc.clone(),
                     utxo: alloc.utxo.clone(),
@@ -556,7 +715,6 @@ pub async fn prebuild_seller_swap(
                         })
                         .map(|x| x.amount)
                         .sum::<u64>();
-                    asset_total += alloc_value;
                 }
             }
             AllocationValue::UDA(_) => {
@@ -620,34 +778,54 @@ pub async fn prebuild_seller_swap(
 
         all_unspents.append(&mut unspent_utxos);
     }
+    all_unspents.retain(|utxo| !rgb_locks.is_locked(&utxo.outpoint.to_string()));
 
     let mut bitcoin_total = total_asset_bitcoin_unspend;
     let total_spendable = total_bitcoin_spend;
 
+    // Bitcoin inputs must exceed the spendable amount
+    let bitcoin_target = (total_spendable + 1).saturating_sub(bitcoin_total);
+    let all_unspents = select_coins(
+        all_unspents,
+        bitcoin_target,
+        &coin_selection,
+        |x| x.outpoint.to_string(),
+        |x| x.amount,
+    );
     for utxo in all_unspents {
-        if bitcoin_total > total_spendable {
-            break;
-        } else {
-            let TerminalPath { app, index } = utxo.derivation.terminal;
-            let btc_input = PsbtInputRequest {
-                descriptor: universal_desc.clone(),
-                utxo: utxo.outpoint.to_string(),
-                utxo_terminal: format!("/{app}/{index}"),
-                sigh_hash: Some(PsbtSigHashRequest::NonePlusAnyoneCanPay),
-                tapret: None,
-            };
-            if !bitcoin_inputs
-                .clone()
-                .into_iter()
-                .any(|x: PsbtInputRequest| x.utxo == utxo.outpoint.to_string())
-            {
-                bitcoin_inputs.push(btc_input);
-                bitcoin_total += utxo.amount;
-            }
+        let TerminalPath { app, index } = utxo.derivation.terminal;
+        let btc_input = PsbtInputRequest {
+            descriptor: universal_desc.clone(),
+            utxo: utxo.outpoint.to_string(),
+            utxo_terminal: format!("/{app}/{index}"),
+            sigh_hash: Some(PsbtSigHashRequest::NonePlusAnyoneCanPay),
+            tapret: None,
+        };
+        if !bitcoin_inputs
+            .clone()
+            .into_iter()
+            .any(|x: PsbtInputRequest| x.utxo == utxo.outpoint.to_string())
+        {
+            bitcoin_inputs.push(btc_input);
+            bitcoin_total += utxo.amount;
         }
     }
 
-    let change_value = bitcoin_total - total_spendable;
+    let all_inputs: Vec<String> = assets_inputs
+        .iter()
+        .chain(bitcoin_inputs.iter())
+        .map(|x| x.utxo.clone())
+        .collect();
+    if let Some(outpoint) = find_missing_outpoint(&coin_selection, &all_inputs) {
+        let mut errors = BTreeMap::new();
+        errors.insert(
+            "coin_selection".to_string(),
+            format!("outpoint {outpoint} is not spendable"),
+        );
+        return Err(RgbSwapError::Validation(errors));
+    }
+
+    let change_value = bitcoin_total.saturating_sub(total_spendable);
     if bitcoin_total < total_spendable {
         return Err(RgbSwapError::Inflation {
             input: bitcoin_total,
@@ -686,6 +864,17 @@ pub async fn prebuild_buyer_swap(
         ..
     } = request;
 
+    // Resolve the confirmation target into a fee rate
+    let fee = match fee {
+        PsbtFeeRequest::Target { blocks } => {
+            prefetch_resolver_fee_estimates(resolver).await;
+            let fee_rate = estimate_fee_rate(blocks, &resolver.fee_estimates)
+                .ok_or(RgbSwapError::NoFeeEstimate(blocks))?;
+            PsbtFeeRequest::FeeRate(fee_rate)
+        }
+        fee => fee,
+    };
+
     let wildcard_terminal = "/*/*";
     let mut universal_desc = descriptor.to_string();
     for contract_type in [AssetType::Bitcoin, AssetType::Change] {
@@ -714,6 +903,7 @@ pub async fn prebuild_buyer_swap(
         AssetType::Change,
         AssetType::RGB20,
         AssetType::RGB21,
+        AssetType::RGB25,
     ];
     for derive_type in derive_indexes {
         let derive_index = derive_type.clone() as u32;
@@ -846,6 +1036,7 @@ pub async fn prebuild_buyer_swap(
 
             (change_value, fee)
         }
+        PsbtFeeRequest::Target { blocks } => return Err(RgbSwapError::NoFeeEstimate(blocks)),
     };
 
     let total_spendable = fee_value + offer.bitcoin_price;
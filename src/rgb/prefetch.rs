@@ -2,7 +2,7 @@
 #![allow(unused_variables)]
 use crate::rgb::resolvers::ExplorerResolver;
 use crate::structs::UtxoSpentStatus;
-use crate::structs::{AssetType, MediaInfo, TxStatus};
+use crate::structs::{AssetType, MediaInfo, TxFee, TxStatus};
 use amplify::{
     confinement::Confined,
     hex::{FromHex, ToHex},
@@ -451,7 +451,7 @@ pub async fn prefetch_resolver_waddress(
     let script = ScriptBuf::from_hex(&sc.script_pubkey().to_hex()).expect("invalid script");
 
     let mut scripts: BTreeMap<DeriveInfo, ScriptBuf> = BTreeMap::new();
-    let asset_indexes: Vec<u32> = [0, 1, 9, 10, 20, 21].to_vec();
+    let asset_indexes: Vec<u32> = [0, 1, 9, 10, 20, 21, 25].to_vec();
     for app in asset_indexes {
         scripts.append(&mut wallet.descr.derive(app, index..step));
     }
@@ -676,6 +676,12 @@ pub async fn prefetch_resolver_txs_status(txids: Vec<Txid>, explorer: &mut Explo
             if let Some(tx_status) = tx_resp {
                 if tx_status.confirmed {
                     status = TxStatus::Block(tx_status.block_height.unwrap_or_default());
+                    if let Some(block_time) = tx_status.block_time {
+                        explorer.txs_block_time.insert(txid, block_time);
+                    }
+                    if let Some(block_hash) = tx_status.block_hash {
+                        explorer.txs_block_hash.insert(txid, block_hash.to_string());
+                    }
                 } else {
                     status = TxStatus::Mempool;
                 }
@@ -693,6 +699,40 @@ pub async fn prefetch_resolver_txs_status(txids: Vec<Txid>, explorer: &mut Explo
     }
 }
 
+pub async fn prefetch_resolver_txs_fee(txids: Vec<Txid>, explorer: &mut ExplorerResolver) {
+    let esplora_client = EsploraBlockchain::new(&explorer.explorer_url, 1).with_concurrency(6);
+    for txid in txids {
+        let tx_resp = esplora_client
+            .client()
+            .get(&format!("{}/tx/{}", esplora_client.url(), txid))
+            .send()
+            .await;
+
+        if let Ok(tx_resp) = tx_resp {
+            if let Ok(tx_fee) = tx_resp.json::<TxFee>().await {
+                explorer.txs_fee.insert(txid, tx_fee);
+            }
+        }
+    }
+}
+
+pub async fn prefetch_resolver_tip_height(explorer: &mut ExplorerResolver) {
+    let esplora_client = EsploraBlockchain::new(&explorer.explorer_url, 1).with_concurrency(6);
+    if let Ok(height) = esplora_client.get_height().await {
+        explorer.tip_height = Some(height);
+    }
+}
+
+pub async fn prefetch_resolver_fee_estimates(explorer: &mut ExplorerResolver) {
+    let esplora_client = EsploraBlockchain::new(&explorer.explorer_url, 1).with_concurrency(6);
+    if let Ok(estimates) = esplora_client.get_fee_estimates().await {
+        explorer.fee_estimates = estimates
+            .into_iter()
+            .filter_map(|(target, fee_rate)| Some((target.parse().ok()?, fee_rate)))
+            .collect();
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 struct ExploreAsyncExt {}
 
@@ -5,8 +5,8 @@ use postcard::from_bytes;
 use strict_encoding::StrictSerialize;
 
 use crate::proxy::{
-    proxy_consig_retrieve, proxy_consig_store, proxy_media_data_store, proxy_media_retrieve,
-    proxy_metadata_retrieve,
+    media_data_digest, proxy_consig_retrieve, proxy_consig_store, proxy_media_data_store,
+    proxy_media_retrieve, proxy_metadata_retrieve,
 };
 
 use crate::proxy::ProxyServerError;
@@ -131,6 +131,18 @@ pub async fn post_media_metadata(
     Ok(data)
 }
 
+/// Same metadata of `post_media_metadata`, computed locally (nothing is stored in the proxy)
+pub async fn digest_media_metadata(
+    data: MediaItemRequest,
+    encode: MediaEncode,
+) -> Result<MediaMetadata, ProxyError> {
+    let data = media_data_digest(data, encode)
+        .await
+        .map_err(ProxyError::IO)?;
+
+    Ok(data)
+}
+
 pub async fn post_media_metadata_list(
     data: Vec<MediaItemRequest>,
     encode: MediaEncode,
@@ -1,11 +1,14 @@
-use std::{collections::BTreeSet, str::FromStr};
+use std::{
+    collections::{BTreeSet, HashMap},
+    str::FromStr,
+};
 
 use amplify::hex::{FromHex, ToHex};
 use bdk::FeeRate;
 use bitcoin::{
     blockdata::opcodes,
     hashes::{sha256, Hash},
-    psbt::TapTree,
+    psbt::{PartiallySignedTransaction, TapTree},
     schnorr::TapTweak,
     secp256k1::SECP256K1,
     util::{
@@ -19,14 +22,14 @@ use bitcoin::{EcdsaSighashType, OutPoint, Script, XOnlyPublicKey};
 use bitcoin_30::{secp256k1::SECP256K1 as SECP256K1_30, ScriptBuf};
 use bitcoin_blockchain::locks::SeqNo;
 use bitcoin_scripts::PubkeyScript;
-use bp::{dbc::tapret::TapretCommitment, Outpoint, TapScript, Vout};
+use bp::{dbc::tapret::TapretCommitment, seals::txout::CloseMethod, Outpoint, TapScript, Vout};
 use commit_verify::{mpc::Commitment, CommitVerify};
 use miniscript_crate::{Descriptor, ForEachKey, ToPublicKey};
 use psbt::{ProprietaryKey, ProprietaryKeyType, PsbtVersion};
 use rgb::{
     psbt::{
-        DbcPsbtError, TapretKeyError, PSBT_OUT_TAPRET_COMMITMENT, PSBT_OUT_TAPRET_HOST,
-        PSBT_TAPRET_PREFIX,
+        DbcPsbtError, TapretKeyError, PSBT_OPRET_PREFIX, PSBT_OUT_OPRET_COMMITMENT,
+        PSBT_OUT_OPRET_HOST, PSBT_OUT_TAPRET_COMMITMENT, PSBT_OUT_TAPRET_HOST, PSBT_TAPRET_PREFIX,
     },
     DeriveInfo, MiningStatus, Resolver, RgbDescr, RgbWallet, TerminalPath, Utxo,
 };
@@ -39,8 +42,11 @@ use wallet::{
 
 use crate::{
     debug, info,
-    rgb::{constants::RGB_PSBT_TAPRET, structs::AddressAmount},
-    structs::{AssetType, PsbtInputRequest, PsbtSigHashRequest},
+    rgb::{
+        constants::{RGB_MAX_FEE_RATE, RGB_MIN_FEE_RATE, RGB_PSBT_OPRET, RGB_PSBT_TAPRET},
+        structs::AddressAmount,
+    },
+    structs::{AssetType, PsbtInputRequest, PsbtSigHashRequest, TxFee},
 };
 
 use crate::rgb::structs::AddressFormatParseError;
@@ -49,6 +55,11 @@ use crate::rgb::structs::AddressFormatParseError;
 // prev_txid (32 bytes) + prev_vout (4 bytes) + sequence (4 bytes)
 const TXIN_BASE_WEIGHT: usize = (32 + 4 + 4) * 4;
 
+// Virtual sizes of a transaction spending taproot key-path inputs to taproot outputs
+const TX_OVERHEAD_VSIZE: u64 = 11;
+const TAPROOT_INPUT_VSIZE: u64 = 58;
+const TAPROOT_OUTPUT_VSIZE: u64 = 43;
+
 #[derive(Clone, Eq, PartialEq, Debug, Display, Error, From)]
 #[display(doc_comments)]
 pub enum CreatePsbtError {
@@ -112,6 +123,7 @@ pub fn create_psbt(
     for contract_type in [
         AssetType::RGB20,
         AssetType::RGB21,
+        AssetType::RGB25,
         AssetType::Contract,
         AssetType::Bitcoin,
         AssetType::Change,
@@ -150,11 +162,16 @@ pub fn create_psbt(
         })
         .collect();
 
-    let outputs: Vec<(PubkeyScript, u64)> = bitcoin_addresses
+    let mut outputs: Vec<(PubkeyScript, u64)> = bitcoin_addresses
         .into_iter()
         .map(|AddressAmount { address, amount }| (address.script_pubkey().into(), amount))
         .collect();
 
+    // OpRet commitments are hosted by an (empty) OP_RETURN output
+    if options.close_method == CloseMethod::OpretFirst {
+        outputs.push((Script::new_op_return(&[]).into(), 0));
+    }
+
     // Change Terminal Derivation
     let mut change_index = DerivationSubpath::new();
     if let Some(terminal_change) = terminal_change {
@@ -195,6 +212,43 @@ pub fn set_tapret_output(psbt: Psbt, pos: u16) -> Result<Psbt, CreatePsbtError>
         value: None,
     }];
 
+    set_proprietary_keys(psbt, proprietary_keys)
+}
+
+pub fn set_opret_output(psbt: Psbt, pos: u16) -> Result<Psbt, CreatePsbtError> {
+    let mut psbt = psbt;
+
+    // Keep the change output first, like the tapret flow
+    if pos > 0 && !psbt.outputs[pos as usize].script.is_op_return() {
+        psbt.outputs.swap(0, pos.into());
+    }
+
+    let host = psbt
+        .outputs
+        .iter()
+        .position(|output| output.script.is_op_return())
+        .ok_or(CreatePsbtError::Inconclusive)?;
+
+    // Define Opret Proprierties
+    let proprietary_keys = vec![ProprietaryKeyDescriptor {
+        location: ProprietaryKeyLocation::Output(host as u16),
+        ty: ProprietaryKeyType {
+            prefix: RGB_PSBT_OPRET.to_owned(),
+            subtype: PSBT_OUT_OPRET_HOST,
+        },
+        key: None,
+        value: None,
+    }];
+
+    set_proprietary_keys(psbt, proprietary_keys)
+}
+
+fn set_proprietary_keys(
+    psbt: Psbt,
+    proprietary_keys: Vec<ProprietaryKeyDescriptor>,
+) -> Result<Psbt, CreatePsbtError> {
+    let mut psbt = psbt;
+
     for key in proprietary_keys {
         match key.location {
             ProprietaryKeyLocation::Input(pos) if pos as usize >= psbt.inputs.len() => {
@@ -231,15 +285,44 @@ pub fn set_tapret_output(psbt: Psbt, pos: u16) -> Result<Psbt, CreatePsbtError>
     Ok(psbt)
 }
 
+pub fn psbt_close_method(psbt: &Psbt) -> CloseMethod {
+    let is_opret = psbt.outputs.iter().any(|output| {
+        output.proprietary.contains_key(&ProprietaryKey {
+            prefix: PSBT_OPRET_PREFIX.to_vec(),
+            subtype: PSBT_OUT_OPRET_HOST,
+            key: vec![],
+        })
+    });
+
+    if is_opret {
+        CloseMethod::OpretFirst
+    } else {
+        CloseMethod::TapretFirst
+    }
+}
+
 pub fn extract_output_commit(psbt: Psbt) -> Result<(Outpoint, u64, Vec<u8>), DbcPsbtError> {
+    let (prefix, host, commitment) = match psbt_close_method(&psbt) {
+        CloseMethod::OpretFirst => (
+            PSBT_OPRET_PREFIX,
+            PSBT_OUT_OPRET_HOST,
+            PSBT_OUT_OPRET_COMMITMENT,
+        ),
+        CloseMethod::TapretFirst => (
+            PSBT_TAPRET_PREFIX,
+            PSBT_OUT_TAPRET_HOST,
+            PSBT_OUT_TAPRET_COMMITMENT,
+        ),
+    };
+
     let (index, output) = psbt
         .outputs
         .iter()
         .enumerate()
         .find(|(_, output)| {
             output.proprietary.contains_key(&ProprietaryKey {
-                prefix: PSBT_TAPRET_PREFIX.to_vec(),
-                subtype: PSBT_OUT_TAPRET_HOST,
+                prefix: prefix.to_vec(),
+                subtype: host,
                 key: vec![],
             })
         })
@@ -247,8 +330,8 @@ pub fn extract_output_commit(psbt: Psbt) -> Result<(Outpoint, u64, Vec<u8>), Dbc
         .expect("none of the outputs is market as a commitment host");
 
     let commit_vec = output.proprietary.get(&ProprietaryKey {
-        prefix: PSBT_TAPRET_PREFIX.to_vec(),
-        subtype: PSBT_OUT_TAPRET_COMMITMENT,
+        prefix: prefix.to_vec(),
+        subtype: commitment,
         key: vec![],
     });
 
@@ -288,6 +371,11 @@ pub fn save_tap_commit(
     terminal: &str,
     wallet: &mut RgbWallet,
 ) {
+    // OpRet hosts are zero-value OP_RETURN outputs, so there is nothing to watch
+    if amount == 0 {
+        return;
+    }
+
     let descr = wallet.descr.clone();
     let RgbDescr::Tapret(mut tapret) = descr;
     let derive: Vec<&str> = terminal.split('/').filter(|s| !s.is_empty()).collect();
@@ -342,6 +430,62 @@ pub enum EstimateFeeError {
     },
 }
 
+/// Virtual size of a transaction spending taproot key-path inputs to taproot outputs
+pub fn estimate_taproot_vsize(inputs: usize, outputs: usize) -> u64 {
+    TX_OVERHEAD_VSIZE + TAPROOT_INPUT_VSIZE * inputs as u64 + TAPROOT_OUTPUT_VSIZE * outputs as u64
+}
+
+/// Fee rate (in sat/vB) to confirm within the target blocks, bounded by the
+/// min. relay fee rate and the max. fee rate accepted by the library
+pub fn estimate_fee_rate(blocks: u16, estimates: &HashMap<u16, f64>) -> Option<f32> {
+    // Esplora only estimates some targets (1-25, 144, 504 and 1008 blocks)
+    let (_, fee_rate) = estimates
+        .iter()
+        .filter(|(target, _)| **target <= blocks)
+        .max_by_key(|(target, _)| **target)
+        .or_else(|| estimates.iter().min_by_key(|(target, _)| **target))?;
+
+    Some((*fee_rate as f32).clamp(RGB_MIN_FEE_RATE, RGB_MAX_FEE_RATE))
+}
+
+/// Effective fee rate (in sat/vB) of a PSBT spending taproot key-path inputs
+///
+/// Returns zero when the PSBT cannot be decoded.
+pub fn estimate_psbt_fee_rate(psbt: &str) -> f32 {
+    let psbt = match Psbt::from_str(psbt) {
+        Ok(psbt) => PartiallySignedTransaction::from(psbt),
+        _ => return 0.0,
+    };
+
+    let input_value: u64 = psbt
+        .inputs
+        .iter()
+        .filter_map(|input| input.witness_utxo.as_ref())
+        .map(|txout| txout.value)
+        .sum();
+    let output_value: u64 = psbt
+        .unsigned_tx
+        .output
+        .iter()
+        .map(|txout| txout.value)
+        .sum();
+    let vsize = estimate_taproot_vsize(psbt.inputs.len(), psbt.unsigned_tx.output.len());
+
+    input_value.saturating_sub(output_value) as f32 / vsize as f32
+}
+
+/// Fee of a child transaction (CPFP) required to reach the fee rate (in sat/vB)
+/// for the package of the parent and child transactions
+pub fn estimate_cpfp_fee(parent: &TxFee, inputs: usize, outputs: usize, fee_rate: f32) -> u64 {
+    let parent_vsize = parent.weight.div_ceil(4);
+    let child_vsize = estimate_taproot_vsize(inputs, outputs);
+
+    // The child always pays its own size, even if the parent is above the fee rate
+    let package_fee = ((parent_vsize + child_vsize) as f32 * fee_rate).ceil() as u64;
+    let child_fee = (child_vsize as f32 * fee_rate).ceil() as u64;
+    package_fee.saturating_sub(parent.fee).max(child_fee)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn estimate_fee<T>(
     assets_inputs: Vec<PsbtInputRequest>,
@@ -369,6 +513,7 @@ where
     for contract_type in [
         AssetType::RGB20,
         AssetType::RGB21,
+        AssetType::RGB25,
         AssetType::Contract,
         AssetType::Bitcoin,
         AssetType::Change,
@@ -719,6 +864,7 @@ pub struct NewPsbtOptions {
     pub set_tapret: bool,
     pub force_inflation: u64,
     pub rbf: bool,
+    pub close_method: CloseMethod,
 }
 
 impl Default for NewPsbtOptions {
@@ -727,6 +873,7 @@ impl Default for NewPsbtOptions {
             rbf: true,
             set_tapret: true,
             force_inflation: 0,
+            close_method: CloseMethod::TapretFirst,
         }
     }
 }
@@ -737,6 +884,7 @@ impl NewPsbtOptions {
             rbf,
             set_tapret: true,
             force_inflation: 0,
+            close_method: CloseMethod::TapretFirst,
         }
     }
 
@@ -745,6 +893,7 @@ impl NewPsbtOptions {
             rbf: true,
             set_tapret: true,
             force_inflation: inflaction,
+            close_method: CloseMethod::TapretFirst,
         }
     }
 
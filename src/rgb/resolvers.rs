@@ -20,7 +20,7 @@ use rgbstd::{
 };
 use wallet::onchain::{ResolveTx, TxResolverError};
 
-use crate::structs::{TxStatus, UtxoSpentStatus};
+use crate::structs::{TxFee, TxStatus, UtxoSpentStatus};
 
 #[derive(Default)]
 pub struct ExplorerResolver {
@@ -32,6 +32,11 @@ pub struct ExplorerResolver {
     pub bp_txs: HashMap<Txid, Tx>,
     pub tx_height: HashMap<Txid, WitnessOrd>,
     pub txs_status: HashMap<bitcoin::Txid, TxStatus>,
+    pub txs_fee: HashMap<bitcoin::Txid, TxFee>,
+    pub txs_block_time: HashMap<bitcoin::Txid, u64>,
+    pub txs_block_hash: HashMap<bitcoin::Txid, String>,
+    pub tip_height: Option<u32>,
+    pub fee_estimates: HashMap<u16, f64>,
 }
 
 impl rgb::Resolver for ExplorerResolver {
@@ -0,0 +1,343 @@
+use std::collections::BTreeMap;
+
+use aluvm::{
+    isa::Instr,
+    library::{Lib, LibSite},
+};
+use rgbstd::{
+    contract::{Opout, Transition},
+    interface::{rgb20, rgb21, rgb21::rgb21_stl, IfaceImpl, NamedField, NamedType, VerNo},
+    schema::{
+        FungibleType, GenesisSchema, GlobalStateSchema, Occurrences, Schema, Script, StateSchema,
+        SubSchema, TransitionSchema,
+    },
+    stl::{rgb_contract_stl, StandardTypes},
+    vm::{AluScript, ContractOp, EntryPoint, RgbIsa},
+};
+use strict_encoding::{fname, tn};
+use strict_types::{SemId, Ty};
+
+// Global State
+const GS_NOMINAL: u16 = 2000;
+const GS_CONTRACT: u16 = 2001;
+const GS_TIMESTAMP: u16 = 2002;
+const GS_ISSUED_SUPPLY: u16 = 2003;
+const GS_BURNED_SUPPLY: u16 = 2004;
+const GS_TOKENS: u16 = 2102;
+const GS_ATTACH: u16 = 2104;
+
+// Owned State
+const OS_ASSET: u16 = 4000;
+const OS_INFLATION: u16 = 4001;
+
+// Transitions
+const TS_TRANSFER: u16 = 10000;
+const TS_ISSUE: u16 = 10001;
+const TS_BURN: u16 = 10002;
+
+/// Inflatable Fungible Asset (IFA) schema
+///
+/// Same layout of the NIA schema, plus the `inflationAllowance` right
+/// reserved at genesis and the `Issue` transition which spends it to
+/// mint new supply. The `Burn` transition destroys owned supply and
+/// records the amount in the `burnedSupply` global state.
+///
+/// The script only checks that transfers preserve the amounts. The supply
+/// rules of `Issue` and `Burn` relate owned and global state, which the
+/// contract operations of AluVM cannot compare, so the inflation allowance
+/// is not a consensus limit: bitmask checks it with [`check_ifa_transition`]
+/// when a consignment is validated or accepted, but other RGB
+/// implementations (and consignments concealing the amounts) are not bound
+/// by it.
+pub fn ifa_schema() -> SubSchema {
+    let types = StandardTypes::with(rgb_contract_stl());
+
+    let code = [Instr::ExtensionCodes(RgbIsa::Contract(ContractOp::PcVs(
+        OS_ASSET,
+    )))];
+    let alu_lib = Lib::assemble(&code).expect("invalid IFA script");
+    let alu_id = alu_lib.id();
+
+    Schema {
+        ffv: zero!(),
+        subset_of: None,
+        type_system: types.type_system(),
+        global_types: tiny_bmap! {
+            GS_NOMINAL => GlobalStateSchema::once(types.get("RGBContract.DivisibleAssetSpec")),
+            GS_CONTRACT => GlobalStateSchema::once(types.get("RGBContract.ContractData")),
+            GS_TIMESTAMP => GlobalStateSchema::once(types.get("RGBContract.Timestamp")),
+            GS_ISSUED_SUPPLY => GlobalStateSchema::many(types.get("RGBContract.Amount")),
+            GS_BURNED_SUPPLY => GlobalStateSchema::many(types.get("RGBContract.Amount")),
+        },
+        owned_types: tiny_bmap! {
+            OS_ASSET => StateSchema::Fungible(FungibleType::Unsigned64Bit),
+            OS_INFLATION => StateSchema::Fungible(FungibleType::Unsigned64Bit),
+        },
+        valency_types: none!(),
+        genesis: GenesisSchema {
+            metadata: Ty::<SemId>::UNIT.id(None),
+            globals: tiny_bmap! {
+                GS_NOMINAL => Occurrences::Once,
+                GS_CONTRACT => Occurrences::Once,
+                GS_TIMESTAMP => Occurrences::Once,
+                GS_ISSUED_SUPPLY => Occurrences::Once,
+            },
+            assignments: tiny_bmap! {
+                OS_ASSET => Occurrences::OnceOrMore,
+                OS_INFLATION => Occurrences::OnceOrMore,
+            },
+            valencies: none!(),
+        },
+        extensions: none!(),
+        transitions: tiny_bmap! {
+            TS_TRANSFER => TransitionSchema {
+                metadata: Ty::<SemId>::UNIT.id(None),
+                globals: none!(),
+                inputs: tiny_bmap! {
+                    OS_ASSET => Occurrences::OnceOrMore
+                },
+                assignments: tiny_bmap! {
+                    OS_ASSET => Occurrences::OnceOrMore
+                },
+                valencies: none!(),
+            },
+            TS_ISSUE => TransitionSchema {
+                metadata: Ty::<SemId>::UNIT.id(None),
+                globals: tiny_bmap! {
+                    GS_ISSUED_SUPPLY => Occurrences::Once,
+                },
+                inputs: tiny_bmap! {
+                    OS_INFLATION => Occurrences::OnceOrMore
+                },
+                assignments: tiny_bmap! {
+                    OS_ASSET => Occurrences::OnceOrMore,
+                    OS_INFLATION => Occurrences::NoneOrMore,
+                },
+                valencies: none!(),
+            },
+            TS_BURN => TransitionSchema {
+                metadata: Ty::<SemId>::UNIT.id(None),
+                globals: tiny_bmap! {
+                    GS_BURNED_SUPPLY => Occurrences::Once,
+                },
+                inputs: tiny_bmap! {
+                    OS_ASSET => Occurrences::OnceOrMore
+                },
+                assignments: tiny_bmap! {
+                    OS_ASSET => Occurrences::NoneOrMore,
+                },
+                valencies: none!(),
+            },
+        },
+        script: Script::AluVM(AluScript {
+            libs: confined_bmap! { alu_id => alu_lib },
+            entry_points: confined_bmap! {
+                EntryPoint::ValidateTransition(TS_TRANSFER) => LibSite::with(0, alu_id)
+            },
+        }),
+    }
+}
+
+/// Check the supply rules of an IFA transition, given the revealed amounts
+/// of the previous outputs it spends
+///
+/// An `Issue` must mint exactly its `issuedSupply`, spending an
+/// `inflationAllowance` which covers the minted amount plus the allowance
+/// assigned back to the issuer. A `Burn` must declare as `burnedSupply`
+/// the difference between the spent and the remaining amounts of the
+/// asset. Rules involving concealed amounts are skipped, since they cannot
+/// be verified.
+///
+/// This is an acceptance policy of bitmask, not a rule of the schema.
+pub fn check_ifa_transition(
+    transition: &Transition,
+    inputs: &BTreeMap<Opout, u64>,
+) -> Result<(), String> {
+    let opid = transition.id();
+    let input_sum = |ty: u16| -> Option<u64> {
+        let mut sum = 0u64;
+        for input in transition
+            .inputs
+            .iter()
+            .filter(|input| input.prev_out.ty == ty)
+        {
+            sum = sum.checked_add(*inputs.get(&input.prev_out)?)?;
+        }
+        Some(sum)
+    };
+    let output_sum = |ty: u16| -> Option<u64> {
+        let mut sum = 0u64;
+        if let Some(assigns) = transition.assignments.get(&ty) {
+            for idx in 0..assigns.len_u16() {
+                match assigns.as_fungible_state_at(idx) {
+                    Ok(Some(revealed)) => sum = sum.checked_add(revealed.value.as_u64())?,
+                    _ => return None,
+                }
+            }
+        }
+        Some(sum)
+    };
+    let global_sum = |ty: u16| -> Result<u64, String> {
+        let mut sum = 0u64;
+        if let Some(values) = transition.globals.get(&ty) {
+            for data in values.iter() {
+                let bytes: [u8; 8] = data
+                    .as_ref()
+                    .try_into()
+                    .map_err(|_| format!("operation {opid} has an invalid supply amount"))?;
+                sum = sum
+                    .checked_add(u64::from_le_bytes(bytes))
+                    .ok_or(format!("operation {opid} overflows the supply"))?;
+            }
+        }
+        Ok(sum)
+    };
+
+    if transition.transition_type == TS_ISSUE {
+        let issued = global_sum(GS_ISSUED_SUPPLY)?;
+        if let Some(minted) = output_sum(OS_ASSET) {
+            if minted != issued {
+                return Err(format!(
+                    "operation {opid} mints {minted} but declares an issued supply of {issued}"
+                ));
+            }
+        }
+
+        let remaining = output_sum(OS_INFLATION);
+        if let (Some(allowance), Some(remaining)) = (input_sum(OS_INFLATION), remaining) {
+            if issued.saturating_add(remaining) > allowance {
+                return Err(format!(
+                    "operation {opid} issues {issued} over the inflation allowance of {allowance}"
+                ));
+            }
+        }
+    }
+
+    if transition.transition_type == TS_BURN {
+        let burned = global_sum(GS_BURNED_SUPPLY)?;
+        if let (Some(spent), Some(remaining)) = (input_sum(OS_ASSET), output_sum(OS_ASSET)) {
+            if spent.checked_sub(remaining) != Some(burned) {
+                return Err(format!(
+                    "operation {opid} spends {spent} and keeps {remaining} but declares a burned supply of {burned}"
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// RGB20 implementation of the IFA schema
+pub fn ifa_rgb20() -> IfaceImpl {
+    let schema = ifa_schema();
+    let iface = rgb20();
+
+    IfaceImpl {
+        version: VerNo::V1,
+        schema_id: schema.schema_id(),
+        iface_id: iface.iface_id(),
+        global_state: tiny_bset! {
+            NamedField::with(GS_NOMINAL, fname!("spec")),
+            NamedField::with(GS_CONTRACT, fname!("data")),
+            NamedField::with(GS_TIMESTAMP, fname!("created")),
+            NamedField::with(GS_ISSUED_SUPPLY, fname!("issuedSupply")),
+            NamedField::with(GS_BURNED_SUPPLY, fname!("burnedSupply")),
+        },
+        assignments: tiny_bset! {
+            NamedField::with(OS_ASSET, fname!("assetOwner")),
+            NamedField::with(OS_INFLATION, fname!("inflationAllowance")),
+        },
+        valencies: none!(),
+        transitions: tiny_bset! {
+            NamedType::with(TS_TRANSFER, tn!("Transfer")),
+            NamedType::with(TS_ISSUE, tn!("Issue")),
+            NamedType::with(TS_BURN, tn!("Burn")),
+        },
+        extensions: none!(),
+    }
+}
+
+/// Unique Collection Asset (UCA) schema
+///
+/// Same layout of the UDA schema, but the genesis can define many tokens
+/// (each one with its own index, media and attachments) and allocate each
+/// token to a different seal.
+pub fn uca_schema() -> SubSchema {
+    let types = StandardTypes::with(rgb21_stl());
+
+    Schema {
+        ffv: zero!(),
+        subset_of: None,
+        type_system: types.type_system(),
+        global_types: tiny_bmap! {
+            GS_NOMINAL => GlobalStateSchema::once(types.get("RGBContract.DivisibleAssetSpec")),
+            GS_CONTRACT => GlobalStateSchema::once(types.get("RGBContract.RicardianContract")),
+            GS_TIMESTAMP => GlobalStateSchema::once(types.get("RGBContract.Timestamp")),
+            GS_TOKENS => GlobalStateSchema::many(types.get("RGB21.TokenData")),
+            GS_ATTACH => GlobalStateSchema::many(types.get("RGB21.AttachmentType")),
+        },
+        owned_types: tiny_bmap! {
+            OS_ASSET => StateSchema::Structured(types.get("RGB21.Allocation")),
+        },
+        valency_types: none!(),
+        genesis: GenesisSchema {
+            metadata: Ty::<SemId>::UNIT.id(None),
+            globals: tiny_bmap! {
+                GS_NOMINAL => Occurrences::Once,
+                GS_CONTRACT => Occurrences::Once,
+                GS_TIMESTAMP => Occurrences::Once,
+                GS_TOKENS => Occurrences::OnceOrMore,
+                GS_ATTACH => Occurrences::NoneOrMore,
+            },
+            assignments: tiny_bmap! {
+                OS_ASSET => Occurrences::OnceOrMore,
+            },
+            valencies: none!(),
+        },
+        extensions: none!(),
+        transitions: tiny_bmap! {
+            TS_TRANSFER => TransitionSchema {
+                metadata: Ty::<SemId>::UNIT.id(None),
+                globals: none!(),
+                inputs: tiny_bmap! {
+                    OS_ASSET => Occurrences::OnceOrMore
+                },
+                assignments: tiny_bmap! {
+                    OS_ASSET => Occurrences::OnceOrMore
+                },
+                valencies: none!(),
+            },
+        },
+        script: Script::AluVM(AluScript {
+            libs: none!(),
+            entry_points: none!(),
+        }),
+    }
+}
+
+/// RGB21 implementation of the UCA schema
+pub fn uca_rgb21() -> IfaceImpl {
+    let schema = uca_schema();
+    let iface = rgb21();
+
+    IfaceImpl {
+        version: VerNo::V1,
+        schema_id: schema.schema_id(),
+        iface_id: iface.iface_id(),
+        global_state: tiny_bset! {
+            NamedField::with(GS_NOMINAL, fname!("spec")),
+            NamedField::with(GS_CONTRACT, fname!("terms")),
+            NamedField::with(GS_TIMESTAMP, fname!("created")),
+            NamedField::with(GS_TOKENS, fname!("tokens")),
+            NamedField::with(GS_ATTACH, fname!("attachmentTypes")),
+        },
+        assignments: tiny_bset! {
+            NamedField::with(OS_ASSET, fname!("assetOwner")),
+        },
+        valencies: none!(),
+        transitions: tiny_bset! {
+            NamedType::with(TS_TRANSFER, tn!("Transfer")),
+        },
+        extensions: none!(),
+    }
+}
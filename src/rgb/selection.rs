@@ -0,0 +1,155 @@
+use crate::structs::CoinSelectionStrategy;
+
+/// Max. number of branches explored by the branch-and-bound selection
+pub const BNB_MAX_TRIES: usize = 100_000;
+
+/// Select the candidates required to reach the target amount
+///
+/// The `Manual` strategy returns the candidates of the outpoint list
+/// (in the given order) regardless of the target amount. The list can mix asset
+/// and bitcoin outpoints, so outpoints that are not candidates are skipped here
+/// and checked against the final inputs with [`find_missing_outpoint`].
+///
+/// The `BranchAndBound` strategy falls back to largest first when no combination
+/// matches the target exactly (or the search exceeds [`BNB_MAX_TRIES`]).
+pub fn select_coins<T, O, A>(
+    candidates: Vec<T>,
+    target: u64,
+    strategy: &CoinSelectionStrategy,
+    outpoint: O,
+    amount: A,
+) -> Vec<T>
+where
+    T: Clone,
+    O: Fn(&T) -> String,
+    A: Fn(&T) -> u64,
+{
+    if let CoinSelectionStrategy::Manual(outpoints) = strategy {
+        return outpoints
+            .iter()
+            .filter_map(|x| candidates.iter().find(|c| outpoint(c) == *x).cloned())
+            .collect();
+    }
+
+    if target == 0 {
+        return vec![];
+    }
+
+    let mut candidates = candidates;
+    match strategy {
+        CoinSelectionStrategy::LargestFirst => {
+            candidates.sort_by_key(|c| std::cmp::Reverse(amount(c)));
+        }
+        CoinSelectionStrategy::SmallestFirst => {
+            candidates.sort_by_key(|c| amount(c));
+        }
+        CoinSelectionStrategy::BranchAndBound => {
+            candidates.sort_by_key(|c| std::cmp::Reverse(amount(c)));
+            let amounts: Vec<u64> = candidates.iter().map(&amount).collect();
+            if let Some(indexes) = branch_and_bound(&amounts, target) {
+                return indexes.into_iter().map(|i| candidates[i].clone()).collect();
+            }
+            // Without exact match, the candidates are already sorted as largest first
+        }
+        CoinSelectionStrategy::Privacy => {
+            // A single input does not link UTXOs of the wallet
+            if let Some(single) = candidates
+                .iter()
+                .filter(|c| amount(c) >= target)
+                .min_by_key(|c| amount(c))
+            {
+                return vec![single.clone()];
+            }
+            candidates.sort_by_key(|c| std::cmp::Reverse(amount(c)));
+        }
+        _ => {}
+    }
+
+    let mut total = 0;
+    let mut selected = vec![];
+    for candidate in candidates {
+        if total >= target {
+            break;
+        }
+        total += amount(&candidate);
+        selected.push(candidate);
+    }
+    selected
+}
+
+/// Find the first outpoint of the `Manual` strategy that is not spent by the
+/// inputs (unknown, unspendable or locked outpoints)
+pub fn find_missing_outpoint(
+    strategy: &CoinSelectionStrategy,
+    inputs: &[String],
+) -> Option<String> {
+    match strategy {
+        CoinSelectionStrategy::Manual(outpoints) => outpoints
+            .iter()
+            .find(|outpoint| !inputs.contains(outpoint))
+            .cloned(),
+        _ => None,
+    }
+}
+
+/// Search the combination of amounts (sorted in descending order) whose sum is
+/// exactly the target, returning the indexes of the combination
+pub fn branch_and_bound(amounts: &[u64], target: u64) -> Option<Vec<usize>> {
+    let mut available = vec![0; amounts.len() + 1];
+    for (i, amount) in amounts.iter().enumerate().rev() {
+        available[i] = available[i + 1] + amount;
+    }
+
+    let mut tries = 0;
+    let mut selected = vec![];
+    if bnb_search(amounts, &available, target, 0, 0, &mut selected, &mut tries) {
+        Some(selected)
+    } else {
+        None
+    }
+}
+
+fn bnb_search(
+    amounts: &[u64],
+    available: &[u64],
+    target: u64,
+    index: usize,
+    total: u64,
+    selected: &mut Vec<usize>,
+    tries: &mut usize,
+) -> bool {
+    *tries += 1;
+    if total == target {
+        return true;
+    }
+    if index >= amounts.len() || total > target || total + available[index] < target {
+        return false;
+    }
+    if *tries > BNB_MAX_TRIES {
+        return false;
+    }
+
+    selected.push(index);
+    if bnb_search(
+        amounts,
+        available,
+        target,
+        index + 1,
+        total + amounts[index],
+        selected,
+        tries,
+    ) {
+        return true;
+    }
+    selected.pop();
+
+    bnb_search(
+        amounts,
+        available,
+        target,
+        index + 1,
+        total,
+        selected,
+        tries,
+    )
+}
@@ -1,4 +1,7 @@
-use amplify::confinement::{Confined, U32};
+use amplify::{
+    confinement::{Confined, U32},
+    hex::ToHex,
+};
 use bitcoin::Address;
 use bitcoin_scripts::address::AddressCompat;
 use bp::Txid;
@@ -9,9 +12,15 @@ use std::{
     str::FromStr,
 };
 
-use rgbstd::containers::{Bindle, Transfer};
+use rgbstd::{
+    containers::{Bindle, Transfer},
+    interface::TypedState,
+};
+use rgbwallet::RgbInvoice;
 use serde::{Deserialize, Serialize};
 
+use crate::structs::{InvoiceStatus, RgbInvoiceStatusDetail};
+
 pub type RgbAccountV0 = RgbAccount;
 pub type RgbTransferV0 = RgbTransfer;
 pub type RgbTransfersV0 = RgbTransfers;
@@ -350,6 +359,278 @@ impl Default for RgbTransferV1 {
     }
 }
 
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub struct RgbInvoices {
+    /// Created invoices, indexed by invoice (the same beneficiary can be used in many invoices)
+    pub invoices: BTreeMap<String, RgbInvoiceRecord>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Display)]
+#[display("{beneficiary}:{status}")]
+pub struct RgbInvoiceRecord {
+    pub invoice: String,
+    pub contract_id: String,
+    pub iface: String,
+    pub amount: u64,
+    pub received: u64,
+    pub beneficiary: String,
+    pub created: i64,
+    pub expiry: Option<i64>,
+    pub transfers: Vec<String>,
+    pub status: InvoiceStatus,
+}
+
+impl RgbInvoiceRecord {
+    pub fn new(invoice: &RgbInvoice, created: i64) -> Self {
+        let amount = match invoice.owned_state {
+            TypedState::Amount(amount) => amount,
+            TypedState::Data(_) => 1,
+            _ => 0,
+        };
+
+        Self {
+            invoice: invoice.to_string(),
+            contract_id: invoice
+                .contract
+                .map(|contract_id| contract_id.to_string())
+                .unwrap_or_default(),
+            iface: invoice
+                .iface
+                .clone()
+                .map(|iface| iface.to_string())
+                .unwrap_or_default(),
+            amount,
+            received: 0,
+            beneficiary: invoice.beneficiary.to_string(),
+            created,
+            expiry: invoice.expiry,
+            transfers: vec![],
+            status: InvoiceStatus::Open,
+        }
+    }
+
+    /// Register a payment received by the invoice beneficiary, returning whether it
+    /// was settled (payments of other contracts are ignored)
+    pub fn settle(&mut self, contract_id: &str, transfer_id: &str, amount: u64) -> bool {
+        if !self.contract_id.is_empty() && self.contract_id != contract_id {
+            return false;
+        }
+        if self.transfers.contains(&transfer_id.to_string()) {
+            return false;
+        }
+
+        self.transfers.push(transfer_id.to_string());
+        self.received = self.received.saturating_add(amount);
+        self.status = if self.received >= self.amount {
+            InvoiceStatus::Paid
+        } else {
+            InvoiceStatus::PartiallyPaid
+        };
+        true
+    }
+
+    /// Mark as expired an open invoice whose expiration date has passed
+    pub fn refresh(&mut self, now: i64) {
+        if self.status != InvoiceStatus::Open {
+            return;
+        }
+
+        if let Some(expiry) = self.expiry {
+            if expiry <= now {
+                self.status = InvoiceStatus::Expired;
+            }
+        }
+    }
+}
+
+impl From<RgbInvoiceRecord> for RgbInvoiceStatusDetail {
+    fn from(value: RgbInvoiceRecord) -> Self {
+        let RgbInvoiceRecord {
+            invoice,
+            contract_id,
+            iface,
+            amount,
+            received,
+            beneficiary,
+            created,
+            expiry,
+            transfers,
+            status,
+        } = value;
+
+        Self {
+            invoice,
+            contract_id,
+            iface,
+            amount,
+            received,
+            beneficiary,
+            created,
+            expiry,
+            transfers,
+            status,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize, Display)]
+#[display(doc_comments)]
+pub enum RgbLockOwner {
+    /// transfer:{0}
+    Transfer(String),
+    /// offer:{0}
+    Offer(String),
+    /// export:{0}
+    Export(String),
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Display)]
+#[display("{outpoint} ({owner})")]
+pub struct RgbLock {
+    pub outpoint: String,
+    pub owner: RgbLockOwner,
+    pub created: i64,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub struct RgbLocks {
+    /// Outpoints reserved by pending transfers and open offers, indexed by outpoint
+    pub locks: BTreeMap<String, RgbLock>,
+}
+
+impl RgbLocks {
+    /// Reserve the outpoints for the owner (ex: a replacement transaction takes
+    /// over the reservations of the transfer it replaces)
+    pub fn lock(&mut self, outpoints: Vec<String>, owner: RgbLockOwner, created: i64) {
+        for outpoint in outpoints {
+            let lock = RgbLock {
+                outpoint: outpoint.clone(),
+                owner: owner.clone(),
+                created,
+            };
+            self.locks.insert(outpoint, lock);
+        }
+    }
+
+    /// Release all outpoints reserved by the owner, returning them
+    pub fn release(&mut self, owner: &RgbLockOwner) -> Vec<String> {
+        let released: Vec<String> = self
+            .locks
+            .values()
+            .filter(|lock| &lock.owner == owner)
+            .map(|lock| lock.outpoint.clone())
+            .collect();
+
+        for outpoint in &released {
+            self.locks.remove(outpoint);
+        }
+        released
+    }
+
+    /// Release the outpoints reserved by transfers that are no longer pending
+    pub fn release_transfers(&mut self, pending_transfers: &[String]) {
+        self.locks.retain(|_, lock| match &lock.owner {
+            RgbLockOwner::Transfer(consig_id) => pending_transfers.contains(consig_id),
+            RgbLockOwner::Export(_) | RgbLockOwner::Offer(_) => true,
+        });
+    }
+
+    /// Release the outpoints reserved by exported transfers that were not
+    /// imported before the expiry (in seconds)
+    pub fn release_expired_exports(&mut self, now: i64, expiry: i64) {
+        self.locks.retain(|_, lock| match &lock.owner {
+            RgbLockOwner::Export(_) => lock.created + expiry > now,
+            _ => true,
+        });
+    }
+
+    pub fn is_locked(&self, outpoint: &str) -> bool {
+        self.locks.contains_key(outpoint)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub struct RgbReservations {
+    /// Wallet addresses reserved by witness invoices and new outputs, indexed by address
+    pub addresses: BTreeMap<String, TerminalPath>,
+}
+
+impl RgbReservations {
+    /// Reserve the address, so it is not handed out again before an UTXO is received
+    pub fn reserve(&mut self, address: &AddressTerminal) {
+        self.addresses
+            .insert(address.address.to_string(), address.terminal);
+    }
+
+    /// Last derivation index reserved in the app (ex: RGB20)
+    pub fn last_index(&self, app: u32) -> Option<u32> {
+        self.addresses
+            .values()
+            .filter(|terminal| terminal.app == app)
+            .map(|terminal| terminal.index)
+            .max()
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Display)]
+#[display("{txid}:{block_hash}")]
+pub struct RgbAnchor {
+    pub contract_id: String,
+    pub txid: String,
+    pub block_height: u32,
+    pub block_hash: String,
+    /// Accepted transfer (restored as pending when the block is reorged out)
+    pub transfer: RgbTransferV1,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
+pub struct RgbAnchors {
+    /// Blocks of the accepted transfers anchors, indexed by consignment id
+    pub anchors: BTreeMap<String, RgbAnchor>,
+}
+
+impl RgbAnchors {
+    pub fn record(&mut self, contract_id: &str, transfer: RgbTransferV1, height: u32, hash: &str) {
+        let anchor = RgbAnchor {
+            contract_id: contract_id.to_string(),
+            txid: transfer.tx_id.to_hex(),
+            block_height: height,
+            block_hash: hash.to_string(),
+            transfer,
+        };
+        self.anchors
+            .insert(anchor.transfer.consig_id.clone(), anchor);
+    }
+
+    /// Remove the anchors whose block left the best chain, returning them
+    ///
+    /// `block_hashes` contains the current block of each anchor transaction
+    /// (`None` if unconfirmed). Anchors missing in `block_hashes` are kept.
+    pub fn reorged(&mut self, block_hashes: &BTreeMap<String, Option<String>>) -> Vec<RgbAnchor> {
+        let reorged: Vec<RgbAnchor> = self
+            .anchors
+            .values()
+            .filter(|anchor| match block_hashes.get(&anchor.txid) {
+                Some(Some(hash)) => *hash != anchor.block_hash,
+                Some(None) => true,
+                None => false,
+            })
+            .cloned()
+            .collect();
+
+        for anchor in reorged.iter() {
+            self.anchors.remove(&anchor.transfer.consig_id);
+        }
+        reorged
+    }
+
+    /// Stop monitoring the anchors buried deeper than `depth` blocks
+    pub fn prune(&mut self, tip_height: u32, depth: u32) {
+        self.anchors
+            .retain(|_, anchor| anchor.block_height + depth > tip_height);
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct RgbExtractTransfer {
     pub consig_id: String,
@@ -1,29 +1,40 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
 
 use amplify::{
     confinement::{Confined, U32},
     hex::ToHex,
 };
-use bitcoin_30::psbt::Psbt as PSBT;
+use bitcoin_30::{psbt::Psbt as PSBT, Address, Network, Script};
 use bitcoin_hashes::hex::FromHex;
-use bp::{seals::txout::CloseMethod, Chain, Txid};
+use bp::{Chain, Outpoint, Txid, Vout};
 use indexmap::IndexMap;
 use psbt::{serialize::Serialize, Psbt};
 use rgbstd::{
-    containers::{Bindle, Transfer},
-    contract::{ContractId, GraphSeal},
+    containers::{Bindle, TerminalSeal, Transfer},
+    contract::{ContractId, GraphSeal, OpId, Operation},
     interface::TypedState,
     persistence::{Inventory, Stash, Stock},
     resolvers::ResolveHeight,
-    validation::{AnchoredBundle, ConsignmentApi, ResolveTx, Status},
+    validation::{AnchoredBundle, ConsignmentApi, Failure, ResolveTx, Status, Validity},
 };
-use rgbwallet::{InvoiceParseError, RgbInvoice, RgbTransport};
+use rgbwallet::{Beneficiary, InvoiceParseError, RgbInvoice, RgbTransport};
 use seals::txout::ExplicitSeal;
 use strict_encoding::{StrictDeserialize, TypeName};
 
-use crate::rgb::{
-    consignment::{ConsignmentEx, NewTransferOptions},
-    prebuild::prebuild_extract_transfer,
+use crate::{
+    rgb::{
+        consignment::{ConsignmentEx, NewTransferOptions},
+        prebuild::prebuild_extract_transfer,
+        psbt::psbt_close_method,
+        schemata::{check_ifa_transition, ifa_schema},
+    },
+    structs::{
+        ConsignmentAnchorStatus, ConsignmentValidationItem, ConsignmentValidationReport,
+        ConsignmentValidity, TxStatus,
+    },
 };
 
 #[derive(Clone, Eq, PartialEq, Debug, Display, Error, From)]
@@ -35,6 +46,8 @@ pub enum NewInvoiceError {
     WrongContract(String),
     /// '{0}' is an invalid seal definition
     WrongSeal(String),
+    /// '{0}' is an invalid beneficiary address
+    WrongAddress(String),
     /// Network cannot be decoded. {0}
     WrongNetwork(String),
     /// {0} is unspecified or wrong contract id
@@ -43,6 +56,36 @@ pub enum NewInvoiceError {
     EmptyContracts,
     /// Error saving secret seal: {0}
     StoreSeal(String),
+    /// '{0}' is an invalid transport endpoint
+    WrongTransport(String),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct NewInvoiceOptions {
+    /// Expiration date (unix timestamp)
+    pub expiry: Option<i64>,
+    /// Transport endpoints (ex: rpcs://proxy.example.com/json-rpc)
+    pub transports: Vec<String>,
+}
+
+impl NewInvoiceOptions {
+    pub fn with(expiry: Option<i64>, transports: Vec<String>) -> Self {
+        Self { expiry, transports }
+    }
+
+    fn rgb_transports(&self) -> Result<Vec<RgbTransport>, NewInvoiceError> {
+        if self.transports.is_empty() {
+            return Ok(vec![RgbTransport::UnspecifiedMeans]);
+        }
+
+        self.transports
+            .iter()
+            .map(|endpoint| {
+                RgbTransport::from_str(endpoint)
+                    .map_err(|_| NewInvoiceError::WrongTransport(endpoint.to_string()))
+            })
+            .collect()
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Debug, Display, Error, From)]
@@ -85,6 +128,7 @@ pub fn create_invoice(
     seal: &str,
     network: &str,
     params: HashMap<String, String>,
+    options: NewInvoiceOptions,
     stock: &mut Stock,
 ) -> Result<RgbInvoice, NewInvoiceError> {
     let ty =
@@ -119,7 +163,7 @@ pub fn create_invoice(
 
     // Generate Invoice
     let invoice = RgbInvoice {
-        transports: vec![RgbTransport::UnspecifiedMeans],
+        transports: options.rgb_transports()?,
         contract: Some(contract_id),
         iface: Some(iface.name.clone()),
         operation: None,
@@ -128,7 +172,7 @@ pub fn create_invoice(
         owned_state: TypedState::Amount(amount),
         chain: Some(chain),
         unknown_query: query,
-        expiry: None,
+        expiry: options.expiry,
     };
 
     stock
@@ -138,6 +182,59 @@ pub fn create_invoice(
     Ok(invoice)
 }
 
+/// Create an invoice paid to a new output of the sender's witness transaction
+pub fn create_witness_invoice(
+    contract_id: &str,
+    iface: &str,
+    amount: u64,
+    address: &str,
+    network: &str,
+    params: HashMap<String, String>,
+    options: NewInvoiceOptions,
+    stock: &mut Stock,
+) -> Result<RgbInvoice, NewInvoiceError> {
+    let ty =
+        TypeName::from_str(iface).map_err(|_| NewInvoiceError::WrongIface(iface.to_string()))?;
+    let iface = stock
+        .iface_by_name(&ty)
+        .map_err(|_| NewInvoiceError::WrongIface(iface.to_string()))?;
+
+    let contract_id = ContractId::from_str(contract_id)
+        .map_err(|_| NewInvoiceError::NoContract(contract_id.to_string()))?;
+
+    let chain =
+        Chain::from_str(network).map_err(|op| NewInvoiceError::WrongNetwork(op.to_string()))?;
+    let address_network =
+        Network::from_str(network).map_err(|op| NewInvoiceError::WrongNetwork(op.to_string()))?;
+
+    let address = Address::from_str(address)
+        .map_err(|_| NewInvoiceError::WrongAddress(address.to_string()))?
+        .require_network(address_network)
+        .map_err(|_| NewInvoiceError::WrongAddress(address.to_string()))?;
+
+    // Query Params
+    let mut query = IndexMap::default();
+    for (k, v) in params {
+        query.insert(k, v);
+    }
+
+    // Generate Invoice
+    let invoice = RgbInvoice {
+        transports: options.rgb_transports()?,
+        contract: Some(contract_id),
+        iface: Some(iface.name.clone()),
+        operation: None,
+        assignment: None,
+        beneficiary: Beneficiary::WitnessUtxo(address),
+        owned_state: TypedState::Amount(amount),
+        chain: Some(chain),
+        unknown_query: query,
+        expiry: options.expiry,
+    };
+
+    Ok(invoice)
+}
+
 pub fn pay_invoice(
     invoice: String,
     psbt: String,
@@ -146,6 +243,7 @@ pub fn pay_invoice(
 ) -> Result<(Psbt, Vec<Bindle<Transfer>>), NewPaymentError> {
     let invoice = RgbInvoice::from_str(&invoice).map_err(NewPaymentError::WrongInvoice)?;
     let psbt_file = Psbt::from_str(&psbt).map_err(|_| NewPaymentError::WrongHex)?;
+    let method = psbt_close_method(&psbt_file);
 
     let psbt = base64::decode(&base64::encode(&psbt_file.serialize()))
         .map_err(|err| NewPaymentError::WrongPSBT(err.to_string()))?;
@@ -154,7 +252,7 @@ pub fn pay_invoice(
         PSBT::deserialize(&psbt).map_err(|err| NewPaymentError::WrongPSBT(err.to_string()))?;
 
     let transfers = stock
-        .process(invoice, &mut psbt_final, CloseMethod::TapretFirst, options)
+        .process(invoice, &mut psbt_final, method, options)
         .map_err(|err| NewPaymentError::NoPay(err.to_string()))?;
 
     let psbt_file = Psbt::from_str(&PSBT::serialize(&psbt_final).to_hex())
@@ -162,29 +260,242 @@ pub fn pay_invoice(
     Ok((psbt_file, transfers))
 }
 
+pub fn burn_asset(
+    contract_id: &str,
+    iface: &str,
+    amount: u64,
+    psbt: String,
+    stock: &mut Stock,
+) -> Result<(Psbt, Bindle<Transfer>), NewPaymentError> {
+    let contract_id = ContractId::from_str(contract_id)
+        .map_err(|_| NewPaymentError::NoPay(format!("invalid contract id {contract_id}")))?;
+    let iface = TypeName::from_str(iface)
+        .map_err(|_| NewPaymentError::NoPay(format!("invalid iface {iface}")))?;
+
+    let psbt_file = Psbt::from_str(&psbt).map_err(|_| NewPaymentError::WrongHex)?;
+    let method = psbt_close_method(&psbt_file);
+    let psbt = base64::decode(&base64::encode(&psbt_file.serialize()))
+        .map_err(|err| NewPaymentError::WrongPSBT(err.to_string()))?;
+
+    let mut psbt_final =
+        PSBT::deserialize(&psbt).map_err(|err| NewPaymentError::WrongPSBT(err.to_string()))?;
+
+    let transfer = stock
+        .burn(contract_id, iface, amount, &mut psbt_final, method)
+        .map_err(|err| NewPaymentError::NoPay(err.to_string()))?;
+
+    let psbt_file = Psbt::from_str(&PSBT::serialize(&psbt_final).to_hex())
+        .map_err(|err| NewPaymentError::WrongPSBT(err.to_string()))?;
+    Ok((psbt_file, transfer))
+}
+
+/// Validate the consignment without importing it into the stock
 pub fn validate_transfer<R: ResolveTx>(
     transfer: String,
     resolver: &mut R,
-) -> Result<(ContractId, Status), AcceptTransferError> {
+) -> Result<(Bindle<Transfer>, Status), AcceptTransferError> {
     let serialized = Vec::<u8>::from_hex(&transfer).map_err(|_| AcceptTransferError::WrongHex)?;
     let confined = Confined::try_from_iter(serialized.iter().copied())
         .map_err(|err| AcceptTransferError::WrongConsig(err.to_string()))?;
     let transfer = Transfer::from_strict_serialized::<{ usize::MAX }>(confined)
         .map_err(|err| AcceptTransferError::WrongConsig(err.to_string()))?;
 
-    let consig = transfer.clone().validate(resolver).map_err(|err| {
-        if let Some(status) = err.into_validation_status() {
-            let mut messages = vec![];
-            messages.append(&mut status.warnings.into_iter().map(|x| x.to_string()).collect());
-            messages.append(&mut status.failures.into_iter().map(|x| x.to_string()).collect());
-            AcceptTransferError::InvalidConsig(messages)
-        } else {
-            AcceptTransferError::Inconclusive
+    // Invalid consignments also carry the validation status
+    let consig = match transfer.validate(resolver) {
+        Ok(consig) => consig,
+        Err(consig) => consig,
+    };
+
+    let mut status = consig
+        .clone()
+        .into_validation_status()
+        .ok_or(AcceptTransferError::Inconclusive)?;
+    for (opid, _) in extract_supply_violations(&consig) {
+        status.failures.push(Failure::ScriptFailure(opid));
+    }
+    Ok((Bindle::new(consig), status))
+}
+
+/// Operations of the consignment breaking the supply rules of its schema
+///
+/// Only IFA contracts have rules (see [`check_ifa_transition`]) which
+/// are not verified by the schema script, so they are only enforced by
+/// bitmask (and only for revealed amounts).
+pub fn extract_supply_violations(transfer: &Transfer) -> Vec<(OpId, String)> {
+    let mut violations = vec![];
+    if transfer.schema.schema_id() != ifa_schema().schema_id() {
+        return violations;
+    }
+
+    for anchored_bundle in transfer.bundles.iter() {
+        let bundle_id = anchored_bundle.bundle.bundle_id();
+        let transitions = match transfer.known_transitions_by_bundle_id(bundle_id) {
+            Some(transitions) => transitions,
+            _ => continue,
+        };
+
+        for transition in transitions {
+            let mut inputs = BTreeMap::new();
+            for input in transition.inputs.iter() {
+                let prev_out = input.prev_out;
+                let assigns = match transfer
+                    .operation(prev_out.op)
+                    .and_then(|op| op.assignments_by_type(prev_out.ty))
+                {
+                    Some(assigns) => assigns,
+                    _ => continue,
+                };
+                if let Ok(Some(revealed)) = assigns.as_fungible_state_at(prev_out.no) {
+                    inputs.insert(prev_out, revealed.value.as_u64());
+                }
+            }
+
+            if let Err(err) = check_ifa_transition(transition, &inputs) {
+                violations.push((transition.id(), err));
+            }
         }
-    })?;
+    }
+    violations
+}
 
-    let status = consig.into_validation_status();
-    Ok((transfer.contract_id(), status.unwrap_or_default()))
+/// Anchor transactions of the transfer (witness transactions)
+pub fn extract_anchor_txids(transfer: &Bindle<Transfer>) -> Vec<Txid> {
+    let mut txids = vec![];
+    for (bundle_id, _) in transfer.terminals() {
+        if let Some(AnchoredBundle { anchor, bundle: _ }) = transfer.anchored_bundle(bundle_id) {
+            if !txids.contains(&anchor.txid) {
+                txids.push(anchor.txid);
+            }
+        }
+    }
+    txids
+}
+
+pub fn extract_validation_report<F>(
+    transfer: &Bindle<Transfer>,
+    status: &Status,
+    anchor_status: F,
+) -> ConsignmentValidationReport
+where
+    F: Fn(Txid) -> TxStatus,
+{
+    let mut operations = vec![];
+    let mut seals = vec![];
+    for (bundle_id, terminal) in transfer.terminals() {
+        if let Some(transitions) = transfer.known_transitions_by_bundle_id(bundle_id) {
+            for transition in transitions {
+                let opid = transition.id().to_string();
+                if !operations.contains(&opid) {
+                    operations.push(opid);
+                }
+            }
+        }
+
+        let anchor = transfer
+            .anchored_bundle(bundle_id)
+            .map(|AnchoredBundle { anchor, bundle: _ }| anchor.txid);
+        for seal in terminal.seals.iter() {
+            let seal = match seal {
+                TerminalSeal::ConcealedUtxo(concealed) => concealed.to_string(),
+                TerminalSeal::WitnessVout(seal) => match anchor {
+                    Some(txid) => Outpoint::new(txid, seal.vout).to_string(),
+                    None => continue,
+                },
+            };
+            if !seals.contains(&seal) {
+                seals.push(seal);
+            }
+        }
+    }
+
+    let mut txids = vec![];
+    for anchor_bundle in transfer.bundles.iter() {
+        let txid = anchor_bundle.anchor.txid.to_string();
+        if !txids.contains(&txid) {
+            txids.push(txid);
+        }
+    }
+
+    let anchors: Vec<ConsignmentAnchorStatus> = extract_anchor_txids(transfer)
+        .into_iter()
+        .map(|txid| ConsignmentAnchorStatus {
+            txid: txid.to_string(),
+            status: anchor_status(txid),
+        })
+        .collect();
+    let anchor_mined = !anchors.is_empty()
+        && anchors
+            .iter()
+            .all(|anchor| matches!(anchor.status, TxStatus::Block(_)));
+
+    // Identifiers of the consignment mentioned by each failure, warning or info
+    let references: Vec<String> = operations
+        .iter()
+        .chain(seals.iter())
+        .chain(txids.iter())
+        .cloned()
+        .collect();
+    let validation_item = |code: String, message: String| {
+        let code = code
+            .split(|c: char| !c.is_alphanumeric())
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let involved = references
+            .iter()
+            .filter(|reference| message.contains(reference.as_str()))
+            .cloned()
+            .collect();
+        ConsignmentValidationItem {
+            code,
+            message,
+            involved,
+        }
+    };
+
+    let validity = match status.validity() {
+        Validity::Valid => ConsignmentValidity::Valid,
+        Validity::UnresolvedTransactions => ConsignmentValidity::UnresolvedTransactions,
+        Validity::ValidExceptEndpoints => ConsignmentValidity::ValidExceptEndpoints,
+        Validity::Invalid => ConsignmentValidity::Invalid,
+    };
+
+    ConsignmentValidationReport {
+        transfer_id: transfer.transfer_id().to_string(),
+        contract_id: transfer.contract_id().to_string(),
+        valid: validity == ConsignmentValidity::Valid,
+        validity,
+        failures: status
+            .failures
+            .iter()
+            .map(|x| validation_item(format!("{x:?}"), x.to_string()))
+            .collect(),
+        warnings: status
+            .warnings
+            .iter()
+            .map(|x| validation_item(format!("{x:?}"), x.to_string()))
+            .collect(),
+        info: status
+            .info
+            .iter()
+            .map(|x| validation_item(format!("{x:?}"), x.to_string()))
+            .collect(),
+        unresolved_txids: status
+            .unresolved_txids
+            .iter()
+            .map(|x| x.to_string())
+            .collect(),
+        unmined_terminals: status
+            .unmined_terminals
+            .iter()
+            .map(|x| x.to_string())
+            .collect(),
+        operations,
+        seals,
+        txids,
+        anchors,
+        anchor_mined,
+    }
 }
 
 pub fn accept_transfer<T>(
@@ -212,6 +523,13 @@ where
         }
     })?;
 
+    let violations = extract_supply_violations(&consig);
+    if !violations.is_empty() {
+        return Err(AcceptTransferError::InvalidConsig(
+            violations.into_iter().map(|(_, err)| err).collect(),
+        ));
+    }
+
     let bindle = Bindle::new(consig.clone());
     match stock.accept_transfer(consig, resolver, force) {
         Ok(_) => Ok(bindle),
@@ -219,6 +537,104 @@ where
     }
 }
 
+/// Outputs created by the witness transaction itself (ex: witness-vout invoices)
+pub fn extract_witness_outpoints(transfer: &Bindle<Transfer>) -> Vec<Outpoint> {
+    let mut outpoints = vec![];
+    for (bundle_id, terminal) in transfer.terminals() {
+        if let Some(AnchoredBundle { anchor, bundle: _ }) = transfer.anchored_bundle(bundle_id) {
+            for seal in terminal.seals.iter() {
+                if let TerminalSeal::WitnessVout(seal) = seal {
+                    outpoints.push(Outpoint::new(anchor.txid, seal.vout));
+                }
+            }
+        }
+    }
+    outpoints
+}
+
+/// Amounts assigned to each invoice beneficiary (blinded seal or witness address)
+pub fn extract_beneficiary_amounts<R>(
+    transfer: &Bindle<Transfer>,
+    network: Network,
+    resolver: &R,
+) -> BTreeMap<String, u64>
+where
+    R: ResolveTx,
+{
+    let mut amounts = BTreeMap::new();
+    for (bundle_id, terminal) in transfer.terminals() {
+        let anchor = match transfer.anchored_bundle(bundle_id) {
+            Some(AnchoredBundle { anchor, bundle: _ }) => anchor,
+            _ => continue,
+        };
+        let transitions = match transfer.known_transitions_by_bundle_id(bundle_id) {
+            Some(transitions) => transitions,
+            _ => continue,
+        };
+
+        for transition in transitions {
+            for (_, assigns) in transition.assignments.iter() {
+                let seals = assigns.to_confidential_seals();
+                for (index, secret) in seals.into_iter().enumerate() {
+                    let idx = index as u16;
+                    let value = if assigns.is_fungible() {
+                        match assigns.as_fungible_state_at(idx) {
+                            Ok(Some(reveal)) => reveal.value.as_u64(),
+                            _ => continue,
+                        }
+                    } else if assigns.is_structured() {
+                        1
+                    } else {
+                        continue;
+                    };
+
+                    for seal in terminal.seals.iter() {
+                        let beneficiary = match seal {
+                            TerminalSeal::ConcealedUtxo(concealed) if *concealed == secret => {
+                                Some(concealed.to_string())
+                            }
+                            TerminalSeal::WitnessVout(vout_seal) => {
+                                match assigns.revealed_seal_at(idx) {
+                                    Ok(Some(revealed))
+                                        if revealed.vout == vout_seal.vout
+                                            && revealed.blinding == vout_seal.blinding =>
+                                    {
+                                        witness_address(
+                                            anchor.txid,
+                                            vout_seal.vout,
+                                            network,
+                                            resolver,
+                                        )
+                                    }
+                                    _ => None,
+                                }
+                            }
+                            _ => None,
+                        };
+
+                        if let Some(beneficiary) = beneficiary {
+                            *amounts.entry(beneficiary).or_insert(0) += value;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    amounts
+}
+
+fn witness_address<R>(txid: Txid, vout: Vout, network: Network, resolver: &R) -> Option<String>
+where
+    R: ResolveTx,
+{
+    let tx = resolver.resolve_tx(txid).ok()?;
+    let output = tx.outputs.get(vout.into_usize())?;
+    let script = Script::from_bytes(output.script_pubkey.as_slice());
+    Address::from_script(script, network)
+        .ok()
+        .map(|address| address.to_string())
+}
+
 pub fn extract_transfer(transfer: String) -> Result<(Txid, Bindle<Transfer>), AcceptTransferError> {
     let serialized = Vec::<u8>::from_hex(&transfer).map_err(|_| AcceptTransferError::WrongHex)?;
     let confined = Confined::try_from_iter(serialized.iter().copied())
@@ -0,0 +1,187 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use bitcoin_30::{address::NetworkUnchecked, Address, Amount, Denomination, Network};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::Url;
+use rgbwallet::RgbInvoice;
+
+pub const BIP21_SCHEME: &str = "bitcoin";
+pub const BIP21_AMOUNT: &str = "amount";
+pub const BIP21_RGB_INVOICE: &str = "rgb";
+pub const BIP21_LIGHTNING: &str = "lightning";
+
+/// Characters escaped in the query values (all but the URI unreserved ones)
+const BIP21_QUERY_ESCAPE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+#[derive(Clone, Eq, PartialEq, Debug, Display, Error, From)]
+#[display(doc_comments)]
+pub enum UnifiedUriError {
+    /// '{0}' is an invalid BIP-21 URI
+    WrongUri(String),
+    /// '{0}' is an invalid bitcoin address
+    WrongAddress(String),
+    /// '{0}' is not an address of the {1} network
+    WrongNetwork(String, String),
+    /// '{0}' is an invalid on-chain amount
+    WrongAmount(String),
+    /// '{0}' is an invalid RGB invoice
+    WrongInvoice(String),
+    /// The URI does not contain an RGB invoice
+    NoInvoice,
+}
+
+/// BIP-21 URI carrying an RGB invoice and the on-chain/lightning fallbacks
+/// (ex: bitcoin:{address}?amount={btc}&rgb={invoice}&lightning={bolt11})
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct UnifiedUri {
+    pub address: String,
+    /// On-chain amount (in sats)
+    pub amount: Option<u64>,
+    pub rgb_invoice: Option<String>,
+    pub lightning: Option<String>,
+    /// Other query parameters (ex: label, message)
+    pub params: BTreeMap<String, String>,
+}
+
+impl UnifiedUri {
+    pub fn with(
+        network: &str,
+        address: Option<String>,
+        amount: Option<u64>,
+        rgb_invoice: Option<String>,
+        lightning: Option<String>,
+    ) -> Result<Self, UnifiedUriError> {
+        let uri = Self {
+            address: address.unwrap_or_default(),
+            amount,
+            rgb_invoice,
+            lightning,
+            params: BTreeMap::new(),
+        };
+        uri.check()?;
+        uri.require_network(network)
+    }
+
+    /// Check the URI address belongs to the given network
+    pub fn require_network(self, network: &str) -> Result<Self, UnifiedUriError> {
+        if !self.address.is_empty() {
+            let wrong_network =
+                || UnifiedUriError::WrongNetwork(self.address.clone(), network.to_string());
+            let network = Network::from_str(network).map_err(|_| wrong_network())?;
+            Address::<NetworkUnchecked>::from_str(&self.address)
+                .map_err(|_| UnifiedUriError::WrongAddress(self.address.clone()))?
+                .require_network(network)
+                .map_err(|_| wrong_network())?;
+        }
+        Ok(self)
+    }
+
+    fn check(&self) -> Result<(), UnifiedUriError> {
+        if !self.address.is_empty() {
+            Address::<NetworkUnchecked>::from_str(&self.address)
+                .map_err(|_| UnifiedUriError::WrongAddress(self.address.clone()))?;
+        }
+
+        if let Some(rgb_invoice) = &self.rgb_invoice {
+            RgbInvoice::from_str(rgb_invoice)
+                .map_err(|_| UnifiedUriError::WrongInvoice(rgb_invoice.clone()))?;
+        }
+
+        if self.address.is_empty() && self.rgb_invoice.is_none() && self.lightning.is_none() {
+            return Err(UnifiedUriError::WrongUri(self.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for UnifiedUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut pairs = vec![];
+        if let Some(amount) = self.amount {
+            let amount = Amount::from_sat(amount).to_string_in(Denomination::Bitcoin);
+            pairs.push((BIP21_AMOUNT, amount));
+        }
+        if let Some(rgb_invoice) = &self.rgb_invoice {
+            pairs.push((BIP21_RGB_INVOICE, rgb_invoice.clone()));
+        }
+        if let Some(lightning) = &self.lightning {
+            pairs.push((BIP21_LIGHTNING, lightning.clone()));
+        }
+        for (key, value) in &self.params {
+            pairs.push((key.as_str(), value.clone()));
+        }
+
+        write!(f, "{BIP21_SCHEME}:{}", self.address)?;
+        for (index, (key, value)) in pairs.into_iter().enumerate() {
+            let separator = if index == 0 { '?' } else { '&' };
+            let key = utf8_percent_encode(key, BIP21_QUERY_ESCAPE);
+            let value = utf8_percent_encode(&value, BIP21_QUERY_ESCAPE);
+            write!(f, "{separator}{key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for UnifiedUri {
+    type Err = UnifiedUriError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = Url::parse(s).map_err(|_| UnifiedUriError::WrongUri(s.to_string()))?;
+        if url.scheme() != BIP21_SCHEME {
+            return Err(UnifiedUriError::WrongUri(s.to_string()));
+        }
+
+        let mut uri = UnifiedUri {
+            address: url.path().to_string(),
+            ..Default::default()
+        };
+
+        // BIP-21 values are percent-encoded ('+' is a literal character, ex: in RGB invoices)
+        let query = url.query().unwrap_or_default();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let key = percent_decode_str(key)
+                .decode_utf8()
+                .map_err(|_| UnifiedUriError::WrongUri(s.to_string()))?;
+            let value = percent_decode_str(value)
+                .decode_utf8()
+                .map_err(|_| UnifiedUriError::WrongUri(s.to_string()))?;
+
+            match key.to_lowercase().as_str() {
+                BIP21_AMOUNT => {
+                    let amount = Amount::from_str_in(&value, Denomination::Bitcoin)
+                        .map_err(|_| UnifiedUriError::WrongAmount(value.to_string()))?;
+                    uri.amount = Some(amount.to_sat());
+                }
+                BIP21_RGB_INVOICE => uri.rgb_invoice = Some(value.to_string()),
+                BIP21_LIGHTNING => uri.lightning = Some(value.to_string()),
+                _ => {
+                    uri.params.insert(key.to_string(), value.to_string());
+                }
+            }
+        }
+
+        uri.check()?;
+        Ok(uri)
+    }
+}
+
+/// Extract the RGB invoice of a unified URI (a plain RGB invoice is returned as-is)
+pub fn extract_rgb_invoice(value: &str, network: &str) -> Result<String, UnifiedUriError> {
+    if !value
+        .to_lowercase()
+        .starts_with(&format!("{BIP21_SCHEME}:"))
+    {
+        return Ok(value.to_string());
+    }
+
+    UnifiedUri::from_str(value)?
+        .require_network(network)?
+        .rgb_invoice
+        .ok_or(UnifiedUriError::NoInvoice)
+}
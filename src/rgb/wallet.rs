@@ -17,11 +17,16 @@ use rgbstd::{
     persistence::{Inventory, Stash, Stock},
     validation::ResolveTx,
 };
-use strict_encoding::tn;
+use strict_encoding::{tn, FieldName};
 
 use crate::{
     debug,
-    rgb::{resolvers::ResolveSpent, structs::AddressTerminal},
+    rgb::{
+        constants::RGB_ASSET_OWNER,
+        contract::burned_supply,
+        resolvers::ResolveSpent,
+        structs::{AddressTerminal, RgbReservations},
+    },
     structs::{
         AllocationDetail, AllocationValue, TxStatus, UDAPosition, UtxoSpentStatus, WatcherDetail,
     },
@@ -143,6 +148,58 @@ pub fn next_address(
     Ok(addresses[addresses.len() - 1].clone())
 }
 
+/// Derive `count` unused addresses of the wallet (after the last known or reserved derivation)
+pub fn next_addresses(
+    iface_index: u32,
+    wallet: RgbWallet,
+    rgb_reservations: &RgbReservations,
+    network: AddressNetwork,
+    count: u32,
+) -> Result<Vec<AddressTerminal>, anyhow::Error> {
+    let reserved = rgb_reservations.last_index(iface_index);
+    let max = wallet
+        .utxos
+        .iter()
+        .filter(|utxo| utxo.derivation.terminal.app == iface_index)
+        .map(|utxo| utxo.derivation.terminal.index)
+        .chain(reserved)
+        .max()
+        .unwrap_or_default();
+
+    let next_index = max + 1;
+    let scripts = wallet
+        .descr
+        .derive(iface_index, next_index..next_index + count);
+    let addresses = scripts
+        .into_iter()
+        .map(|(d, sb)| {
+            let sc = Script::from_str(&sb.to_hex_string()).expect("invalid script data");
+            let address =
+                AddressCompat::from_script(&sc.into(), network).expect("invalid address data");
+            let terminal = d.terminal;
+            AddressTerminal { address, terminal }
+        })
+        .collect();
+
+    Ok(addresses)
+}
+
+/// Derive the next unused address of the wallet and reserve it, so that it
+/// is not handed out again before an UTXO is received (ex: witness invoices)
+pub fn reserve_address(
+    iface_index: u32,
+    wallet: &RgbWallet,
+    rgb_reservations: &mut RgbReservations,
+    network: AddressNetwork,
+) -> Result<AddressTerminal, anyhow::Error> {
+    let address = next_addresses(iface_index, wallet.clone(), rgb_reservations, network, 1)?
+        .pop()
+        .ok_or(anyhow!("no address derived"))?;
+
+    rgb_reservations.reserve(&address);
+    Ok(address)
+}
+
 pub fn next_utxo(
     iface_index: u32,
     wallet: RgbWallet,
@@ -327,6 +384,7 @@ where
     let iface_name = match iface_index {
         20 => "RGB20",
         21 => "RGB21",
+        25 => "RGB25",
         _ => "Contract",
     };
 
@@ -337,6 +395,10 @@ where
         if let Ok(contract) = stock.contract_iface(contract_id, iface.iface_id()) {
             let mut owners = vec![];
             for owned in &contract.iface.assignments {
+                if owned.name != FieldName::from(RGB_ASSET_OWNER) {
+                    continue;
+                }
+
                 if let Ok(allocations) = contract.fungible(owned.name.clone(), &None) {
                     for allocation in allocations {
                         let txid = bitcoin::Txid::from_str(&allocation.owner.txid.to_hex())
@@ -417,6 +479,7 @@ where
             details.push(WatcherDetail {
                 contract_id: contract_id.to_string(),
                 allocations: owners,
+                burned_supply: burned_supply(&contract),
             });
         }
     }
@@ -437,14 +500,21 @@ where
     let iface_name = match iface_index {
         20 => "RGB20",
         21 => "RGB21",
+        25 => "RGB25",
         _ => "Contract",
     };
     let iface = stock.iface_by_name(&tn!(iface_name))?;
 
     let mut owners = vec![];
+    let mut burned = 0;
     if let Ok(contract) = stock.contract_iface(contract_id, iface.iface_id()) {
+        burned = burned_supply(&contract);
         sync_wallet(iface_index, wallet, resolver);
         for owned in &contract.iface.assignments {
+            if owned.name != FieldName::from(RGB_ASSET_OWNER) {
+                continue;
+            }
+
             if let Ok(allocations) = contract.fungible(owned.name.clone(), &None) {
                 for allocation in allocations {
                     let txid = bitcoin::Txid::from_str(&allocation.owner.txid.to_hex())
@@ -524,5 +594,69 @@ where
     Ok(WatcherDetail {
         contract_id: contract_id.to_string(),
         allocations: owners,
+        burned_supply: burned,
     })
 }
+
+pub fn contract_rights<T>(
+    contract_id: ContractId,
+    iface_index: u32,
+    right: &'static str,
+    wallet: &mut RgbWallet,
+    stock: &mut Stock,
+    resolver: &mut T,
+) -> Result<Vec<AllocationDetail>, anyhow::Error>
+where
+    T: ResolveSpent + Resolver,
+{
+    let iface_name = match iface_index {
+        20 => "RGB20",
+        21 => "RGB21",
+        25 => "RGB25",
+        _ => "Contract",
+    };
+    let iface = stock.iface_by_name(&tn!(iface_name))?;
+
+    let mut rights = vec![];
+    if let Ok(contract) = stock.contract_iface(contract_id, iface.iface_id()) {
+        sync_wallet(iface_index, wallet, resolver);
+        if let Ok(allocations) = contract.fungible(FieldName::from(right), &None) {
+            for allocation in allocations {
+                let txid =
+                    bitcoin::Txid::from_str(&allocation.owner.txid.to_hex()).expect("invalid txid");
+                let index = allocation.owner.vout.into_u32();
+                let utxo_spent = resolver
+                    .resolve_spent_status(txid, index.into(), true)
+                    .expect("cannot find utxo");
+
+                if utxo_spent.clone().is_invalid_state() {
+                    continue;
+                }
+
+                let UtxoSpentStatus { is_spent, .. } = utxo_spent;
+                if let Some(utxo) = wallet.utxo(allocation.owner) {
+                    rights.push(AllocationDetail {
+                        utxo: utxo.outpoint.to_string(),
+                        value: AllocationValue::Value(allocation.value),
+                        derivation: format!(
+                            "/{}/{}",
+                            utxo.derivation.terminal.app, utxo.derivation.terminal.index
+                        ),
+                        is_mine: true,
+                        is_spent,
+                    });
+                } else {
+                    rights.push(AllocationDetail {
+                        utxo: allocation.owner.to_string(),
+                        value: AllocationValue::Value(allocation.value),
+                        derivation: default!(),
+                        is_mine: false,
+                        is_spent,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(rights)
+}
@@ -1,5 +1,5 @@
 #![allow(deprecated)]
-use bp::Outpoint;
+use bp::{seals::txout::CloseMethod, Outpoint};
 use garde::Validate;
 use psbt::Psbt;
 use rgb::MiningStatus;
@@ -15,9 +15,11 @@ use crate::{
     rgb::{
         structs::MediaMetadata,
         swap::{PublicRgbBid, RgbBid, RgbOffer, RgbOfferSwap},
+        uri::UnifiedUri,
     },
     validators::{
-        verify_descriptor, verify_media_request, verify_rgb_invoice, verify_tapret_seal,
+        verify_descriptor, verify_invoice_expiry, verify_media_digest, verify_media_digests,
+        verify_media_preview, verify_media_request, verify_rgb_invoice, verify_seal_commitment,
         verify_terminal_path, RGBContext,
     },
 };
@@ -135,16 +137,402 @@ pub struct IssueRequest {
     /// Precision of the asset
     #[garde(range(min = u8::MIN, max = u8::MAX))]
     pub precision: u8,
-    /// Seal of the initial owner
+    /// Seal of the initial owner (receives the supply not listed in `allocations`)
     #[garde(ascii)]
-    #[garde(custom(verify_tapret_seal))]
+    #[garde(custom(verify_seal_commitment))]
     pub seal: String,
-    /// The name of the iface (ex: RGB20)
+    /// Additional genesis allocations (only RGB20 and RGB25)
+    #[garde(dive)]
+    #[garde(length(min = 0, max = 999))]
+    #[serde(default)]
+    pub allocations: Vec<IssueAllocationRequest>,
+    /// The name of the iface (ex: RGB20, RGB21 or RGB25)
     #[garde(alphanumeric)]
     pub iface: String,
-    /// contract metadata (only RGB21/UDA)
+    /// contract metadata (RGB21/UDA, or the contract media of RGB20 and RGB25/CFA)
     #[garde(custom(verify_media_request))]
     pub meta: Option<IssueMediaRequest>,
+    /// The ricardian contract terms (by default, the description)
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = u16::MAX.into()))]
+    #[serde(default)]
+    pub terms: Option<String>,
+    /// Document attached to the contract, ex: prospectus (only RGB20 and RGB25/CFA).
+    /// The document is stored in the proxy and only the hash is committed.
+    #[garde(dive)]
+    #[serde(default)]
+    pub attachment: Option<MediaItemRequest>,
+    /// inflation right reserved at genesis (only RGB20)
+    #[garde(dive)]
+    pub inflation: Option<IssueInflationRequest>,
+    /// tokens of the collection, each one allocated to its own seal (only RGB21)
+    #[garde(dive)]
+    #[garde(length(min = 0, max = 999))]
+    #[serde(default)]
+    pub collection: Vec<IssueTokenRequest>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct IssueAllocationRequest {
+    /// Seal of the allocation owner
+    #[garde(ascii)]
+    #[garde(custom(verify_seal_commitment))]
+    pub seal: String,
+    /// Amount of the asset assigned to the seal
+    #[garde(range(min = u64::MIN, max = u64::MAX))]
+    pub amount: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct IssueTokenRequest {
+    /// Seal of the token owner
+    #[garde(ascii)]
+    #[garde(custom(verify_seal_commitment))]
+    pub seal: String,
+    /// Preview of the token (base64)
+    #[garde(dive)]
+    #[garde(custom(verify_media_preview))]
+    pub preview: Option<MediaInfo>,
+    /// Media of the token (digest)
+    #[garde(dive)]
+    #[garde(custom(verify_media_digest))]
+    pub media: Option<MediaInfo>,
+    /// Attachments of the token (digest)
+    #[garde(dive)]
+    #[garde(length(min = 0, max = 20))]
+    #[garde(custom(verify_media_digests))]
+    #[serde(default)]
+    pub attachments: Vec<MediaInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct IssueInflationRequest {
+    /// Seal of the inflation right owner (must differ from the issue seal)
+    #[garde(ascii)]
+    #[garde(custom(verify_seal_commitment))]
+    pub seal: String,
+    /// Amount of the asset that can be issued later
+    #[garde(range(min = u64::MIN, max = u64::MAX))]
+    pub amount: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct InflateRequest {
+    /// The contract id
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub contract_id: String,
+    /// Amount of the asset to be issued
+    #[garde(range(min = u64::MIN, max = u64::MAX))]
+    pub amount: u64,
+    /// Seal of the new supply owner
+    #[garde(ascii)]
+    #[garde(custom(verify_seal_commitment))]
+    pub seal: String,
+    /// Asset Descriptor
+    #[garde(custom(verify_descriptor))]
+    pub descriptor: SecretString,
+    /// Asset Terminal Change
+    #[garde(ascii)]
+    pub change_terminal: String,
+    /// Bitcoin Fee
+    #[garde(dive)]
+    pub fee: PsbtFeeRequest,
+    /// Seal Close Method (default: tapret1st)
+    #[garde(skip)]
+    #[serde(default)]
+    pub close_method: SealCloseMethod,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InflateResponse {
+    /// The contract id
+    pub contract_id: String,
+    /// Consignment ID
+    pub consig_id: String,
+    /// Consignment encoded (in hexadecimal)
+    pub consig: String,
+    /// PSBT File Information with tapret (in hexadecimal)
+    pub psbt: String,
+    /// Tapret Commitment (used to spend output)
+    pub commit: String,
+    /// Inflation Bitcoin L1 transaction id
+    pub txid: String,
+    /// Issued supply (after inflation)
+    pub supply: u64,
+    /// Issued supply plus the remaining inflation allowance (not a consensus limit)
+    pub max_supply: u64,
+    /// Effective fee rate (in sat/vB)
+    pub fee_rate: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct BurnRequest {
+    /// The contract id (IFA contracts only)
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub contract_id: String,
+    /// Amount of the asset to be burned
+    #[garde(range(min = 1, max = u64::MAX))]
+    pub amount: u64,
+    /// Asset Descriptor
+    #[garde(custom(verify_descriptor))]
+    pub descriptor: SecretString,
+    /// Asset Terminal Change
+    #[garde(ascii)]
+    pub change_terminal: String,
+    /// Bitcoin Fee
+    #[garde(dive)]
+    pub fee: PsbtFeeRequest,
+    /// Seal Close Method (default: tapret1st)
+    #[garde(skip)]
+    #[serde(default)]
+    pub close_method: SealCloseMethod,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BurnResponse {
+    /// The contract id
+    pub contract_id: String,
+    /// Consignment ID
+    pub consig_id: String,
+    /// Consignment encoded (in hexadecimal)
+    pub consig: String,
+    /// PSBT File Information with tapret (in hexadecimal)
+    pub psbt: String,
+    /// Tapret Commitment (used to spend output)
+    pub commit: String,
+    /// Burn Bitcoin L1 transaction id (on-chain proof of the burn)
+    pub txid: String,
+    /// Supply destroyed by burn operations (after burn, checked by bitmask only)
+    pub burned_supply: u64,
+    /// Circulating supply of the asset (after burn)
+    pub circulating_supply: u64,
+    /// Effective fee rate (in sat/vB)
+    pub fee_rate: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct ConsolidateRequest {
+    /// The contract ids (all their allocations are merged into a single UTXO)
+    #[garde(length(min = 1, max = 999))]
+    pub contract_ids: Vec<String>,
+    /// Asset Descriptor
+    #[garde(custom(verify_descriptor))]
+    pub descriptor: SecretString,
+    /// Bitcoin Terminal Change
+    #[garde(ascii)]
+    pub change_terminal: String,
+    /// Bitcoin Fee
+    #[garde(dive)]
+    pub fee: PsbtFeeRequest,
+    /// Seal Close Method (default: tapret1st)
+    #[garde(skip)]
+    #[serde(default)]
+    pub close_method: SealCloseMethod,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsolidateResponse {
+    /// Watcher addresses of the new UTXOs, by contract
+    pub addresses: BTreeMap<String, String>,
+    /// Number of UTXOs merged
+    pub utxos: usize,
+    /// Amount consolidated, by contract
+    pub amounts: BTreeMap<String, u64>,
+    /// Consignment ID
+    pub consig_id: String,
+    /// Consignment encoded (in hexadecimal)
+    pub consig: String,
+    /// Strict Consignments of the other contracts, by contract and beneficiary (in hexadecimal)
+    pub consigs: BTreeMap<String, String>,
+    /// PSBT File Information with tapret (in hexadecimal)
+    pub psbt: String,
+    /// Tapret Commitment (used to spend output)
+    pub commit: String,
+    /// Consolidation Bitcoin L1 transaction id
+    pub txid: String,
+    /// Effective fee rate (in sat/vB)
+    pub fee_rate: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct SplitRequest {
+    /// The contract id
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub contract_id: String,
+    /// The allocation UTXO to be split
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub utxo: String,
+    /// Amounts of the new allocations (one output per amount)
+    #[garde(length(min = 1, max = 999))]
+    pub amounts: Vec<u64>,
+    /// Asset Descriptor
+    #[garde(custom(verify_descriptor))]
+    pub descriptor: SecretString,
+    /// Asset Terminal Change
+    #[garde(ascii)]
+    pub change_terminal: String,
+    /// Bitcoin Fee
+    #[garde(dive)]
+    pub fee: PsbtFeeRequest,
+    /// Seal Close Method (default: tapret1st)
+    #[garde(skip)]
+    #[serde(default)]
+    pub close_method: SealCloseMethod,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitResponse {
+    /// New allocations (format: {address}:{amount})
+    pub allocations: Vec<String>,
+    /// Consignment ID
+    pub consig_id: String,
+    /// Consignment encoded (in hexadecimal)
+    pub consig: String,
+    /// Strict Consignments of the other allocations, by contract and beneficiary (in hexadecimal)
+    pub consigs: BTreeMap<String, String>,
+    /// PSBT File Information with tapret (in hexadecimal)
+    pub psbt: String,
+    /// Tapret Commitment (used to spend output)
+    pub commit: String,
+    /// Split Bitcoin L1 transaction id
+    pub txid: String,
+    /// Effective fee rate (in sat/vB)
+    pub fee_rate: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct CpfpRequest {
+    /// The unconfirmed RGB transfer transaction id
+    #[garde(ascii)]
+    #[garde(length(min = 64, max = 64))]
+    pub txid: String,
+    /// Target fee rate of the parent and child package (in sat/vB)
+    #[garde(skip)]
+    pub fee_rate: f32,
+    /// Bitcoin Descriptor
+    #[garde(custom(verify_descriptor))]
+    pub descriptor: SecretString,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CpfpResponse {
+    /// The unconfirmed RGB transfer transaction id
+    pub parent_txid: String,
+    /// Outputs spent by the child transaction (format: {txid}:{vout})
+    pub inputs: Vec<String>,
+    /// Child transaction fee (in sats)
+    pub fee: u64,
+    /// Fee rate of the parent and child package (in sat/vB)
+    pub package_fee_rate: f32,
+    /// Child PSBT File Information (in hexadecimal)
+    pub psbt: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct IssueCustomRequest {
+    /// The schema id (the schema must be available in the stock)
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub schema_id: String,
+    /// The name of the iface implemented by the schema
+    #[garde(alphanumeric)]
+    pub iface: String,
+    /// Global state of the genesis
+    #[garde(dive)]
+    #[garde(length(min = 0, max = 999))]
+    pub globals: Vec<IssueGlobalStateRequest>,
+    /// Owned state of the genesis
+    #[garde(dive)]
+    #[garde(length(min = 1, max = 999))]
+    pub assignments: Vec<IssueAssignmentRequest>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct IssueGlobalStateRequest {
+    /// The global state name (as defined by the iface)
+    #[garde(ascii)]
+    #[garde(length(min = 1, max = 100))]
+    pub name: String,
+    /// The global state value (typed JSON, checked against the schema type)
+    #[garde(skip)]
+    pub value: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct IssueAssignmentRequest {
+    /// The owned state name (as defined by the iface)
+    #[garde(ascii)]
+    #[garde(length(min = 1, max = 100))]
+    pub name: String,
+    /// Seal of the owner
+    #[garde(ascii)]
+    #[garde(custom(verify_seal_commitment))]
+    pub seal: String,
+    /// Amount of the fungible state
+    #[garde(skip)]
+    pub amount: Option<u64>,
+    /// Value of the structured state (typed JSON, checked against the schema type)
+    #[garde(skip)]
+    pub data: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueCustomResponse {
+    /// The contract id
+    pub contract_id: String,
+    /// The schema id
+    pub schema_id: String,
+    /// The contract impl id
+    pub iimpl_id: String,
+    /// The contract interface
+    pub iface: String,
+    /// The contract state (multiple formats)
+    pub contract: ContractFormats,
+    /// Genesis
+    pub genesis: GenesisFormats,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -344,6 +732,9 @@ pub struct IssueResponse {
     pub genesis: GenesisFormats,
     /// contract metadata (only RGB21/UDA)
     pub meta: Option<ContractMediaDetail>,
+    /// tokens of the collection (only RGB21 with many tokens)
+    #[serde(default)]
+    pub tokens: Vec<UDADetail>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -387,6 +778,8 @@ pub enum AssetType {
     RGB20 = 20,
     #[serde(rename = "rgb21")]
     RGB21 = 21,
+    #[serde(rename = "rgb25")]
+    RGB25 = 25,
 }
 
 impl std::fmt::Display for AssetType {
@@ -399,16 +792,53 @@ impl std::fmt::Display for AssetType {
 #[serde(rename_all = "camelCase")]
 #[derive(Validate)]
 #[garde(context(RGBContext))]
-pub struct ImportRequest {
-    /// The type data
+pub struct ImportRequest {
+    /// The type data
+    #[garde(skip)]
+    pub import: AssetType,
+    /// The payload data (in hexadecimal)
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = usize::MAX))]
+    pub data: String,
+}
+
+#[derive(Eq, PartialEq, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum SchemaImportType {
+    #[default]
+    #[serde(rename = "schema")]
+    Schema,
+    #[serde(rename = "interface")]
+    Iface,
+    #[serde(rename = "implementation")]
+    IfaceImpl,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct ImportSchemaRequest {
+    /// The type data (schema, interface or implementation)
     #[garde(skip)]
-    pub import: AssetType,
-    /// The payload data (in hexadecimal)
+    pub import: SchemaImportType,
+    /// The payload data (armored or strict encoded in hexadecimal)
     #[garde(ascii)]
     #[garde(length(min = 0, max = usize::MAX))]
     pub data: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSchemaResponse {
+    /// The type data (schema, interface or implementation)
+    pub import: SchemaImportType,
+    /// The schema, interface or implementation id
+    pub id: String,
+    /// The interface name (only interface)
+    pub name: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ContractHiddenResponse {
@@ -435,8 +865,14 @@ pub struct ContractResponse {
     pub created: i64,
     /// Description of the asset
     pub description: String,
-    /// Supply of the asset
+    /// Issued supply of the asset
     pub supply: u64,
+    /// Issued supply plus the remaining inflation allowance (not a consensus limit)
+    pub max_supply: u64,
+    /// Supply destroyed by burn operations
+    pub burned_supply: u64,
+    /// Circulating supply of the asset (issued supply minus burned supply)
+    pub circulating_supply: u64,
     /// Precision of the asset
     pub precision: u8,
     /// Current balance
@@ -451,6 +887,9 @@ pub struct ContractResponse {
     pub genesis: GenesisFormats,
     /// contract metadata (only RGB21/UDA)
     pub meta: Option<ContractMediaDetail>,
+    /// tokens of the collection (only RGB21 with many tokens)
+    #[serde(default)]
+    pub tokens: Vec<UDADetail>,
 }
 
 #[deprecated(
@@ -508,6 +947,9 @@ pub struct ContractMediaDetail {
     pub attachments: Vec<MediaInfo>,
     /// The contract allocations
     pub allocations: Vec<AllocationDetail>,
+    /// The ricardian contract terms
+    #[serde(default)]
+    pub terms: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -523,6 +965,9 @@ pub struct UDADetail {
     pub description: String,
     /// The user contract balance
     pub balance: u64,
+    /// Preview of the uda
+    #[serde(default)]
+    pub preview: Option<MediaInfo>,
     /// Media of the uda
     pub media: Vec<MediaInfo>,
     /// Attach of the uda
@@ -557,11 +1002,52 @@ pub struct InvoiceRequest {
     pub amount: String,
     /// Blinded UTXO
     #[garde(ascii)]
-    #[garde(custom(verify_tapret_seal))]
+    #[garde(custom(verify_seal_commitment))]
     pub seal: String,
     /// Query parameters
     #[garde(skip)]
     pub params: HashMap<String, String>,
+    /// Expiration date (unix timestamp)
+    #[garde(custom(verify_invoice_expiry))]
+    #[serde(default)]
+    pub expiry: Option<i64>,
+    /// Transport endpoints (default: configured RGB transport)
+    #[garde(skip)]
+    #[serde(default)]
+    pub transports: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct WitnessInvoiceRequest {
+    /// The contract id
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub contract_id: String,
+    /// The contract interface
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 32))]
+    pub iface: String,
+    /// Amount of the asset
+    #[garde(skip)]
+    pub amount: String,
+    /// Beneficiary address (default: next address of the watcher)
+    #[garde(skip)]
+    #[serde(default)]
+    pub address: Option<String>,
+    /// Query parameters
+    #[garde(skip)]
+    pub params: HashMap<String, String>,
+    /// Expiration date (unix timestamp)
+    #[garde(custom(verify_invoice_expiry))]
+    #[serde(default)]
+    pub expiry: Option<i64>,
+    /// Transport endpoints (default: configured RGB transport)
+    #[garde(skip)]
+    #[serde(default)]
+    pub transports: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -585,6 +1071,60 @@ pub struct InvoiceResponse {
     pub invoice: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct UnifiedUriRequest {
+    /// RGB Invoice
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 512))]
+    pub rgb_invoice: String,
+    /// On-chain fallback address
+    #[garde(skip)]
+    #[serde(default)]
+    pub address: Option<String>,
+    /// On-chain fallback amount (in sats)
+    #[garde(skip)]
+    #[serde(default)]
+    pub amount: Option<u64>,
+    /// Lightning fallback invoice (BOLT11)
+    #[garde(skip)]
+    #[serde(default)]
+    pub lightning: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UnifiedUriResponse {
+    /// BIP-21 URI (bitcoin:{address}?amount={btc}&rgb={invoice}&lightning={bolt11})
+    pub uri: String,
+    /// RGB Invoice
+    pub rgb_invoice: Option<String>,
+    /// On-chain fallback address
+    pub address: Option<String>,
+    /// On-chain fallback amount (in sats)
+    pub amount: Option<u64>,
+    /// Lightning fallback invoice (BOLT11)
+    pub lightning: Option<String>,
+}
+
+impl From<UnifiedUri> for UnifiedUriResponse {
+    fn from(value: UnifiedUri) -> Self {
+        Self {
+            uri: value.to_string(),
+            rgb_invoice: value.rgb_invoice,
+            address: if value.address.is_empty() {
+                None
+            } else {
+                Some(value.address)
+            },
+            amount: value.amount,
+            lightning: value.lightning,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 #[derive(Validate)]
@@ -613,6 +1153,34 @@ pub struct PsbtRequest {
     /// Allow RBF
     #[garde(skip)]
     pub rbf: bool,
+    /// Seal Close Method (default: tapret1st)
+    #[garde(skip)]
+    #[serde(default)]
+    pub close_method: SealCloseMethod,
+    /// Coin Selection Strategy (bitcoin inputs are candidates when it is not the default)
+    #[garde(skip)]
+    #[serde(default)]
+    pub coin_selection: CoinSelectionStrategy,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SealCloseMethod {
+    /// Commitment in the taproot output (tapret1st)
+    #[default]
+    #[serde(rename = "tapret1st")]
+    TapretFirst,
+    /// Commitment in an OP_RETURN output (opret1st)
+    #[serde(rename = "opret1st")]
+    OpretFirst,
+}
+
+impl From<SealCloseMethod> for CloseMethod {
+    fn from(method: SealCloseMethod) -> Self {
+        match method {
+            SealCloseMethod::TapretFirst => CloseMethod::TapretFirst,
+            SealCloseMethod::OpretFirst => CloseMethod::OpretFirst,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -665,6 +1233,11 @@ pub enum PsbtSigHashRequest {
 pub enum PsbtFeeRequest {
     Value(#[garde(range(min = 0, max = u64::MAX))] u64),
     FeeRate(#[garde(skip)] f32),
+    /// Fee rate estimated by the explorer to confirm within the target blocks
+    Target {
+        #[garde(range(min = 1, max = 1008))]
+        blocks: u16,
+    },
 }
 
 impl Default for PsbtFeeRequest {
@@ -673,6 +1246,25 @@ impl Default for PsbtFeeRequest {
     }
 }
 
+/// Strategy used to choose the asset and bitcoin UTXOs spent in an operation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum CoinSelectionStrategy {
+    /// UTXOs in wallet order
+    #[default]
+    Default,
+    /// Biggest UTXOs first (fewer inputs)
+    LargestFirst,
+    /// Smallest UTXOs first (consolidates dust UTXOs)
+    SmallestFirst,
+    /// UTXOs matching the target amount (avoids change), fallback to largest first
+    BranchAndBound,
+    /// Single UTXO when possible and never mixes contracts in the same transaction
+    Privacy,
+    /// Only the given outpoints (format: {txid}:{vout})
+    Manual(Vec<String>),
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 
@@ -681,6 +1273,8 @@ pub struct PsbtResponse {
     pub psbt: String,
     /// Asset UTXO Terminal (ex. /0/0)
     pub terminal: String,
+    /// Effective fee rate (in sat/vB)
+    pub fee_rate: f32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -742,6 +1336,10 @@ pub struct RgbTransferRequest {
     /// Asset UTXO Terminal (ex. /0/0)
     #[garde(custom(verify_terminal_path))]
     pub terminal: String,
+    /// Other RGB Invoices paid in the same transaction
+    #[garde(length(min = 0, max = 999))]
+    #[serde(default)]
+    pub other_invoices: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -756,9 +1354,9 @@ pub struct FullRgbTransferRequest {
     #[garde(ascii)]
     #[garde(length(min = 0, max = 32))]
     pub iface: String,
-    /// RGB Invoice
+    /// RGB Invoice or BIP-21 URI with an RGB invoice
     #[garde(ascii)]
-    #[garde(length(min = 0, max = 512))]
+    #[garde(length(min = 0, max = 2048))]
     pub rgb_invoice: String,
     /// Asset Descriptor
     #[garde(custom(verify_descriptor))]
@@ -772,6 +1370,18 @@ pub struct FullRgbTransferRequest {
     /// Bitcoin Change Addresses (format: {address}:{amount})
     #[garde(length(min = 0, max = 999))]
     pub bitcoin_changes: Vec<String>,
+    /// Other RGB Invoices paid in the same transaction
+    #[garde(length(min = 0, max = 999))]
+    #[serde(default)]
+    pub other_invoices: Vec<String>,
+    /// Coin Selection Strategy
+    #[garde(skip)]
+    #[serde(default)]
+    pub coin_selection: CoinSelectionStrategy,
+    /// Seal Close Method (default: tapret1st)
+    #[garde(skip)]
+    #[serde(default)]
+    pub close_method: SealCloseMethod,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -879,8 +1489,73 @@ pub struct RgbTransferResponse {
     pub psbt: String,
     /// Tapret Commitment (used to spend output)
     pub commit: String,
+    /// Strict Consignments of the other invoices, by contract and beneficiary (in hexadecimal)
+    pub consigs: BTreeMap<String, String>,
+    /// Transfer Bitcoin L1 transaction id
+    pub txid: String,
+    /// Effective fee rate (in sat/vB)
+    pub fee_rate: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct RgbTransferBundle {
+    /// Consignment ID
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub consig_id: String,
+    /// Consignment encoded (in hexadecimal)
+    #[garde(ascii)]
+    pub consig: String,
+    /// Strict Consignments of the other invoices, by contract and beneficiary (in hexadecimal)
+    #[garde(skip)]
+    pub consigs: BTreeMap<String, String>,
+    /// The name of the iface (ex: RGB20)
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 32))]
+    pub iface: String,
+    /// Beneficiary of the RGB invoice
+    #[garde(ascii)]
+    pub beneficiary: String,
+    /// Unsigned PSBT File Information with tapret (in hexadecimal)
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = usize::MAX))]
+    pub psbt: String,
+    /// Outpoint (used to spend output)
+    #[garde(ascii)]
+    pub outpoint: String,
+    /// Outpoint Amount (used to spend output)
+    #[garde(skip)]
+    pub amount: u64,
+    /// Tapret Commitment (used to spend output)
+    #[garde(ascii)]
+    pub commit: String,
+    /// Asset UTXO Terminal (ex. /0/0)
+    #[garde(custom(verify_terminal_path))]
+    pub terminal: String,
     /// Transfer Bitcoin L1 transaction id
+    #[garde(ascii)]
+    #[garde(length(min = 64, max = 64))]
     pub txid: String,
+    /// Effective fee rate (in sat/vB)
+    #[garde(skip)]
+    pub fee_rate: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct RgbSignedTransferRequest {
+    /// Signed PSBT File Information (in hexadecimal)
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = usize::MAX))]
+    pub psbt: String,
+    /// Transfer bundle exported with the unsigned PSBT
+    #[garde(dive)]
+    pub bundle: RgbTransferBundle,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -944,6 +1619,100 @@ pub struct AcceptResponse {
     pub contract_id: String,
     /// Transfer accept status
     pub valid: bool,
+    /// Consignment validation report
+    pub report: ConsignmentValidationReport,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchAcceptResponse {
+    /// Accept result of each consignment (in the request order)
+    pub transfers: Vec<BatchAcceptItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchAcceptItem {
+    /// Position of the consignment in the request
+    pub index: usize,
+    /// Accept response (when the consignment was accepted)
+    pub accept: Option<AcceptResponse>,
+    /// Reason of the rejection (when the consignment was not accepted)
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct ValidateConsignmentRequest {
+    /// Consignment encoded in hexadecimal
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = usize::MAX))]
+    pub consignment: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsignmentValidationReport {
+    /// Transfer ID
+    pub transfer_id: String,
+    /// Contract ID
+    pub contract_id: String,
+    /// Consignment validity
+    pub validity: ConsignmentValidity,
+    /// Consignment validation status
+    pub valid: bool,
+    /// Validation failures
+    pub failures: Vec<ConsignmentValidationItem>,
+    /// Validation warnings
+    pub warnings: Vec<ConsignmentValidationItem>,
+    /// Validation info
+    pub info: Vec<ConsignmentValidationItem>,
+    /// Transactions that the resolver could not retrieve
+    pub unresolved_txids: Vec<String>,
+    /// Terminal transactions not mined yet
+    pub unmined_terminals: Vec<String>,
+    /// State transitions of the transfer (operation IDs)
+    pub operations: Vec<String>,
+    /// Terminal seals of the transfer
+    pub seals: Vec<String>,
+    /// Anchor transactions of the consignment
+    pub txids: Vec<String>,
+    /// Anchor transactions of the transfer (witness transactions)
+    pub anchors: Vec<ConsignmentAnchorStatus>,
+    /// All anchor transactions of the transfer are mined
+    pub anchor_mined: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ConsignmentValidity {
+    #[default]
+    Valid,
+    UnresolvedTransactions,
+    ValidExceptEndpoints,
+    Invalid,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsignmentValidationItem {
+    /// Kind of the failure, warning or info (ex: SealNoWitnessTx)
+    pub code: String,
+    /// Description
+    pub message: String,
+    /// Operation IDs, seals and txids of the consignment involved
+    pub involved: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsignmentAnchorStatus {
+    /// Anchor transaction id
+    pub txid: String,
+    /// Anchor transaction status
+    pub status: TxStatus,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1066,6 +1835,8 @@ pub struct WatcherDetail {
     pub contract_id: String,
     /// Allocations
     pub allocations: Vec<AllocationDetail>,
+    /// Supply destroyed by burn operations
+    pub burned_supply: u64,
 }
 
 #[derive(Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize, Debug, Clone, Default)]
@@ -1237,6 +2008,87 @@ pub struct RgbTransferDetail {
     pub ty: TransferType,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[derive(Validate)]
+#[garde(context(RGBContext))]
+pub struct RgbTransferHistoryRequest {
+    /// The contract id
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub contract_id: String,
+    /// Number of transfers to skip (newest first)
+    #[garde(skip)]
+    #[serde(default)]
+    pub offset: usize,
+    /// Max. number of transfers returned
+    #[garde(range(min = 1, max = 100))]
+    pub limit: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RgbTransferHistoryResponse {
+    /// The contract id
+    pub contract_id: String,
+    /// Total of transfers of the contract
+    pub total: usize,
+    /// Number of transfers skipped
+    pub offset: usize,
+    /// Max. number of transfers returned
+    pub limit: usize,
+    /// Transfers of the page (newest first)
+    pub transfers: Vec<RgbTransferHistoryItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RgbTransferHistoryItem {
+    /// Consignment ID
+    pub consig_id: String,
+    /// Bitcoin L1 transaction id
+    pub txid: String,
+    /// The name of the iface (ex: RGB20)
+    pub iface: String,
+    #[serde(rename = "type")]
+    pub ty: TransferType,
+    /// Amount moved (for UDA, the number of tokens)
+    pub amount: u64,
+    /// Counterparty beneficiaries (sended transfers)
+    pub beneficiaries: Vec<RgbTransferBeneficiary>,
+    /// Bitcoin fee paid (sended transfers, in sats)
+    pub fee: Option<u64>,
+    /// Transaction status
+    pub status: TxStatus,
+    /// Block height
+    pub block_height: Option<u32>,
+    /// Block time (unix timestamp)
+    pub block_time: Option<u64>,
+    /// Number of confirmations
+    pub confirmations: u32,
+    /// Transfer can be replaced by fee (RBF)
+    pub rbf: bool,
+    /// Consignments replaced by this transfer (oldest first)
+    pub replaces: Vec<String>,
+    /// Consignment that replaced this transfer
+    pub replaced_by: Option<String>,
+    /// Invoice paid by this transfer
+    pub invoice: Option<String>,
+    /// Swap offer filled by this transfer
+    pub offer_id: Option<String>,
+    /// Swap bid filled by this transfer
+    pub bid_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RgbTransferBeneficiary {
+    /// Blinded UTXO or witness address
+    pub beneficiary: String,
+    /// Amount assigned to the beneficiary
+    pub amount: Option<u64>,
+}
+
 #[derive(
     Eq, Ord, PartialEq, PartialOrd, Hash, Serialize, Deserialize, Clone, Debug, Display, Default,
 )]
@@ -1254,6 +2106,15 @@ pub enum TxStatus {
     Block(u32),
 }
 
+/// Fee paid by a transaction (as reported by the explorer)
+#[derive(Eq, PartialEq, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct TxFee {
+    /// Fee (in sats)
+    pub fee: u64,
+    /// Weight (in weight units)
+    pub weight: u64,
+}
+
 #[derive(Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize, Clone, Debug, Display)]
 #[serde(rename_all = "camelCase")]
 pub enum TransferType {
@@ -1271,12 +2132,66 @@ pub enum TransferType {
 pub struct RgbInvoiceResponse {
     pub contract_id: String,
     pub amount: u64,
+    /// Expiration date (unix timestamp)
+    pub expiry: Option<i64>,
+    /// Transport endpoints
+    pub transports: Vec<String>,
+}
+
+#[derive(
+    Eq, Ord, PartialEq, PartialOrd, Hash, Serialize, Deserialize, Clone, Debug, Display, Default,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum InvoiceStatus {
+    #[default]
+    #[display(inner)]
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "partially_paid")]
+    PartiallyPaid,
+    #[serde(rename = "paid")]
+    Paid,
+    #[serde(rename = "expired")]
+    Expired,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RgbInvoiceStatusDetail {
+    /// The RGB invoice
+    pub invoice: String,
+    /// The contract id
+    pub contract_id: String,
+    /// The contract interface
+    pub iface: String,
+    /// Requested amount (in atomic units)
+    pub amount: u64,
+    /// Received amount (in atomic units)
+    pub received: u64,
+    /// Blinded seal or witness address of the invoice
+    pub beneficiary: String,
+    /// Creation date (unix timestamp)
+    pub created: i64,
+    /// Expiration date (unix timestamp)
+    pub expiry: Option<i64>,
+    /// Transfers which paid the invoice
+    pub transfers: Vec<String>,
+    /// Payment status
+    pub status: InvoiceStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RgbInvoicesResponse {
+    pub invoices: Vec<RgbInvoiceStatusDetail>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchRgbTransferResponse {
     pub transfers: Vec<BatchRgbTransferItem>,
+    /// Accepted transfers whose anchor block was reorged out (pending again)
+    pub reorgs: Vec<BatchRgbTransferItem>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1341,6 +2256,14 @@ pub struct RgbOfferRequest {
     pub presig: bool,
     #[garde(skip)]
     pub expire_at: Option<i64>,
+    /// Coin Selection Strategy
+    #[garde(skip)]
+    #[serde(default)]
+    pub coin_selection: CoinSelectionStrategy,
+    /// Seal Close Method (default: tapret1st)
+    #[garde(skip)]
+    #[serde(default)]
+    pub close_method: SealCloseMethod,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Display, Default)]
@@ -1391,6 +2314,35 @@ pub struct RgbOfferUpdateResponse {
     pub updated: bool,
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug, Display, Default, Validate)]
+#[garde(context(RGBContext))]
+#[serde(rename_all = "camelCase")]
+#[display("{contract_id}:{offer_id}")]
+pub struct RgbOfferCancelRequest {
+    /// The Contract ID
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub contract_id: String,
+    /// The Offer ID
+    #[garde(ascii)]
+    #[garde(length(min = 0, max = 100))]
+    pub offer_id: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Display, Default)]
+#[serde(rename_all = "camelCase")]
+#[display("{offer_id}:{cancelled}")]
+pub struct RgbOfferCancelResponse {
+    /// The Contract ID
+    pub contract_id: String,
+    /// The Offer ID
+    pub offer_id: String,
+    /// Cancelled?
+    pub cancelled: bool,
+    /// Outpoints released by the offer
+    pub released: Vec<String>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug, Display, Default, Validate)]
 #[garde(context(RGBContext))]
 #[serde(rename_all = "camelCase")]
@@ -1412,6 +2364,10 @@ pub struct RgbBidRequest {
     /// Bitcoin Fee
     #[garde(dive)]
     pub fee: PsbtFeeRequest,
+    /// Seal Close Method of the offer (default: tapret1st)
+    #[garde(skip)]
+    #[serde(default)]
+    pub close_method: SealCloseMethod,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Display, Default)]
@@ -1428,6 +2384,8 @@ pub struct RgbBidResponse {
     pub swap_psbt: String,
     /// Fee Value
     pub fee_value: u64,
+    /// Effective fee rate (in sat/vB)
+    pub fee_rate: f32,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Display, Default, Validate)]
@@ -1,19 +1,20 @@
 use std::str::FromStr;
 
 use bp::{Chain, Txid};
+use chrono::Utc;
 use miniscript_crate::Descriptor;
 use rgbwallet::RgbInvoice;
 use seals::txout::ExplicitSeal;
 use wallet::hd::{DerivationAccount, DerivationSubpath, UnhardenedIndex};
 
-use crate::structs::{IssueMediaRequest, SecretString};
+use crate::structs::{IssueMediaRequest, MediaInfo, SecretString};
 
 /// Errors happening during checking of requests to RGB operations
 #[derive(Clone, PartialEq, Eq, Debug, Display, Error, From)]
 #[display(inner)]
 pub enum RGBParamsError {
     /// wrong or unspecified seal closed method. Only TapRet (tapret1st)
-    /// is supported
+    /// and OpRet (opret1st) are supported
     #[display(doc_comments)]
     NoClosedMethod,
 
@@ -31,13 +32,25 @@ pub enum RGBParamsError {
 
     /// Rgb Invoice cannot be decoded. {0}
     WrongInvoice(String),
+
+    /// Rgb Invoice expired at {0} (unix timestamp)
+    #[display(doc_comments)]
+    ExpiredInvoice(i64),
+
+    /// the media preview must be encoded in base64 (up to {0} bytes)
+    #[display(doc_comments)]
+    WrongMediaPreview(usize),
+
+    /// '{0}' is invalid media digest (sha256 in hexadecimal)
+    #[display(doc_comments)]
+    WrongMediaDigest(String),
 }
 
 #[derive(Debug, Display)]
 #[display(doc_comments)]
 pub struct RGBContext {
-    // Close Method supported
-    closed_method: String,
+    // Close Methods supported
+    closed_methods: Vec<String>,
 
     // Current Network
     current_network: String,
@@ -49,7 +62,7 @@ pub struct RGBContext {
 impl Default for RGBContext {
     fn default() -> Self {
         Self {
-            closed_method: "tapret1st".to_string(),
+            closed_methods: vec!["tapret1st".to_string(), "opret1st".to_string()],
             current_network: String::new(),
             min_media_types: 1,
         }
@@ -65,8 +78,12 @@ impl RGBContext {
     }
 }
 
-pub fn verify_tapret_seal(value: &str, context: &RGBContext) -> garde::Result {
-    if !value.contains(&context.closed_method) {
+pub fn verify_seal_commitment(value: &str, context: &RGBContext) -> garde::Result {
+    if !context
+        .closed_methods
+        .iter()
+        .any(|method| value.starts_with(method))
+    {
         return Err(garde::Error::new(
             RGBParamsError::NoClosedMethod.to_string(),
         ));
@@ -117,6 +134,53 @@ pub fn verify_media_request(
     Ok(())
 }
 
+pub fn verify_media_preview(value: &Option<MediaInfo>, _context: &RGBContext) -> garde::Result {
+    if let Some(preview) = value {
+        let max_size = u16::MAX as usize;
+        match base64::decode(&preview.source) {
+            Ok(data) if data.len() <= max_size => {}
+            _ => {
+                return Err(garde::Error::new(
+                    RGBParamsError::WrongMediaPreview(max_size).to_string(),
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn verify_media_digest(value: &Option<MediaInfo>, context: &RGBContext) -> garde::Result {
+    if let Some(media) = value {
+        verify_media_digests(&[media.clone()], context)?;
+    }
+    Ok(())
+}
+
+pub fn verify_media_digests(value: &[MediaInfo], _context: &RGBContext) -> garde::Result {
+    for media in value {
+        match hex::decode(&media.source) {
+            Ok(digest) if digest.len() == 32 => {}
+            _ => {
+                return Err(garde::Error::new(
+                    RGBParamsError::WrongMediaDigest(media.source.clone()).to_string(),
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn verify_invoice_expiry(value: &Option<i64>, _context: &RGBContext) -> garde::Result {
+    if let Some(expiry) = value {
+        if *expiry <= Utc::now().timestamp() {
+            return Err(garde::Error::new(
+                RGBParamsError::ExpiredInvoice(*expiry).to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub fn verify_rgb_invoice(value: &str, context: &RGBContext) -> garde::Result {
     let rgb_invoice =
         RgbInvoice::from_str(value).map_err(|err| RGBParamsError::WrongInvoice(err.to_string()));
@@ -125,7 +189,16 @@ pub fn verify_rgb_invoice(value: &str, context: &RGBContext) -> garde::Result {
         return Err(garde::Error::new(rgb_invoice.err().unwrap().to_string()));
     }
 
-    if let Some(chain) = rgb_invoice.unwrap().chain {
+    let rgb_invoice = rgb_invoice.unwrap();
+    if let Some(expiry) = rgb_invoice.expiry {
+        if expiry < Utc::now().timestamp() {
+            return Err(garde::Error::new(
+                RGBParamsError::ExpiredInvoice(expiry).to_string(),
+            ));
+        }
+    }
+
+    if let Some(chain) = rgb_invoice.chain {
         let network = &context.current_network;
         let current_chain =
             Chain::from_str(network).map_err(|op| RGBParamsError::WrongInvoice(op.to_string()));
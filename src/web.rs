@@ -6,10 +6,13 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::{future_to_promise, JsFuture};
 
 use crate::structs::{
-    AcceptRequest, FullRgbTransferRequest, ImportRequest, InvoiceRequest, IssueRequest,
-    MediaRequest, PsbtRequest, PublishPsbtRequest, ReIssueRequest, RgbBidRequest, RgbOfferRequest,
-    RgbRemoveTransferRequest, RgbSaveTransferRequest, RgbSwapRequest, RgbTransferRequest,
-    SecretString, SignPsbtRequest, WatcherRequest,
+    AcceptRequest, BurnRequest, ConsolidateRequest, CpfpRequest, FullRgbTransferRequest,
+    ImportRequest, ImportSchemaRequest, InflateRequest, InvoiceRequest, IssueCustomRequest,
+    IssueRequest, MediaRequest, PsbtRequest, PublishPsbtRequest, ReIssueRequest, RgbBidRequest,
+    RgbOfferCancelRequest, RgbOfferRequest, RgbRemoveTransferRequest, RgbSaveTransferRequest,
+    RgbSignedTransferRequest, RgbSwapRequest, RgbTransferHistoryRequest, RgbTransferRequest,
+    SecretString, SignPsbtRequest, SplitRequest, UnifiedUriRequest, ValidateConsignmentRequest,
+    WatcherRequest, WitnessInvoiceRequest,
 };
 
 pub fn set_panic_hook() {
@@ -390,6 +393,36 @@ pub mod rgb {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn preview_issue_contract(request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: IssueRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::preview_issue_contract(req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn issue_custom_contract(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: IssueCustomRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::issue_custom_contract(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[wasm_bindgen]
     pub fn reissue_contract(nostr_hex_sk: String, request: JsValue) -> Promise {
@@ -406,6 +439,81 @@ pub mod rgb {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn inflate_contract(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: InflateRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::inflate_contract(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn burn_asset(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: BurnRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::burn_asset(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn consolidate_allocations(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: ConsolidateRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::consolidate_allocations(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn split_allocation(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: SplitRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::split_allocation(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn bump_transfer_cpfp(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: CpfpRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::bump_transfer_cpfp(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
     #[wasm_bindgen]
     pub fn rgb_create_invoice(nostr_hex_sk: String, request: JsValue) -> Promise {
         set_panic_hook();
@@ -421,6 +529,35 @@ pub mod rgb {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn rgb_create_witness_invoice(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: WitnessInvoiceRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::create_witness_invoice(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn list_invoices(nostr_hex_sk: String) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            match crate::rgb::list_invoices(&nostr_hex_sk).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
     #[wasm_bindgen]
     pub fn create_psbt(nostr_hex_sk: String, request: JsValue) -> Promise {
         set_panic_hook();
@@ -511,6 +648,51 @@ pub mod rgb {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn export_transfer_psbt(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let pay_req: FullRgbTransferRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::export_transfer_psbt(&nostr_hex_sk, pay_req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn import_transfer_psbt(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: RgbSignedTransferRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::import_transfer_psbt(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn accept_transfers(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let accept_reqs: Vec<AcceptRequest> = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::accept_transfers(&nostr_hex_sk, accept_reqs).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
     #[wasm_bindgen]
     pub fn accept_transfer(nostr_hex_sk: String, request: JsValue) -> Promise {
         set_panic_hook();
@@ -526,6 +708,21 @@ pub mod rgb {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn validate_consignment(request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: ValidateConsignmentRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::validate_consignment(req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
     #[wasm_bindgen]
     pub fn verify_transfers(nostr_hex_sk: String) -> Promise {
         set_panic_hook();
@@ -652,6 +849,21 @@ pub mod rgb {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn import_schema(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: ImportSchemaRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::import_schema(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
     #[wasm_bindgen]
     pub fn create_watcher(nostr_hex_sk: String, request: JsValue) -> Promise {
         set_panic_hook();
@@ -779,6 +991,21 @@ pub mod rgb {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn transfer_history(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: RgbTransferHistoryRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::transfer_history(&nostr_hex_sk, req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
     #[wasm_bindgen]
     pub fn save_transfer(nostr_hex_sk: String, request: JsValue) -> Promise {
         set_panic_hook();
@@ -822,6 +1049,35 @@ pub mod rgb {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn create_unified_uri(request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let req: UnifiedUriRequest = serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::create_unified_uri(req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn decode_unified_uri(uri: String) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            match crate::rgb::decode_unified_uri(uri).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
     #[wasm_bindgen]
     pub fn create_offer(nostr_hex_sk: String, request: JsValue) -> Promise {
         set_panic_hook();
@@ -837,6 +1093,22 @@ pub mod rgb {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn cancel_offer(nostr_hex_sk: String, request: JsValue) -> Promise {
+        set_panic_hook();
+
+        future_to_promise(async move {
+            let cancel_req: RgbOfferCancelRequest =
+                serde_wasm_bindgen::from_value(request).unwrap();
+            match crate::rgb::cancel_seller_offer(&nostr_hex_sk, cancel_req).await {
+                Ok(result) => Ok(JsValue::from_string(
+                    serde_json::to_string(&result).unwrap(),
+                )),
+                Err(err) => Err(JsValue::from_string(err.to_string())),
+            }
+        })
+    }
+
     #[wasm_bindgen]
     pub fn create_bid(nostr_hex_sk: String, request: JsValue) -> Promise {
         set_panic_hook();
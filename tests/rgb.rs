@@ -1,12 +1,17 @@
 mod rgb {
 
     mod unit {
+        mod anchors;
         mod invoice;
         mod issue;
+        mod locks;
         mod psbt;
+        mod selection;
         mod stl;
         mod stock;
+        mod uri;
         pub mod utils;
+        mod validation;
     }
 
     mod integration {
@@ -14,8 +19,10 @@ mod rgb {
         // mod collectibles;
         mod accept;
         mod batch;
+        mod burn;
         mod cambria;
         mod collectibles;
+        mod consolidate;
         mod crdt;
         mod drain;
         mod dustless;
@@ -25,6 +32,7 @@ mod rgb {
         mod issue;
         mod proxy;
         mod rbf;
+        mod split;
         mod states;
         mod swaps;
         mod transfers;
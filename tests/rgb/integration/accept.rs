@@ -4,11 +4,12 @@ use bitmask_core::{
     bitcoin::{save_mnemonic, sign_and_publish_psbt_file},
     rgb::{
         create_watcher, list_transfers, remove_transfer, save_transfer, structs::ContractAmount,
-        verify_transfers, watcher_next_address,
+        transfer_history, verify_transfers, watcher_next_address,
     },
     structs::{
-        DecryptedWalletData, RgbRemoveTransferRequest, RgbSaveTransferRequest, SecretString,
-        SignPsbtRequest, TransferType, TxStatus, WatcherRequest,
+        DecryptedWalletData, RgbRemoveTransferRequest, RgbSaveTransferRequest,
+        RgbTransferHistoryRequest, SecretString, SignPsbtRequest, TransferType, TxStatus,
+        WatcherRequest,
     },
 };
 
@@ -155,6 +156,32 @@ pub async fn allow_save_read_remove_transfers() -> Result<()> {
         {
             matches!(consig_status.status, TxStatus::Block(_));
         }
+
+        // Accepted transfers are kept in the history
+        verify_transfers(&sk).await?;
+
+        let request = RgbTransferHistoryRequest {
+            contract_id: contract_id.clone(),
+            offset: 0,
+            limit: 10,
+        };
+        let history_resp = transfer_history(&sk, request).await?;
+        let item = history_resp
+            .transfers
+            .into_iter()
+            .find(|x| x.consig_id == transfer.consig_id);
+        assert!(item.is_some());
+
+        let item = item.unwrap();
+        assert!(item.amount > 0);
+        if let TxStatus::Block(height) = item.status {
+            assert_eq!(Some(height), item.block_height);
+            assert!(item.confirmations > 0);
+        }
+        if sk == issuer_sk {
+            assert_eq!(item.ty, TransferType::Sended);
+            assert!(item.fee.is_some());
+        }
     }
 
     // 8. Remove Consig (Both Sides)
@@ -7,14 +7,14 @@ use bitcoin::Txid;
 use bitmask_core::{
     bitcoin::{get_blockchain, new_mnemonic, sign_and_publish_psbt_file},
     rgb::{
-        accept_transfer, consignment::NewTransferOptions, create_watcher, full_transfer_asset,
-        get_contract, internal_replace_transfer, issue_contract, list_contracts,
-        structs::ContractAmount, watcher_next_address,
+        accept_transfer, accept_transfers, consignment::NewTransferOptions, create_watcher,
+        full_transfer_asset, get_contract, internal_replace_transfer, issue_contract,
+        list_contracts, structs::ContractAmount, watcher_next_address,
     },
     structs::{
-        AcceptRequest, FullRgbTransferRequest, IssueRequest, PsbtFeeRequest, PublishedPsbtResponse,
-        RgbReplaceResponse, RgbTransferRequest, RgbTransferResponse, SecretString, SignPsbtRequest,
-        WatcherRequest,
+        AcceptRequest, CoinSelectionStrategy, FullRgbTransferRequest, IssueRequest, PsbtFeeRequest,
+        PublishedPsbtResponse, RgbReplaceResponse, RgbTransferRequest, RgbTransferResponse,
+        SealCloseMethod, SecretString, SignPsbtRequest, WatcherRequest,
     },
 };
 use rgbwallet::RgbInvoice;
@@ -59,6 +59,11 @@ pub async fn create_strict_transfer() -> Result<()> {
         seal: issue_seal.to_owned(),
         iface: "RGB21".to_string(),
         meta,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let _uda_resp = issue_contract(issuer_sk, issue_uda_req).await?;
@@ -176,6 +181,11 @@ pub async fn create_transfer_rbf() -> Result<()> {
         seal: issue_seal.to_owned(),
         iface: "RGB21".to_string(),
         meta,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let _uda_resp = issue_contract(issuer_sk, issue_uda_req).await?;
@@ -329,6 +339,11 @@ pub async fn create_batch_transfer() -> Result<()> {
         seal: issue_seal.to_owned(),
         iface: "RGB21".to_string(),
         meta,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let _uda_resp = issue_contract(issuer_sk, issue_uda_req).await?;
@@ -438,6 +453,7 @@ pub async fn create_batch_transfer() -> Result<()> {
         psbt: psbt_resp_2.psbt,
         rgb_invoice: other_resp.invoice.clone(),
         terminal: psbt_resp_2.terminal,
+        other_invoices: vec![],
     };
 
     let rgb_invoice = RgbInvoice::from_str(&owner_resp.invoice)?;
@@ -556,6 +572,11 @@ pub async fn create_transfer_skipping_invalid_states() -> Result<()> {
         seal: issue_seal.to_owned(),
         iface: "RGB21".to_string(),
         meta,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let _uda_resp = issue_contract(issuer_sk, issue_uda_req).await?;
@@ -688,6 +709,9 @@ pub async fn create_transfer_skipping_invalid_states() -> Result<()> {
         change_terminal: "/20/1".to_string(),
         fee: PsbtFeeRequest::Value(546),
         bitcoin_changes: vec![],
+        other_invoices: vec![],
+        coin_selection: CoinSelectionStrategy::default(),
+        close_method: SealCloseMethod::default(),
     };
 
     let full_transfer_resp = full_transfer_asset(issuer_sk, full_transfer_req).await;
@@ -731,3 +755,103 @@ pub async fn create_transfer_skipping_invalid_states() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+pub async fn accept_many_consignments() -> Result<()> {
+    // 1. Initial Setup
+    let issuer_keys = new_mnemonic(&SecretString("".to_string())).await?;
+    let owner_keys = new_mnemonic(&SecretString("".to_string())).await?;
+
+    let issuer_sk = &issuer_keys.private.nostr_prv;
+    let fungibles_resp = issuer_issue_contract_v2(
+        1,
+        "RGB20",
+        ContractAmount::new(5, 2).to_value(),
+        false,
+        true,
+        None,
+        Some("0.10000000".to_string()),
+        Some(UtxoFilter::with_amount_equal_than(10_000_000)),
+        Some(issuer_keys.clone()),
+    )
+    .await?;
+    let issuer_resp = &fungibles_resp[0];
+
+    // 2. Create PSBT
+    let contract_id = &issuer_resp.contract_id;
+    let issuer_contract = get_contract(issuer_sk, contract_id).await?;
+    let new_alloc = issuer_contract
+        .allocations
+        .into_iter()
+        .find(|x| x.is_mine)
+        .unwrap();
+    let psbt_resp = create_new_psbt_v2(
+        &issuer_resp.iface,
+        vec![new_alloc],
+        issuer_keys.clone(),
+        vec![],
+        vec![],
+        None,
+    )
+    .await?;
+
+    // 3. Generate Invoice
+    let watcher_name = "default";
+    let owner_sk = owner_keys.private.nostr_prv.to_string();
+    let create_watch_req = WatcherRequest {
+        name: watcher_name.to_string(),
+        xpub: owner_keys.public.watcher_xpub.clone(),
+        force: false,
+    };
+    create_watcher(&owner_sk, create_watch_req).await?;
+    let owner_fungible_address = watcher_next_address(&owner_sk, watcher_name, "RGB20").await?;
+    send_some_coins(&owner_fungible_address.address, "1").await;
+
+    let owner_resp = &create_new_invoice(
+        &issuer_resp.contract_id,
+        &issuer_resp.iface,
+        1.0,
+        owner_keys.clone(),
+        None,
+        Some(issuer_resp.clone().contract.strict),
+    )
+    .await?;
+
+    // 4. Generate Transfer
+    let transfer_resp =
+        &create_new_transfer(issuer_keys.clone(), owner_resp.clone(), psbt_resp.clone()).await?;
+    let psbt_req = SignPsbtRequest {
+        psbt: transfer_resp.psbt.clone(),
+        descriptors: vec![SecretString(
+            issuer_keys.private.rgb_assets_descriptor_xprv.clone(),
+        )],
+    };
+    let psbt_resp = sign_and_publish_psbt_file(psbt_req).await;
+    assert!(psbt_resp.is_ok());
+
+    // 5. Accept Transfers (the invalid consignment does not abort the batch)
+    generate_new_block().await;
+    let requests = vec![
+        AcceptRequest {
+            consignment: "invalid".to_string(),
+            force: false,
+        },
+        AcceptRequest {
+            consignment: transfer_resp.consig.clone(),
+            force: false,
+        },
+    ];
+    let resp = accept_transfers(&owner_sk, requests).await?;
+
+    assert_eq!(resp.transfers.len(), 2);
+    assert!(resp.transfers[0].accept.is_none());
+    assert!(resp.transfers[0].error.is_some());
+    assert_eq!(resp.transfers[1].index, 1);
+    assert!(resp.transfers[1].error.is_none());
+    assert!(resp.transfers[1].accept.as_ref().is_some_and(|x| x.valid));
+
+    // 6. Check Facts
+    let owner_contracts = list_contracts(&owner_sk, false).await?;
+    assert_eq!(owner_contracts.contracts.len(), 1);
+    Ok(())
+}
@@ -59,6 +59,11 @@ pub async fn measure_batch_operation() -> Result<()> {
         seal: issue_seal.to_owned(),
         iface: "RGB21".to_string(),
         meta,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let _uda_resp = issue_contract(&issuer_sk, issue_uda_req).await?;
@@ -177,6 +182,11 @@ pub async fn create_transfer_rbf() -> Result<()> {
         seal: issue_seal.to_owned(),
         iface: "RGB21".to_string(),
         meta,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let _uda_resp = issue_contract(&issuer_sk, issue_uda_req).await?;
@@ -330,6 +340,11 @@ pub async fn create_batch_transfer() -> Result<()> {
         seal: issue_seal.to_owned(),
         iface: "RGB21".to_string(),
         meta,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let _uda_resp = issue_contract(&issuer_sk, issue_uda_req).await?;
@@ -439,6 +454,7 @@ pub async fn create_batch_transfer() -> Result<()> {
         psbt: psbt_resp_2.psbt,
         rgb_invoice: other_resp.invoice.clone(),
         terminal: psbt_resp_2.terminal,
+        other_invoices: vec![],
     };
 
     let rgb_invoice = RgbInvoice::from_str(&owner_resp.invoice)?;
@@ -0,0 +1,152 @@
+#![cfg(not(target_arch = "wasm32"))]
+use anyhow::Result;
+use bitmask_core::{
+    bitcoin::{new_mnemonic, sign_and_publish_psbt_file},
+    rgb::{
+        burn_asset, create_watcher, get_contract, issue_contract, verify_transfers,
+        watcher_next_address, watcher_unspent_utxos, TransferError,
+    },
+    structs::{
+        BurnRequest, IssueInflationRequest, IssueRequest, PsbtFeeRequest, SealCloseMethod,
+        SecretString, SignPsbtRequest, WatcherRequest,
+    },
+};
+
+use crate::rgb::integration::utils::{generate_new_block, send_some_coins, setup_regtest};
+
+#[tokio::test]
+async fn allow_issuer_burn_fungible_asset() -> Result<()> {
+    // 1. Initial Setup
+    setup_regtest(false, None).await;
+    let issuer_keys = new_mnemonic(&SecretString("".to_string())).await?;
+    let issuer_sk = &issuer_keys.private.nostr_prv;
+    let watcher_name = "default";
+
+    let create_watch_req = WatcherRequest {
+        name: watcher_name.to_string(),
+        xpub: issuer_keys.public.watcher_xpub.clone(),
+        force: true,
+    };
+    create_watcher(issuer_sk, create_watch_req).await?;
+
+    for _ in 0..2 {
+        let next_address = watcher_next_address(issuer_sk, watcher_name, "RGB20").await?;
+        send_some_coins(&next_address.address, "0.1").await;
+    }
+
+    let utxos = watcher_unspent_utxos(issuer_sk, watcher_name, "RGB20")
+        .await?
+        .utxos;
+    assert!(utxos.len() >= 2);
+
+    // 2. Issue Contract (with inflation right, required by burn transition)
+    let issue_req = IssueRequest {
+        ticker: "DIBA".to_string(),
+        name: "DIBA".to_string(),
+        description: "DIBA".to_string(),
+        precision: 0,
+        supply: 10,
+        seal: format!("tapret1st:{}", utxos[0].outpoint),
+        iface: "RGB20".to_string(),
+        meta: None,
+        inflation: Some(IssueInflationRequest {
+            seal: format!("tapret1st:{}", utxos[1].outpoint),
+            amount: 5,
+        }),
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
+    };
+    let issue_resp = issue_contract(issuer_sk, issue_req).await?;
+
+    // 3. Burn Asset
+    let burn_req = BurnRequest {
+        contract_id: issue_resp.contract_id.clone(),
+        amount: 4,
+        descriptor: SecretString(issuer_keys.public.rgb_assets_descriptor_xpub.clone()),
+        change_terminal: "/20/1".to_string(),
+        fee: PsbtFeeRequest::Value(1000),
+        close_method: SealCloseMethod::default(),
+    };
+    let burn_resp = burn_asset(issuer_sk, burn_req).await?;
+    assert_eq!(burn_resp.burned_supply, 4);
+    assert_eq!(burn_resp.circulating_supply, 6);
+
+    // 4. Sign and Broadcast
+    let psbt_req = SignPsbtRequest {
+        psbt: burn_resp.psbt,
+        descriptors: vec![SecretString(
+            issuer_keys.private.rgb_assets_descriptor_xprv.clone(),
+        )],
+    };
+    let psbt_resp = sign_and_publish_psbt_file(psbt_req).await;
+    assert!(psbt_resp.is_ok());
+
+    // 5. Check Contract State
+    generate_new_block().await;
+    verify_transfers(issuer_sk).await?;
+
+    let contract = get_contract(issuer_sk, &issue_resp.contract_id).await?;
+    assert_eq!(contract.supply, 10);
+    assert_eq!(contract.burned_supply, 4);
+    assert_eq!(contract.circulating_supply, 6);
+    assert_eq!(contract.balance, 6);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reject_burn_non_inflatable_asset() -> Result<()> {
+    // 1. Initial Setup
+    setup_regtest(false, None).await;
+    let issuer_keys = new_mnemonic(&SecretString("".to_string())).await?;
+    let issuer_sk = &issuer_keys.private.nostr_prv;
+    let watcher_name = "default";
+
+    let create_watch_req = WatcherRequest {
+        name: watcher_name.to_string(),
+        xpub: issuer_keys.public.watcher_xpub.clone(),
+        force: true,
+    };
+    create_watcher(issuer_sk, create_watch_req).await?;
+
+    let next_address = watcher_next_address(issuer_sk, watcher_name, "RGB20").await?;
+    send_some_coins(&next_address.address, "0.1").await;
+
+    let utxos = watcher_unspent_utxos(issuer_sk, watcher_name, "RGB20")
+        .await?
+        .utxos;
+
+    // 2. Issue Contract (without inflation right)
+    let issue_req = IssueRequest {
+        ticker: "DIBA".to_string(),
+        name: "DIBA".to_string(),
+        description: "DIBA".to_string(),
+        precision: 0,
+        supply: 10,
+        seal: format!("tapret1st:{}", utxos[0].outpoint),
+        iface: "RGB20".to_string(),
+        meta: None,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
+    };
+    let issue_resp = issue_contract(issuer_sk, issue_req).await?;
+
+    // 3. Burn Asset
+    let burn_req = BurnRequest {
+        contract_id: issue_resp.contract_id.clone(),
+        amount: 4,
+        descriptor: SecretString(issuer_keys.public.rgb_assets_descriptor_xpub.clone()),
+        change_terminal: "/20/1".to_string(),
+        fee: PsbtFeeRequest::Value(1000),
+        close_method: SealCloseMethod::default(),
+    };
+    let burn_resp = burn_asset(issuer_sk, burn_req).await;
+    assert!(matches!(burn_resp, Err(TransferError::NoBurnSupport(_))));
+
+    Ok(())
+}
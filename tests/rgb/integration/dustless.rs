@@ -13,8 +13,8 @@ use bitmask_core::{
         accept_transfer, create_watcher, full_transfer_asset, get_contract, structs::ContractAmount,
     },
     structs::{
-        AcceptRequest, FullRgbTransferRequest, PsbtFeeRequest, PsbtInputRequest, SecretString,
-        SignPsbtRequest, WatcherRequest,
+        AcceptRequest, CoinSelectionStrategy, FullRgbTransferRequest, PsbtFeeRequest,
+        PsbtInputRequest, SealCloseMethod, SecretString, SignPsbtRequest, WatcherRequest,
     },
 };
 
@@ -211,6 +211,9 @@ async fn create_dustless_transfer_with_fee_rate() -> anyhow::Result<()> {
         change_terminal: "/20/1".to_string(),
         fee: PsbtFeeRequest::FeeRate(1.1),
         bitcoin_changes: vec![],
+        other_invoices: vec![],
+        coin_selection: CoinSelectionStrategy::default(),
+        close_method: SealCloseMethod::default(),
     };
 
     let transfer_resp = full_transfer_asset(&sk, request).await?;
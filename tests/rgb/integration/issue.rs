@@ -1,5 +1,13 @@
 #![cfg(not(target_arch = "wasm32"))]
-use crate::rgb::integration::utils::{get_uda_data, issuer_issue_contract};
+use bitmask_core::{
+    bitcoin::save_mnemonic,
+    rgb::{list_contracts, preview_issue_contract},
+    structs::{ContractsResponse, IssueRequest, SecretString},
+};
+
+use crate::rgb::integration::utils::{
+    get_uda_data, issuer_issue_contract, setup_regtest, ISSUER_MNEMONIC,
+};
 
 #[tokio::test]
 async fn allow_issuer_issue_fungible_contract() -> anyhow::Result<()> {
@@ -16,6 +24,17 @@ async fn allow_issuer_issue_uda_contract() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn allow_issuer_issue_collectible_fungible_contract() -> anyhow::Result<()> {
+    let issuer_resp = issuer_issue_contract("RGB25", 5, false, true, None).await;
+    assert!(issuer_resp.is_ok());
+
+    let issuer_resp = issuer_resp?;
+    assert_eq!(issuer_resp.iface, "RGB25");
+    assert_eq!(issuer_resp.supply, 5);
+    Ok(())
+}
+
 // TODO: Review after support multi-token transfer
 // async fn _allow_issuer_issue_collectible_contract() -> anyhow::Result<()> {
 //     let collectible = Some(get_collectible_data());
@@ -23,3 +42,42 @@ async fn allow_issuer_issue_uda_contract() -> anyhow::Result<()> {
 //     assert!(issuer_resp.is_ok());
 //     Ok(())
 // }
+
+#[tokio::test]
+async fn allow_issuer_preview_contract_without_store() -> anyhow::Result<()> {
+    setup_regtest(false, None).await;
+    let issuer_keys = save_mnemonic(
+        &SecretString(ISSUER_MNEMONIC.to_string()),
+        &SecretString("".to_string()),
+    )
+    .await?;
+    let sk = &issuer_keys.private.nostr_prv;
+
+    let request = IssueRequest {
+        ticker: "PREV".to_string(),
+        name: "PREVIEW".to_string(),
+        description: "PREVIEW".to_string(),
+        precision: 2,
+        supply: 5,
+        seal: "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:0"
+            .to_string(),
+        iface: "RGB20".to_string(),
+        meta: None,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
+    };
+
+    let preview_resp = preview_issue_contract(request).await?;
+    assert_eq!(preview_resp.ticker, "PREV");
+    assert!(!preview_resp.contract.armored.is_empty());
+    assert!(!preview_resp.contract.strict.is_empty());
+
+    let ContractsResponse { contracts } = list_contracts(sk, false).await?;
+    assert!(!contracts
+        .into_iter()
+        .any(|x| x.contract_id == preview_resp.contract_id));
+    Ok(())
+}
@@ -0,0 +1,96 @@
+#![cfg(not(target_arch = "wasm32"))]
+use anyhow::Result;
+use bitmask_core::{
+    bitcoin::{new_mnemonic, sign_and_publish_psbt_file},
+    rgb::{
+        create_watcher, get_contract, issue_contract, split_allocation, verify_transfers,
+        watcher_next_address, watcher_unspent_utxos,
+    },
+    structs::{
+        IssueRequest, PsbtFeeRequest, SealCloseMethod, SecretString, SignPsbtRequest, SplitRequest,
+        WatcherRequest,
+    },
+};
+
+use crate::rgb::integration::utils::{generate_new_block, send_some_coins, setup_regtest};
+
+#[tokio::test]
+async fn allow_split_fungible_allocation() -> Result<()> {
+    // 1. Initial Setup
+    setup_regtest(false, None).await;
+    let issuer_keys = new_mnemonic(&SecretString("".to_string())).await?;
+    let issuer_sk = &issuer_keys.private.nostr_prv;
+    let watcher_name = "default";
+
+    let create_watch_req = WatcherRequest {
+        name: watcher_name.to_string(),
+        xpub: issuer_keys.public.watcher_xpub.clone(),
+        force: true,
+    };
+    create_watcher(issuer_sk, create_watch_req).await?;
+
+    let next_address = watcher_next_address(issuer_sk, watcher_name, "RGB20").await?;
+    send_some_coins(&next_address.address, "0.1").await;
+
+    let utxos = watcher_unspent_utxos(issuer_sk, watcher_name, "RGB20")
+        .await?
+        .utxos;
+    assert!(!utxos.is_empty());
+
+    // 2. Issue Contract (on a single UTXO)
+    let issue_req = IssueRequest {
+        ticker: "DIBA".to_string(),
+        name: "DIBA".to_string(),
+        description: "DIBA".to_string(),
+        precision: 0,
+        supply: 10,
+        seal: format!("tapret1st:{}", utxos[0].outpoint),
+        iface: "RGB20".to_string(),
+        meta: None,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
+    };
+    let issue_resp = issue_contract(issuer_sk, issue_req).await?;
+
+    // 3. Split Allocation
+    let split_req = SplitRequest {
+        contract_id: issue_resp.contract_id.clone(),
+        utxo: utxos[0].outpoint.clone(),
+        amounts: vec![3, 3],
+        descriptor: SecretString(issuer_keys.public.rgb_assets_descriptor_xpub.clone()),
+        change_terminal: "/20/1".to_string(),
+        fee: PsbtFeeRequest::Value(1000),
+        close_method: SealCloseMethod::default(),
+    };
+    let split_resp = split_allocation(issuer_sk, split_req).await?;
+    assert_eq!(split_resp.allocations.len(), 2);
+    assert_eq!(split_resp.consigs.len(), 1);
+
+    // 4. Sign and Broadcast
+    let psbt_req = SignPsbtRequest {
+        psbt: split_resp.psbt,
+        descriptors: vec![SecretString(
+            issuer_keys.private.rgb_assets_descriptor_xprv.clone(),
+        )],
+    };
+    let psbt_resp = sign_and_publish_psbt_file(psbt_req).await;
+    assert!(psbt_resp.is_ok());
+
+    // 5. Check Contract State
+    generate_new_block().await;
+    verify_transfers(issuer_sk).await?;
+
+    let contract = get_contract(issuer_sk, &issue_resp.contract_id).await?;
+    let allocations: Vec<_> = contract
+        .allocations
+        .into_iter()
+        .filter(|x| x.is_mine && !x.is_spent)
+        .collect();
+    assert_eq!(allocations.len(), 3);
+    assert_eq!(contract.balance, 10);
+
+    Ok(())
+}
@@ -13,10 +13,10 @@ use bitmask_core::{
         update_seller_offer, verify_transfers,
     },
     structs::{
-        AcceptRequest, AssetType, ImportRequest, IssueResponse, PsbtFeeRequest, PublishPsbtRequest,
-        RgbBidRequest, RgbBidResponse, RgbOfferRequest, RgbOfferResponse, RgbOfferUpdateRequest,
-        RgbSwapRequest, RgbSwapResponse, SecretString, SignPsbtRequest, SignedPsbtResponse,
-        WatcherRequest,
+        AcceptRequest, AssetType, CoinSelectionStrategy, ImportRequest, IssueResponse,
+        PsbtFeeRequest, PublishPsbtRequest, RgbBidRequest, RgbBidResponse, RgbOfferRequest,
+        RgbOfferResponse, RgbOfferUpdateRequest, RgbSwapRequest, RgbSwapResponse, SealCloseMethod,
+        SecretString, SignPsbtRequest, SignedPsbtResponse, WatcherRequest,
     },
 };
 
@@ -148,6 +148,8 @@ async fn create_scriptless_swap() -> anyhow::Result<()> {
         bitcoin_changes: vec![],
         expire_at: Some(expire_at),
         presig: false,
+        coin_selection: CoinSelectionStrategy::default(),
+        close_method: SealCloseMethod::default(),
     };
 
     let seller_swap_resp = create_seller_offer(&seller_sk, seller_swap_req).await;
@@ -164,6 +166,7 @@ async fn create_scriptless_swap() -> anyhow::Result<()> {
         descriptor: SecretString(buyer_btc_desc),
         change_terminal: "/1/0".to_string(),
         fee: PsbtFeeRequest::Value(1000),
+        close_method: SealCloseMethod::default(),
     };
 
     let buyer_swap_resp = create_buyer_bid(&buyer_sk, buyer_swap_req).await;
@@ -376,6 +379,8 @@ async fn create_scriptless_swap_for_uda() -> anyhow::Result<()> {
         bitcoin_changes: vec![],
         expire_at: Some(expire_at),
         presig: false,
+        coin_selection: CoinSelectionStrategy::default(),
+        close_method: SealCloseMethod::default(),
     };
 
     let seller_swap_resp = create_seller_offer(&seller_sk, seller_swap_req).await;
@@ -390,6 +395,7 @@ async fn create_scriptless_swap_for_uda() -> anyhow::Result<()> {
         descriptor: SecretString(buyer_btc_desc),
         change_terminal: "/1/0".to_string(),
         fee: PsbtFeeRequest::Value(1000),
+        close_method: SealCloseMethod::default(),
     };
 
     let buyer_swap_resp = create_buyer_bid(&buyer_sk, buyer_swap_req).await;
@@ -603,6 +609,8 @@ async fn create_presig_scriptless_swap() -> anyhow::Result<()> {
         bitcoin_changes: vec![],
         expire_at: Some(expire_at),
         presig: true,
+        coin_selection: CoinSelectionStrategy::default(),
+        close_method: SealCloseMethod::default(),
     };
 
     let seller_swap_resp = create_seller_offer(&seller_sk, seller_swap_req).await;
@@ -644,6 +652,7 @@ async fn create_presig_scriptless_swap() -> anyhow::Result<()> {
         descriptor: SecretString(buyer_btc_desc),
         change_terminal: "/1/0".to_string(),
         fee: PsbtFeeRequest::Value(1000),
+        close_method: SealCloseMethod::default(),
     };
 
     let buyer_swap_resp = create_buyer_bid(&buyer_sk, buyer_swap_req).await;
@@ -7,12 +7,12 @@ use bitmask_core::{
     rgb::{
         accept_transfer, create_invoice, create_watcher, full_transfer_asset, get_contract, import,
         save_transfer, structs::ContractAmount, verify_transfers, watcher_next_address,
-        watcher_next_utxo, watcher_unspent_utxos,
+        watcher_next_utxo, watcher_unspent_utxos, InvoiceError,
     },
     structs::{
-        AcceptRequest, AllocationDetail, AssetType, FullRgbTransferRequest, ImportRequest,
-        InvoiceRequest, IssueResponse, PsbtFeeRequest, RgbSaveTransferRequest, RgbTransferResponse,
-        SecretString, SignPsbtRequest, WatcherRequest,
+        AcceptRequest, AllocationDetail, AssetType, CoinSelectionStrategy, FullRgbTransferRequest,
+        ImportRequest, InvoiceRequest, IssueResponse, PsbtFeeRequest, RgbSaveTransferRequest,
+        RgbTransferResponse, SealCloseMethod, SecretString, SignPsbtRequest, WatcherRequest,
     },
 };
 
@@ -770,6 +770,8 @@ async fn create_transfers_between_2_owners() -> anyhow::Result<()> {
         amount: "1.00".to_string(),
         seal: another_owner_seal,
         params: HashMap::default(),
+        expiry: None,
+        transports: None,
     };
     let import_req = ImportRequest {
         import: AssetType::RGB20,
@@ -782,6 +784,16 @@ async fn create_transfers_between_2_owners() -> anyhow::Result<()> {
     let another_invoice_1 = &create_invoice(&another_owner_sk, invoice_req.clone()).await?;
     let another_invoice_2 = &create_invoice(&another_owner_sk, invoice_req.clone()).await?;
 
+    let expired_req = InvoiceRequest {
+        expiry: Some(chrono::Utc::now().timestamp() - 3600),
+        ..invoice_req.clone()
+    };
+    let expired_invoice = create_invoice(&another_owner_sk, expired_req).await;
+    assert!(matches!(
+        expired_invoice,
+        Err(InvoiceError::Validation(errors)) if errors.contains_key("expiry")
+    ));
+
     // 8. Create Transfer and Accept (Issuer Side)
     let issuer_xpriv = issuer_keys.private.rgb_assets_descriptor_xprv.clone();
     let issuer_utxo = issuer_contract
@@ -1669,6 +1681,9 @@ async fn allow_fungible_full_transfer_op() -> anyhow::Result<()> {
         change_terminal: "/20/1".to_string(),
         fee: PsbtFeeRequest::Value(1000),
         bitcoin_changes: vec![],
+        other_invoices: vec![],
+        coin_selection: CoinSelectionStrategy::default(),
+        close_method: SealCloseMethod::default(),
     };
 
     let issue_sk = issuer_keys.private.nostr_prv.to_string();
@@ -1740,6 +1755,9 @@ async fn allow_uda_full_transfer_op() -> anyhow::Result<()> {
         change_terminal: "/21/1".to_string(),
         fee: PsbtFeeRequest::Value(546),
         bitcoin_changes: vec![],
+        other_invoices: vec![],
+        coin_selection: CoinSelectionStrategy::default(),
+        close_method: SealCloseMethod::default(),
     };
 
     let issue_sk = issuer_keys.private.nostr_prv.to_string();
@@ -1895,6 +1913,9 @@ async fn allow_consecutive_full_transfer_bidirectional() -> anyhow::Result<()> {
             change_terminal: "/20/1".to_string(),
             fee: PsbtFeeRequest::Value(546),
             bitcoin_changes: vec![],
+            other_invoices: vec![],
+            coin_selection: CoinSelectionStrategy::default(),
+            close_method: SealCloseMethod::default(),
         };
 
         let full_transfer_resp = full_transfer_asset(wallet_a_sk, self_pay_req).await;
@@ -1959,6 +1980,9 @@ async fn allow_consecutive_full_transfer_bidirectional() -> anyhow::Result<()> {
                 change_terminal: "/20/1".to_string(),
                 fee: PsbtFeeRequest::Value(546),
                 bitcoin_changes: vec![],
+                other_invoices: vec![],
+                coin_selection: CoinSelectionStrategy::default(),
+                close_method: SealCloseMethod::default(),
             };
 
             let full_transfer_resp = full_transfer_asset(wallet_b_sk, self_pay_req).await;
@@ -2075,6 +2099,9 @@ async fn allow_save_transfer_and_verify() -> anyhow::Result<()> {
         change_terminal: "/20/1".to_string(),
         fee: PsbtFeeRequest::Value(1000),
         bitcoin_changes: vec![],
+        other_invoices: vec![],
+        coin_selection: CoinSelectionStrategy::default(),
+        close_method: SealCloseMethod::default(),
     };
 
     let resp = full_transfer_asset(&issuer_sk, self_pay_req).await?;
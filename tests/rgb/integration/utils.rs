@@ -12,10 +12,11 @@ use bitmask_core::{
         watcher_unspent_utxos,
     },
     structs::{
-        AllocationDetail, AssetType, ContractResponse, DecryptedWalletData, ImportRequest,
-        InvoiceRequest, InvoiceResponse, IssueMediaRequest, IssueRequest, IssueResponse, MediaInfo,
-        MediaItemRequest, MediaRequest, PsbtFeeRequest, PsbtInputRequest, PsbtRequest,
-        PsbtResponse, RgbTransferRequest, RgbTransferResponse, SecretString, WatcherRequest,
+        AllocationDetail, AssetType, CoinSelectionStrategy, ContractResponse, DecryptedWalletData,
+        ImportRequest, InvoiceRequest, InvoiceResponse, IssueMediaRequest, IssueRequest,
+        IssueResponse, MediaInfo, MediaItemRequest, MediaRequest, PsbtFeeRequest, PsbtInputRequest,
+        PsbtRequest, PsbtResponse, RgbTransferRequest, RgbTransferResponse, SecretString,
+        WatcherRequest,
     },
 };
 use std::{collections::HashMap, env, process::Stdio};
@@ -244,6 +245,11 @@ pub async fn issuer_issue_contract(
         seal: issue_seal.to_owned(),
         iface: iface.to_string(),
         meta,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let resp = issue_contract(sk, request).await?;
@@ -349,6 +355,8 @@ pub async fn create_new_invoice(
         amount: amount.to_string(),
         seal,
         params,
+        expiry: None,
+        transports: None,
     };
 
     let resp = create_invoice(&sk, invoice_req).await?;
@@ -509,6 +517,11 @@ pub async fn issuer_issue_contract_v2(
             seal: issue_seal.to_owned(),
             iface: iface.to_string(),
             meta: meta.clone(),
+            inflation: None,
+            allocations: vec![],
+            terms: None,
+            attachment: None,
+            collection: vec![],
         };
         let contract = issue_contract(sk, request).await?;
         contracts.push(contract);
@@ -560,6 +573,8 @@ pub async fn create_new_invoice_v2(
         amount: amount.to_string(),
         seal,
         params,
+        expiry: None,
+        transports: None,
     };
 
     let resp = create_invoice(&sk, invoice_req).await?;
@@ -612,6 +627,7 @@ pub async fn create_new_psbt_v2(
         bitcoin_changes,
         fee: default_fee,
         rbf: true,
+        coin_selection: CoinSelectionStrategy::default(),
     };
 
     let resp = create_psbt(&sk, req).await?;
@@ -628,6 +644,7 @@ pub async fn create_new_transfer(
         psbt: psbt_resp.psbt,
         rgb_invoice: invoice_resp.invoice,
         terminal: psbt_resp.terminal,
+        other_invoices: vec![],
     };
 
     let sk = owner_keys.private.nostr_prv.clone();
@@ -0,0 +1,73 @@
+#![cfg(not(target_arch = "wasm32"))]
+use std::{collections::BTreeMap, str::FromStr};
+
+use bitmask_core::{
+    rgb::structs::{RgbAnchors, RgbTransferV1},
+    util::init_logging,
+};
+use bp::Txid;
+
+fn fake_transfer(consig_id: &str, txid: &str) -> RgbTransferV1 {
+    RgbTransferV1::new(
+        consig_id.to_string(),
+        String::new(),
+        "RGB20".to_string(),
+        Txid::from_str(txid).expect("invalid txid"),
+        vec![],
+    )
+}
+
+#[tokio::test]
+async fn allow_detect_reorged_anchors() -> anyhow::Result<()> {
+    init_logging("rgb_anchors=warn");
+
+    let txid_1 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e";
+    let txid_2 = "5f0b4f3d5b0d8bbf6a5f2fcd7a1a9a4c3d2b1a0f9e8d7c6b5a4938271605f4e3";
+    let txid_3 = "0c7f4a1e2d3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5";
+    let hash_1 = "000000000000000000030d3e1d2b4c5a69788796a5b4c3d2e1f0a9b8c7d6e5f4";
+    let hash_2 = "00000000000000000002f1e2d3c4b5a69788796a5b4c3d2e1f0a9b8c7d6e5f4a";
+
+    let mut rgb_anchors = RgbAnchors::default();
+    rgb_anchors.record("contract_1", fake_transfer("consig_1", txid_1), 100, hash_1);
+    rgb_anchors.record("contract_1", fake_transfer("consig_2", txid_2), 100, hash_1);
+    rgb_anchors.record("contract_1", fake_transfer("consig_3", txid_3), 100, hash_1);
+
+    // consig_1 is still in the same block, consig_2 was mined in another block
+    // and the status of consig_3 is unknown (explorer error)
+    let block_hashes = BTreeMap::from([
+        (txid_1.to_string(), Some(hash_1.to_string())),
+        (txid_2.to_string(), Some(hash_2.to_string())),
+    ]);
+
+    let reorged = rgb_anchors.reorged(&block_hashes);
+    assert_eq!(1, reorged.len());
+    assert_eq!("consig_2", reorged[0].transfer.consig_id);
+    assert!(rgb_anchors.anchors.contains_key("consig_1"));
+    assert!(!rgb_anchors.anchors.contains_key("consig_2"));
+    assert!(rgb_anchors.anchors.contains_key("consig_3"));
+
+    // Back to the mempool
+    let block_hashes = BTreeMap::from([(txid_3.to_string(), None)]);
+    let reorged = rgb_anchors.reorged(&block_hashes);
+    assert_eq!(1, reorged.len());
+    assert_eq!("consig_3", reorged[0].transfer.consig_id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_prune_buried_anchors() -> anyhow::Result<()> {
+    init_logging("rgb_anchors=warn");
+
+    let txid_1 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e";
+    let txid_2 = "5f0b4f3d5b0d8bbf6a5f2fcd7a1a9a4c3d2b1a0f9e8d7c6b5a4938271605f4e3";
+    let hash = "000000000000000000030d3e1d2b4c5a69788796a5b4c3d2e1f0a9b8c7d6e5f4";
+
+    let mut rgb_anchors = RgbAnchors::default();
+    rgb_anchors.record("contract_1", fake_transfer("consig_1", txid_1), 100, hash);
+    rgb_anchors.record("contract_1", fake_transfer("consig_2", txid_2), 105, hash);
+
+    rgb_anchors.prune(110, 10);
+    assert!(!rgb_anchors.anchors.contains_key("consig_1"));
+    assert!(rgb_anchors.anchors.contains_key("consig_2"));
+    Ok(())
+}
@@ -1,15 +1,25 @@
 #![cfg(not(target_arch = "wasm32"))]
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
 use amplify::{confinement::U32, hex::ToHex};
+use bitcoin_30::bip32::ExtendedPubKey;
+use bitcoin_scripts::address::AddressNetwork;
 use bitmask_core::{
     rgb::{
         consignment::NewTransferOptions,
-        transfer::{accept_transfer, create_invoice, pay_invoice},
+        structs::{RgbInvoiceRecord, RgbReservations},
+        transfer::{
+            accept_transfer, create_invoice, create_witness_invoice, extract_beneficiary_amounts,
+            pay_invoice, NewInvoiceOptions,
+        },
+        wallet::{create_wallet, next_addresses, reserve_address},
     },
+    structs::InvoiceStatus,
     util::init_logging,
 };
-use rgbstd::persistence::Stock;
+use rgb::RgbDescr;
+use rgbstd::{contract::ContractId, persistence::Stock};
+use rgbwallet::Beneficiary;
 use strict_encoding::StrictSerialize;
 
 use crate::rgb::unit::utils::{
@@ -34,6 +44,7 @@ async fn allow_create_invoice() -> anyhow::Result<()> {
         seal,
         "regtest",
         params,
+        NewInvoiceOptions::default(),
         &mut stock,
     );
 
@@ -41,6 +52,91 @@ async fn allow_create_invoice() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn allow_create_invoice_with_expiry_and_transports() -> anyhow::Result<()> {
+    init_logging("rgb_invoice=warn");
+
+    let iface = "RGB20";
+    let seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let amount = 1;
+    let expiry = chrono::Utc::now().timestamp() + 3600;
+    let transports = vec!["rpcs://proxy.bitmask.app/json-rpc".to_string()];
+
+    let mut stock = Stock::default();
+    let params = HashMap::new();
+    let contract_id = create_fake_contract(&mut stock);
+    let invoice = create_invoice(
+        &contract_id.to_string(),
+        iface,
+        amount,
+        seal,
+        "regtest",
+        params,
+        NewInvoiceOptions::with(Some(expiry), transports),
+        &mut stock,
+    )?;
+
+    assert_eq!(invoice.expiry, Some(expiry));
+    assert_eq!(invoice.transports.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn deny_pay_expired_invoice() -> anyhow::Result<()> {
+    init_logging("rgb_invoice=warn");
+
+    let iface = "RGB20";
+    let seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let amount = 1;
+    let expiry = chrono::Utc::now().timestamp() - 3600;
+
+    let mut stock = Stock::default();
+    let psbt = create_fake_psbt();
+    let params = HashMap::new();
+    let contract_id = create_fake_contract(&mut stock);
+    let invoice = create_invoice(
+        &contract_id.to_string(),
+        iface,
+        amount,
+        seal,
+        "regtest",
+        params,
+        NewInvoiceOptions::with(Some(expiry), vec![]),
+        &mut stock,
+    )?;
+
+    let options = NewTransferOptions::default();
+    let result = pay_invoice(invoice.to_string(), psbt.to_string(), options, &mut stock);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_create_witness_invoice() -> anyhow::Result<()> {
+    init_logging("rgb_invoice=warn");
+
+    let iface = "RGB20";
+    let address = "bcrt1p92jefmjdcpwj3yu8caayfm3a2sq60mwzd834tu35tsne9k0c6q2qe0vukk";
+    let amount = 1;
+
+    let mut stock = Stock::default();
+    let params = HashMap::new();
+    let contract_id = create_fake_contract(&mut stock);
+    let invoice = create_witness_invoice(
+        &contract_id.to_string(),
+        iface,
+        amount,
+        address,
+        "regtest",
+        params,
+        NewInvoiceOptions::default(),
+        &mut stock,
+    )?;
+
+    assert!(matches!(invoice.beneficiary, Beneficiary::WitnessUtxo(_)));
+    Ok(())
+}
+
 #[tokio::test]
 async fn allow_pay_invoice() -> anyhow::Result<()> {
     init_logging("rgb_invoice=warn");
@@ -91,3 +187,162 @@ async fn allow_accept_invoice() -> anyhow::Result<()> {
     assert!(pay_status.is_ok());
     Ok(())
 }
+
+#[tokio::test]
+async fn allow_track_invoice_payment_status() -> anyhow::Result<()> {
+    init_logging("rgb_invoice=warn");
+
+    let iface = "RGB20";
+    let seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let amount = 10;
+    let expiry = chrono::Utc::now().timestamp() + 3600;
+
+    let mut stock = Stock::default();
+    let params = HashMap::new();
+    let contract_id = create_fake_contract(&mut stock);
+    let invoice = create_invoice(
+        &contract_id.to_string(),
+        iface,
+        amount,
+        seal,
+        "regtest",
+        params,
+        NewInvoiceOptions::with(Some(expiry), vec![]),
+        &mut stock,
+    )?;
+
+    let mut record = RgbInvoiceRecord::new(&invoice, chrono::Utc::now().timestamp());
+    assert_eq!(record.status, InvoiceStatus::Open);
+    assert_eq!(record.beneficiary, invoice.beneficiary.to_string());
+
+    let contract_id = contract_id.to_string();
+    assert!(record.settle(&contract_id, "transfer_1", 4));
+    assert_eq!(record.status, InvoiceStatus::PartiallyPaid);
+
+    // The same transfer is only counted once
+    assert!(!record.settle(&contract_id, "transfer_1", 4));
+    assert_eq!(record.received, 4);
+
+    // Payments of other contracts are ignored
+    let other_contract_id = ContractId::from([1u8; 32]).to_string();
+    assert!(!record.settle(&other_contract_id, "transfer_3", 6));
+    assert_eq!(record.received, 4);
+
+    assert!(record.settle(&contract_id, "transfer_2", 6));
+    assert_eq!(record.status, InvoiceStatus::Paid);
+
+    // Overpayments never overflow the received amount
+    assert!(record.settle(&contract_id, "transfer_4", u64::MAX));
+    assert_eq!(record.received, u64::MAX);
+
+    // Paid invoices never expire
+    record.refresh(expiry + 1);
+    assert_eq!(record.status, InvoiceStatus::Paid);
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_expire_open_invoice() -> anyhow::Result<()> {
+    init_logging("rgb_invoice=warn");
+
+    let seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+
+    let mut stock = Stock::default();
+    let contract_id = create_fake_contract(&mut stock);
+    let invoice = create_invoice(
+        &contract_id.to_string(),
+        "RGB20",
+        1,
+        seal,
+        "regtest",
+        HashMap::new(),
+        NewInvoiceOptions::with(Some(1), vec![]),
+        &mut stock,
+    )?;
+
+    let mut record = RgbInvoiceRecord::new(&invoice, 0);
+    record.refresh(chrono::Utc::now().timestamp());
+    assert_eq!(record.status, InvoiceStatus::Expired);
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_extract_invoice_beneficiary_amounts() -> anyhow::Result<()> {
+    init_logging("rgb_invoice=warn");
+
+    let mut stock = Stock::default();
+    let psbt = create_fake_psbt();
+    let resolver = DumbResolve {};
+
+    let contract_id = create_fake_contract(&mut stock);
+
+    let seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let invoice = create_fake_invoice(contract_id, seal, &mut stock);
+
+    let options = NewTransferOptions::default();
+    let (_, transfers) = pay_invoice(invoice.to_string(), psbt.to_string(), options, &mut stock)?;
+
+    let amounts =
+        extract_beneficiary_amounts(&transfers[0], bitcoin_30::Network::Regtest, &resolver);
+    assert_eq!(amounts.get(&invoice.beneficiary.to_string()), Some(&1));
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_pay_multiple_invoices_in_one_transaction() -> anyhow::Result<()> {
+    init_logging("rgb_invoice=warn");
+
+    let mut resolver = DumbResolve {};
+    let mut stock = Stock::default();
+    let psbt = create_fake_psbt();
+
+    let contract_id = create_fake_contract(&mut stock);
+
+    let seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let invoice = create_fake_invoice(contract_id, seal, &mut stock);
+
+    let other_seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:1";
+    let other_invoice = create_fake_invoice(contract_id, other_seal, &mut stock);
+
+    let options = NewTransferOptions::with(true, vec![other_invoice]);
+    let (_, transfers) = pay_invoice(invoice.to_string(), psbt.to_string(), options, &mut stock)?;
+
+    // One consignment per beneficiary
+    assert_eq!(transfers.len(), 2);
+    for transfer in transfers {
+        let pay_status = transfer.unbindle().validate(&mut resolver);
+        assert!(pay_status.is_ok());
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_reserve_witness_invoice_address() -> anyhow::Result<()> {
+    init_logging("rgb_invoice=warn");
+
+    let xpub = ExtendedPubKey::from_str("tpubDCa3US185mM8yGTXtPWY1wNRMCiX89kzN4dwTMKUJyiJnnq486MTeyYShvHiS8Dd1zR2myy5xyJFDs5YacVHn6JZbVaDAtkrXZE3tTVRHPu")?;
+    let mut wallets = HashMap::new();
+    let wallet = create_wallet("default", xpub, &mut wallets)?;
+    let mut rgb_reservations = RgbReservations::default();
+
+    let first = reserve_address(20, &wallet, &mut rgb_reservations, AddressNetwork::Regtest)?;
+    let second = reserve_address(20, &wallet, &mut rgb_reservations, AddressNetwork::Regtest)?;
+    assert_ne!(first.address, second.address);
+    assert_eq!(first.terminal.index + 1, second.terminal.index);
+
+    let next = next_addresses(
+        20,
+        wallet.clone(),
+        &rgb_reservations,
+        AddressNetwork::Regtest,
+        1,
+    )?;
+    assert_eq!(next[0].terminal.index, second.terminal.index + 1);
+
+    // The reservations are kept out of the wallet descriptor
+    assert_eq!(rgb_reservations.last_index(20), Some(second.terminal.index));
+    assert_eq!(rgb_reservations.last_index(9), None);
+    let RgbDescr::Tapret(tapret) = &wallet.descr;
+    assert!(tapret.taprets.is_empty());
+    Ok(())
+}
@@ -1,10 +1,31 @@
 #![cfg(not(target_arch = "wasm32"))]
+use std::{collections::BTreeMap, str::FromStr};
+
+use amplify::hex::ToHex;
 use anyhow::Result;
 use bitmask_core::{
-    rgb::issue::issue_contract, structs::IssueRequest, util::init_logging, validators::RGBContext,
+    rgb::{
+        import::{import_iface, import_iface_impl, import_schema},
+        issue::{issue_contract, issue_custom_contract, IssueContractError, IssueError},
+        schemata::{check_ifa_transition, ifa_rgb20, ifa_schema},
+    },
+    structs::{
+        IssueAllocationRequest, IssueAssignmentRequest, IssueGlobalStateRequest,
+        IssueInflationRequest, IssueMediaRequest, IssueRequest, IssueTokenRequest, MediaInfo,
+    },
+    util::init_logging,
+    validators::RGBContext,
 };
+use bp::{seals::txout::CloseMethod, Outpoint, Txid};
 use garde::Validate;
-use rgbstd::persistence::Stock;
+use rgbstd::{
+    containers::{Bindle, BuilderSeal},
+    contract::GraphSeal,
+    interface::{rgb20, TypedState},
+    persistence::{Inventory, Stock},
+    stl::{Amount, ContractData},
+};
+use strict_encoding::{tn, FieldName, StrictDeserialize};
 
 use crate::rgb::unit::utils::{get_uda_data, DumbResolve};
 
@@ -31,6 +52,11 @@ async fn issue_request_params_check() -> Result<()> {
         seal: seal.to_string(),
         iface: iface.to_string(),
         meta: None,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
     assert!(rgb20.validate(ctx).is_ok());
 
@@ -43,6 +69,11 @@ async fn issue_request_params_check() -> Result<()> {
         seal: seal.to_string(),
         iface: iface.to_string(),
         meta: Some(get_uda_data()),
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
     assert!(rgb21.validate(ctx).is_ok());
 
@@ -74,8 +105,12 @@ async fn issue_contract_test() -> Result<()> {
         supply,
         iface,
         seal,
+        vec![],
         network,
         None,
+        None,
+        None,
+        vec![],
         &mut resolver,
         &mut stock,
     );
@@ -83,3 +118,614 @@ async fn issue_contract_test() -> Result<()> {
     assert!(contract.is_ok());
     Ok(())
 }
+
+#[tokio::test]
+async fn issue_collectible_fungible_contract_test() -> Result<()> {
+    init_logging("rgb_issue=warn");
+
+    let ticker = "DIBA";
+    let name = "DIBA";
+    let description =
+        "1 2 3 testing... 1 2 3 testing... 1 2 3 testing... 1 2 3 testing.... 1 2 3 testing";
+    let precision = 0;
+    let supply = 10;
+    let iface = "RGB25";
+    let seal = "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:0";
+    let network = "regtest";
+
+    let mut stock = Stock::default();
+    let mut resolver = DumbResolve {};
+
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        precision,
+        supply,
+        iface,
+        seal,
+        vec![],
+        network,
+        None,
+        None,
+        None,
+        vec![],
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(contract.is_ok());
+
+    // Details are optional
+    let contract = issue_contract(
+        ticker,
+        name,
+        "",
+        precision,
+        supply,
+        iface,
+        seal,
+        vec![],
+        network,
+        None,
+        None,
+        None,
+        vec![],
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(contract.is_ok());
+    Ok(())
+}
+
+#[tokio::test]
+async fn issue_inflatable_contract_test() -> Result<()> {
+    init_logging("rgb_issue=warn");
+
+    let ticker = "DIBA";
+    let name = "DIBA";
+    let description =
+        "1 2 3 testing... 1 2 3 testing... 1 2 3 testing... 1 2 3 testing.... 1 2 3 testing";
+    let precision = 8;
+    let supply = 10;
+    let iface = "RGB20";
+    let seal = "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:0";
+    let network = "regtest";
+    let inflation = IssueInflationRequest {
+        seal: "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:1"
+            .to_string(),
+        amount: 90,
+    };
+
+    let mut stock = Stock::default();
+    let mut resolver = DumbResolve {};
+
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        precision,
+        supply,
+        iface,
+        seal,
+        vec![],
+        network,
+        None,
+        None,
+        Some(inflation.clone()),
+        vec![],
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(contract.is_ok());
+
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        0,
+        supply,
+        "RGB25",
+        seal,
+        vec![],
+        network,
+        None,
+        None,
+        Some(inflation),
+        vec![],
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(contract.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn reject_inflatable_over_issuance_test() -> Result<()> {
+    init_logging("rgb_issue=warn");
+
+    let txid = "70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52";
+    let inflation = IssueInflationRequest {
+        seal: format!("tapret1st:{txid}:1"),
+        amount: 90,
+    };
+
+    let mut stock = Stock::default();
+    let mut resolver = DumbResolve {};
+
+    let contract = issue_contract(
+        "DIBA",
+        "DIBA",
+        "DIBA",
+        8,
+        10,
+        "RGB20",
+        &format!("tapret1st:{txid}:0"),
+        vec![],
+        "regtest",
+        None,
+        None,
+        Some(inflation),
+        vec![],
+        &mut resolver,
+        &mut stock,
+    )?;
+    let contract_id = contract.contract_id();
+
+    let allowance = Outpoint::new(Txid::from_str(txid)?, 1);
+    let issue_transition = |amount: u64, stock: &mut Stock| -> Result<_> {
+        let mut builder =
+            stock.transition_builder(contract_id, tn!("RGB20"), Some(tn!("Issue")))?;
+        let asset_name = FieldName::from("assetOwner");
+        let asset_id = builder
+            .assignments_type(&asset_name)
+            .expect("missing asset owner");
+
+        let mut inputs = BTreeMap::new();
+        for (opout, state) in stock.state_for_outpoints(contract_id, [allowance])? {
+            if let TypedState::Amount(value) = state {
+                builder = builder.add_input(opout)?;
+                inputs.insert(opout, value);
+            }
+        }
+
+        let seal = GraphSeal::new_vout(CloseMethod::TapretFirst, 0);
+        let transition = builder
+            .add_global_state("issuedSupply", Amount::from(amount))?
+            .add_raw_state(
+                asset_id,
+                BuilderSeal::Revealed(seal),
+                TypedState::Amount(amount),
+            )?
+            .complete_transition(contract_id)?;
+        Ok((transition, inputs))
+    };
+
+    let (transition, inputs) = issue_transition(90, &mut stock)?;
+    assert!(check_ifa_transition(&transition, &inputs).is_ok());
+
+    let (transition, inputs) = issue_transition(100, &mut stock)?;
+    assert!(check_ifa_transition(&transition, &inputs).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn issue_multi_seal_contract_test() -> Result<()> {
+    init_logging("rgb_issue=warn");
+
+    let ticker = "DIBA";
+    let name = "DIBA";
+    let description =
+        "1 2 3 testing... 1 2 3 testing... 1 2 3 testing... 1 2 3 testing.... 1 2 3 testing";
+    let precision = 8;
+    let supply = 10;
+    let iface = "RGB20";
+    let seal = "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:0";
+    let network = "regtest";
+    let allocations = vec![
+        IssueAllocationRequest {
+            seal: "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:1"
+                .to_string(),
+            amount: 3,
+        },
+        IssueAllocationRequest {
+            seal: "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:2"
+                .to_string(),
+            amount: 2,
+        },
+    ];
+
+    let ctx = &RGBContext::default();
+    let mut request = IssueRequest {
+        ticker: ticker.to_string(),
+        name: name.to_string(),
+        description: description.to_string(),
+        supply,
+        precision,
+        seal: seal.to_string(),
+        iface: iface.to_string(),
+        meta: None,
+        inflation: None,
+        allocations: allocations.clone(),
+        terms: None,
+        attachment: None,
+        collection: vec![],
+    };
+    assert!(request.validate(ctx).is_ok());
+
+    request.allocations.push(IssueAllocationRequest {
+        seal: "70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:3".to_string(),
+        amount: 1,
+    });
+    assert!(request.validate(ctx).is_err());
+
+    let mut stock = Stock::default();
+    let mut resolver = DumbResolve {};
+
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        precision,
+        supply,
+        iface,
+        seal,
+        allocations.clone(),
+        network,
+        None,
+        None,
+        None,
+        vec![],
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(contract.is_ok());
+
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        precision,
+        4,
+        iface,
+        seal,
+        allocations.clone(),
+        network,
+        None,
+        None,
+        None,
+        vec![],
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(contract.is_err());
+
+    // The sum of the allocations cannot overflow
+    let mut allocations = allocations;
+    allocations[0].amount = u64::MAX;
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        precision,
+        u64::MAX,
+        iface,
+        seal,
+        allocations,
+        network,
+        None,
+        None,
+        None,
+        vec![],
+        &mut resolver,
+        &mut stock,
+    );
+    assert_eq!(contract.err(), Some(IssueContractError::AllocationOverflow));
+    Ok(())
+}
+
+#[tokio::test]
+async fn issue_custom_contract_test() -> Result<()> {
+    init_logging("rgb_issue=warn");
+
+    let seal = "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:0";
+    let network = "regtest";
+
+    let mut stock = Stock::default();
+    let mut resolver = DumbResolve {};
+
+    let iface = import_iface(&Bindle::new(rgb20()).to_string(), &mut stock)?;
+    let schema = import_schema(&Bindle::new(ifa_schema()).to_string(), &mut stock)?;
+    let iimpl = import_iface_impl(&Bindle::new(ifa_rgb20()).to_string(), &mut stock)?;
+    assert_eq!(iface.iface_id(), iimpl.iface_id);
+    assert_eq!(schema.schema_id(), iimpl.schema_id);
+
+    let schema_id = schema.schema_id().to_string();
+    let assignments = vec![IssueAssignmentRequest {
+        seal: seal.to_string(),
+        name: "assetOwner".to_string(),
+        amount: Some(10),
+        data: None,
+    }];
+
+    let unknown_schema = issue_custom_contract(
+        &iface.iface_id().to_string(),
+        "RGB20",
+        network,
+        vec![],
+        assignments.clone(),
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(matches!(
+        unknown_schema,
+        Err(IssueContractError::NoSchema(_))
+    ));
+
+    let unknown_global = issue_custom_contract(
+        &schema_id,
+        "RGB20",
+        network,
+        vec![IssueGlobalStateRequest {
+            name: "unknownState".to_string(),
+            value: serde_json::json!(10),
+        }],
+        assignments.clone(),
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(matches!(
+        unknown_global,
+        Err(IssueContractError::WrongState(name, _)) if name == "unknownState"
+    ));
+
+    let wrong_assignment = issue_custom_contract(
+        &schema_id,
+        "RGB20",
+        network,
+        vec![],
+        vec![IssueAssignmentRequest {
+            amount: None,
+            ..assignments[0].clone()
+        }],
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(matches!(
+        wrong_assignment,
+        Err(IssueContractError::WrongState(name, _)) if name == "assetOwner"
+    ));
+
+    for value in [serde_json::json!("ten"), serde_json::json!(true)] {
+        let wrong_type = issue_custom_contract(
+            &schema_id,
+            "RGB20",
+            network,
+            vec![IssueGlobalStateRequest {
+                name: "issuedSupply".to_string(),
+                value,
+            }],
+            assignments.clone(),
+            &mut resolver,
+            &mut stock,
+        );
+        assert!(matches!(
+            wrong_type,
+            Err(IssueContractError::WrongState(name, _)) if name == "issuedSupply"
+        ));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn issue_contract_with_terms_and_attachment_test() -> Result<()> {
+    init_logging("rgb_issue=warn");
+
+    let ticker = "DIBA";
+    let name = "DIBA";
+    let description = "DIBA fungible asset";
+    let terms = "The holder of the asset agrees with the terms of the prospectus";
+    let precision = 8;
+    let supply = 10;
+    let iface = "RGB20";
+    let seal = "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:0";
+    let network = "regtest";
+    let digest = "5ca6cd1f54c081c8b3a7b4bcc988e55fe3c420ac87512b53a58c55233e15ba4f";
+    let meta = IssueMediaRequest {
+        media: Some(MediaInfo {
+            ty: "application/pdf".to_string(),
+            source: digest.to_string(),
+        }),
+        ..Default::default()
+    };
+
+    let mut stock = Stock::default();
+    let mut resolver = DumbResolve {};
+
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        precision,
+        supply,
+        iface,
+        seal,
+        vec![],
+        network,
+        Some(meta),
+        Some(terms.to_string()),
+        None,
+        vec![],
+        &mut resolver,
+        &mut stock,
+    )?;
+
+    let iface = stock.iface_by_name(&tn!("RGB20"))?;
+    let contract_iface = stock.contract_iface(contract.contract_id(), iface.iface_id())?;
+    let values = contract_iface.global(FieldName::from("data"))?;
+    let contract_data = ContractData::from_strict_val_unchecked(&values[0]);
+
+    assert_eq!(terms, contract_data.terms.to_string());
+    let media = contract_data.media.expect("attachment not committed");
+    assert_eq!("application/pdf", media.ty.to_string());
+    assert_eq!(digest, media.digest.to_hex());
+
+    // The attached document is committed by its sha256 digest
+    let meta = IssueMediaRequest {
+        media: Some(MediaInfo {
+            ty: "application/pdf".to_string(),
+            source: digest[..16].to_string(),
+        }),
+        ..Default::default()
+    };
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        precision,
+        supply,
+        "RGB20",
+        seal,
+        vec![],
+        network,
+        Some(meta),
+        Some(terms.to_string()),
+        None,
+        vec![],
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(matches!(
+        contract,
+        Err(IssueContractError::Issue(IssueError::WrongDigest(_)))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn issue_collection_contract_test() -> Result<()> {
+    init_logging("rgb_issue=warn");
+
+    let ticker = "DBC";
+    let name = "DIBA Collection";
+    let description = "DIBA collection of unique digital assets";
+    let seal = "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:0";
+    let network = "regtest";
+    let media = get_uda_data().media;
+
+    let collection: Vec<IssueTokenRequest> = (0..3)
+        .map(|vout| IssueTokenRequest {
+            seal: format!(
+                "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:{vout}"
+            ),
+            media: media.clone(),
+            ..Default::default()
+        })
+        .collect();
+
+    let mut stock = Stock::default();
+    let mut resolver = DumbResolve {};
+
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        0,
+        1,
+        "RGB21",
+        seal,
+        vec![],
+        network,
+        None,
+        None,
+        None,
+        collection.clone(),
+        &mut resolver,
+        &mut stock,
+    )?;
+
+    let iface = stock.iface_by_name(&tn!("RGB21"))?;
+    let contract_iface = stock.contract_iface(contract.contract_id(), iface.iface_id())?;
+    let tokens = contract_iface.global(FieldName::from("tokens"))?;
+    let owners = contract_iface.data(FieldName::from("assetOwner"))?;
+    assert_eq!(3, tokens.len());
+    assert_eq!(3, owners.into_iter().count());
+
+    let contract = issue_contract(
+        ticker,
+        name,
+        description,
+        0,
+        1,
+        "RGB20",
+        seal,
+        vec![],
+        network,
+        None,
+        None,
+        None,
+        collection,
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(matches!(
+        contract,
+        Err(IssueContractError::NoCollectionSupport(_))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn reject_collection_wrong_media_test() -> Result<()> {
+    init_logging("rgb_issue=warn");
+
+    let seal = "tapret1st:70339a6b27f55105da2d050babc759f046c21c26b7b75e9394bc1d818e50ff52:0";
+    let ctx = &RGBContext::default();
+    let mut token = IssueTokenRequest {
+        seal: seal.to_string(),
+        preview: Some(MediaInfo {
+            ty: "image/png".to_string(),
+            source: "aGVsbG8gd29ybGQ=".to_string(),
+        }),
+        media: get_uda_data().media,
+        attachments: vec![],
+    };
+    assert!(token.validate(ctx).is_ok());
+
+    // The preview is embedded in base64
+    token.preview = Some(MediaInfo {
+        ty: "image/png".to_string(),
+        source: "not base64!".to_string(),
+    });
+    assert!(token.validate(ctx).is_err());
+
+    // Media and attachments are committed by their digest
+    token.preview = None;
+    token.attachments = vec![MediaInfo {
+        ty: "image/png".to_string(),
+        source: "b9bf23a30bedcc4c".to_string(),
+    }];
+    assert!(token.validate(ctx).is_err());
+
+    let mut stock = Stock::default();
+    let mut resolver = DumbResolve {};
+    let contract = issue_contract(
+        "DBC",
+        "DIBA Collection",
+        "DIBA collection of unique digital assets",
+        0,
+        1,
+        "RGB21",
+        seal,
+        vec![],
+        "regtest",
+        None,
+        None,
+        None,
+        vec![token],
+        &mut resolver,
+        &mut stock,
+    );
+    assert!(contract.is_err());
+    Ok(())
+}
@@ -0,0 +1,114 @@
+#![cfg(not(target_arch = "wasm32"))]
+use bitmask_core::{
+    rgb::structs::{RgbLockOwner, RgbLocks},
+    util::init_logging,
+};
+
+#[tokio::test]
+async fn allow_lock_and_release_outpoints() -> anyhow::Result<()> {
+    init_logging("rgb_locks=warn");
+
+    let utxo_1 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let utxo_2 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:1";
+    let utxo_3 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:2";
+
+    let transfer = RgbLockOwner::Transfer("consig_1".to_string());
+    let offer = RgbLockOwner::Offer("offer_1".to_string());
+
+    let mut rgb_locks = RgbLocks::default();
+    rgb_locks.lock(vec![utxo_1.to_string()], transfer.clone(), 0);
+    rgb_locks.lock(vec![utxo_2.to_string()], offer.clone(), 0);
+
+    assert!(rgb_locks.is_locked(utxo_1));
+    assert!(rgb_locks.is_locked(utxo_2));
+    assert!(!rgb_locks.is_locked(utxo_3));
+
+    let released = rgb_locks.release(&offer);
+    assert_eq!(vec![utxo_2.to_string()], released);
+    assert!(!rgb_locks.is_locked(utxo_2));
+    assert!(rgb_locks.is_locked(utxo_1));
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_release_confirmed_transfers() -> anyhow::Result<()> {
+    init_logging("rgb_locks=warn");
+
+    let utxo_1 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let utxo_2 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:1";
+    let utxo_3 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:2";
+
+    let mut rgb_locks = RgbLocks::default();
+    rgb_locks.lock(
+        vec![utxo_1.to_string()],
+        RgbLockOwner::Transfer("consig_1".to_string()),
+        0,
+    );
+    rgb_locks.lock(
+        vec![utxo_2.to_string()],
+        RgbLockOwner::Transfer("consig_2".to_string()),
+        0,
+    );
+    rgb_locks.lock(
+        vec![utxo_3.to_string()],
+        RgbLockOwner::Offer("offer_1".to_string()),
+        0,
+    );
+
+    // consig_1 is confirmed, consig_2 is still pending
+    rgb_locks.release_transfers(&["consig_2".to_string()]);
+
+    assert!(!rgb_locks.is_locked(utxo_1));
+    assert!(rgb_locks.is_locked(utxo_2));
+    assert!(rgb_locks.is_locked(utxo_3));
+
+    // A replacement transaction takes over the reservation
+    rgb_locks.lock(
+        vec![utxo_2.to_string()],
+        RgbLockOwner::Transfer("consig_3".to_string()),
+        1,
+    );
+    rgb_locks.release_transfers(&["consig_3".to_string()]);
+    assert!(rgb_locks.is_locked(utxo_2));
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_release_expired_exports() -> anyhow::Result<()> {
+    init_logging("rgb_locks=warn");
+
+    let utxo_1 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let utxo_2 = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:1";
+
+    let mut rgb_locks = RgbLocks::default();
+    rgb_locks.lock(
+        vec![utxo_1.to_string()],
+        RgbLockOwner::Export("consig_1".to_string()),
+        0,
+    );
+    rgb_locks.lock(
+        vec![utxo_2.to_string()],
+        RgbLockOwner::Export("consig_2".to_string()),
+        100,
+    );
+
+    // Exported transfers are not pending until they are imported
+    rgb_locks.release_transfers(&[]);
+    assert!(rgb_locks.is_locked(utxo_1));
+    assert!(rgb_locks.is_locked(utxo_2));
+
+    rgb_locks.release_expired_exports(150, 100);
+    assert!(!rgb_locks.is_locked(utxo_1));
+    assert!(rgb_locks.is_locked(utxo_2));
+
+    // The imported transfer takes over the reservation
+    rgb_locks.release(&RgbLockOwner::Export("consig_2".to_string()));
+    rgb_locks.lock(
+        vec![utxo_2.to_string()],
+        RgbLockOwner::Transfer("consig_2".to_string()),
+        150,
+    );
+    rgb_locks.release_expired_exports(1000, 100);
+    assert!(rgb_locks.is_locked(utxo_2));
+    Ok(())
+}
@@ -5,13 +5,18 @@ use crate::rgb::unit::utils::{
 use bitmask_core::{
     rgb::{
         consignment::NewTransferOptions,
-        psbt::{create_psbt, extract_output_commit, NewPsbtOptions},
+        psbt::{
+            create_psbt, estimate_cpfp_fee, estimate_fee_rate, estimate_taproot_vsize,
+            extract_output_commit, psbt_close_method, set_opret_output, NewPsbtOptions,
+        },
         transfer::pay_invoice,
     },
-    structs::{PsbtInputRequest, SecretString},
+    structs::{PsbtInputRequest, SecretString, TxFee},
     util::init_logging,
 };
+use bp::seals::txout::CloseMethod;
 use rgb::persistence::Stock;
+use std::collections::HashMap;
 
 #[tokio::test]
 async fn allow_create_psbt_file() -> anyhow::Result<()> {
@@ -44,6 +49,49 @@ async fn allow_create_psbt_file() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn allow_create_opret_psbt_file() -> anyhow::Result<()> {
+    init_logging("rgb_psbt=warn");
+
+    let desc = "tr(m=[280a5963]/86h/1h/0h=[tpubDCa3US185mM8yGTXtPWY1wNRMCiX89kzN4dwTMKUJyiJnnq486MTeyYShvHiS8Dd1zR2myy5xyJFDs5YacVHn6JZbVaDAtkrXZE3tTVRHPu]/*/*)#8an50cqp";
+    let asset_utxo = "5ca6cd1f54c081c8b3a7b4bcc988e55fe3c420ac87512b53a58c55233e15ba4f:1";
+    let asset_utxo_terminal = "/0/0";
+
+    let fee = 1000;
+    let tx_resolver = DumbResolve {};
+
+    let options = NewPsbtOptions {
+        close_method: CloseMethod::OpretFirst,
+        ..NewPsbtOptions::default()
+    };
+    let (psbt, _) = create_psbt(
+        vec![PsbtInputRequest {
+            descriptor: SecretString(desc.to_string()),
+            utxo: asset_utxo.to_string(),
+            utxo_terminal: asset_utxo_terminal.to_string(),
+            sigh_hash: None,
+            tapret: None,
+        }],
+        vec![],
+        fee,
+        Some("/0/1".to_string()),
+        None,
+        &tx_resolver,
+        options,
+    )?;
+    assert!(psbt
+        .outputs
+        .iter()
+        .any(|output| output.script.is_op_return()));
+    assert_eq!(psbt_close_method(&psbt), CloseMethod::TapretFirst);
+
+    let pos = (psbt.outputs.len() - 1) as u16;
+    let psbt = set_opret_output(psbt, pos)?;
+    assert_eq!(psbt_close_method(&psbt), CloseMethod::OpretFirst);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn allow_extract_output_commit_from_psbt() -> anyhow::Result<()> {
     let mut stock = Stock::default();
@@ -64,3 +112,47 @@ async fn allow_extract_output_commit_from_psbt() -> anyhow::Result<()> {
     assert!(commit.is_ok());
     Ok(())
 }
+
+#[tokio::test]
+async fn allow_estimate_cpfp_fee() -> anyhow::Result<()> {
+    init_logging("rgb_psbt=warn");
+
+    assert_eq!(112, estimate_taproot_vsize(1, 1));
+
+    // Parent: 200 vB paying 1 sat/vB
+    let parent = TxFee {
+        fee: 200,
+        weight: 800,
+    };
+    // Package: (200 + 112) vB * 10 sat/vB - 200 sats already paid by the parent
+    assert_eq!(2_920, estimate_cpfp_fee(&parent, 1, 1, 10.0));
+
+    // Parent already above the fee rate, the child pays its own size
+    let parent = TxFee {
+        fee: 5_000,
+        weight: 800,
+    };
+    assert_eq!(1_120, estimate_cpfp_fee(&parent, 1, 1, 10.0));
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_estimate_fee_rate_by_target() -> anyhow::Result<()> {
+    init_logging("rgb_psbt=warn");
+
+    let estimates = HashMap::from([(1, 25.5), (2, 20.0), (6, 12.0), (144, 0.5), (1008, 0.1)]);
+
+    assert_eq!(Some(25.5), estimate_fee_rate(1, &estimates));
+    // No estimate for 3 blocks, use the closest lower target
+    assert_eq!(Some(20.0), estimate_fee_rate(3, &estimates));
+    assert_eq!(Some(12.0), estimate_fee_rate(100, &estimates));
+    // Below the min. relay fee rate
+    assert_eq!(Some(1.0), estimate_fee_rate(144, &estimates));
+
+    // Above the max. fee rate
+    let estimates = HashMap::from([(1, 2_000.0)]);
+    assert_eq!(Some(500.0), estimate_fee_rate(1, &estimates));
+
+    assert_eq!(None, estimate_fee_rate(6, &HashMap::new()));
+    Ok(())
+}
@@ -0,0 +1,105 @@
+#![cfg(not(target_arch = "wasm32"))]
+use bitmask_core::{
+    rgb::selection::{find_missing_outpoint, select_coins},
+    structs::CoinSelectionStrategy,
+    util::init_logging,
+};
+
+fn fake_utxos(amounts: &[u64]) -> Vec<(String, u64)> {
+    let txid = "ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e";
+    amounts
+        .iter()
+        .enumerate()
+        .map(|(vout, amount)| (format!("{txid}:{vout}"), *amount))
+        .collect()
+}
+
+fn select(utxos: &[(String, u64)], target: u64, strategy: CoinSelectionStrategy) -> Vec<u64> {
+    select_coins(
+        utxos.to_vec(),
+        target,
+        &strategy,
+        |(outpoint, _)| outpoint.clone(),
+        |(_, amount)| *amount,
+    )
+    .into_iter()
+    .map(|(_, amount)| amount)
+    .collect()
+}
+
+#[tokio::test]
+async fn allow_select_coins_by_strategy() -> anyhow::Result<()> {
+    init_logging("rgb_selection=warn");
+
+    let utxos = fake_utxos(&[300, 1_000, 200, 5_000, 700]);
+
+    assert_eq!(
+        vec![300, 1_000],
+        select(&utxos, 1_200, CoinSelectionStrategy::Default)
+    );
+    assert_eq!(
+        vec![5_000],
+        select(&utxos, 1_200, CoinSelectionStrategy::LargestFirst)
+    );
+    assert_eq!(
+        vec![200, 300, 700],
+        select(&utxos, 1_200, CoinSelectionStrategy::SmallestFirst)
+    );
+    assert_eq!(
+        vec![1_000, 200],
+        select(&utxos, 1_200, CoinSelectionStrategy::BranchAndBound)
+    );
+    assert_eq!(
+        vec![1_000],
+        select(&utxos, 900, CoinSelectionStrategy::Privacy)
+    );
+    assert!(select(&utxos, 0, CoinSelectionStrategy::LargestFirst).is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_select_coins_fallback() -> anyhow::Result<()> {
+    init_logging("rgb_selection=warn");
+
+    let utxos = fake_utxos(&[300, 1_000, 200]);
+
+    // No exact match, so the biggest UTXOs are selected
+    assert_eq!(
+        vec![1_000, 300],
+        select(&utxos, 1_250, CoinSelectionStrategy::BranchAndBound)
+    );
+    // No single UTXO covers the target
+    assert_eq!(
+        vec![1_000, 300],
+        select(&utxos, 1_250, CoinSelectionStrategy::Privacy)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn allow_select_manual_outpoints() -> anyhow::Result<()> {
+    init_logging("rgb_selection=warn");
+
+    let utxos = fake_utxos(&[300, 1_000, 200]);
+    let unknown = "0000000000000000000000000000000000000000000000000000000000000000:0";
+    let strategy = CoinSelectionStrategy::Manual(vec![
+        utxos[2].0.clone(),
+        unknown.to_string(),
+        utxos[0].0.clone(),
+    ]);
+
+    // The listed outpoints are selected even when the target is already reached
+    assert_eq!(vec![200, 300], select(&utxos, 100, strategy.clone()));
+
+    // The unknown outpoint is not spent by the inputs
+    let inputs = vec![utxos[2].0.clone(), utxos[0].0.clone()];
+    assert_eq!(
+        Some(unknown.to_string()),
+        find_missing_outpoint(&strategy, &inputs)
+    );
+    assert_eq!(
+        None,
+        find_missing_outpoint(&CoinSelectionStrategy::Default, &inputs)
+    );
+    Ok(())
+}
@@ -0,0 +1,107 @@
+#![cfg(not(target_arch = "wasm32"))]
+use std::str::FromStr;
+
+use bitmask_core::{
+    rgb::uri::{extract_rgb_invoice, UnifiedUri, UnifiedUriError},
+    util::init_logging,
+};
+use rgbstd::persistence::Stock;
+
+use crate::rgb::unit::utils::{create_fake_contract, create_fake_invoice};
+
+#[tokio::test]
+async fn allow_encode_decode_unified_uri() -> anyhow::Result<()> {
+    init_logging("rgb_uri=warn");
+
+    let mut stock = Stock::default();
+    let contract_id = create_fake_contract(&mut stock);
+    let seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let invoice = create_fake_invoice(contract_id, seal, &mut stock).to_string();
+
+    let address = "bcrt1p92jefmjdcpwj3yu8caayfm3a2sq60mwzd834tu35tsne9k0c6q2qe0vukk";
+    let uri = UnifiedUri::with(
+        "regtest",
+        Some(address.to_string()),
+        Some(150_000),
+        Some(invoice.clone()),
+        Some("lnbcrt1500n1".to_string()),
+    )?;
+
+    let encoded = uri.to_string();
+    assert!(encoded.starts_with(&format!("bitcoin:{address}?amount=")));
+
+    let decoded = UnifiedUri::from_str(&encoded)?;
+    assert_eq!(decoded, uri);
+    assert_eq!(extract_rgb_invoice(&encoded, "regtest")?, invoice);
+
+    // Plain RGB invoices are returned as-is
+    assert_eq!(extract_rgb_invoice(&invoice, "regtest")?, invoice);
+
+    // Query values are percent-decoded ('+' is kept as-is)
+    let encoded = format!(
+        "bitcoin:{address}?rgb={}&label=a+b%20c",
+        invoice.replace(':', "%3A").replace('/', "%2F")
+    );
+    let decoded = UnifiedUri::from_str(&encoded)?;
+    assert_eq!(decoded.rgb_invoice, Some(invoice));
+    assert_eq!(decoded.params.get("label"), Some(&"a+b c".to_string()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn deny_unified_uri_from_other_network() -> anyhow::Result<()> {
+    init_logging("rgb_uri=warn");
+
+    let mut stock = Stock::default();
+    let contract_id = create_fake_contract(&mut stock);
+    let seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let invoice = create_fake_invoice(contract_id, seal, &mut stock).to_string();
+
+    let address = "bcrt1p92jefmjdcpwj3yu8caayfm3a2sq60mwzd834tu35tsne9k0c6q2qe0vukk";
+    let uri = UnifiedUri::with(
+        "bitcoin",
+        Some(address.to_string()),
+        None,
+        Some(invoice.clone()),
+        None,
+    );
+    assert_eq!(
+        uri,
+        Err(UnifiedUriError::WrongNetwork(
+            address.to_string(),
+            "bitcoin".to_string()
+        ))
+    );
+
+    let uri = UnifiedUri::with(
+        "regtest",
+        Some(address.to_string()),
+        None,
+        Some(invoice),
+        None,
+    )?;
+    assert!(matches!(
+        extract_rgb_invoice(&uri.to_string(), "bitcoin"),
+        Err(UnifiedUriError::WrongNetwork(..))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn deny_unified_uri_without_rgb_invoice() -> anyhow::Result<()> {
+    init_logging("rgb_uri=warn");
+
+    let address = "bcrt1p92jefmjdcpwj3yu8caayfm3a2sq60mwzd834tu35tsne9k0c6q2qe0vukk";
+    let uri = format!("bitcoin:{address}?amount=0.0015");
+    assert_eq!(
+        extract_rgb_invoice(&uri, "regtest"),
+        Err(UnifiedUriError::NoInvoice)
+    );
+
+    let uri = format!("bitcoin:{address}?amount=abc");
+    assert_eq!(
+        UnifiedUri::from_str(&uri),
+        Err(UnifiedUriError::WrongAmount("abc".to_string()))
+    );
+    Ok(())
+}
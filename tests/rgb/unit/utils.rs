@@ -4,7 +4,7 @@ use amplify::hex::{FromHex, ToHex};
 use bitcoin::Transaction;
 use bitmask_core::{
     rgb::issue::issue_contract,
-    rgb::transfer::create_invoice,
+    rgb::transfer::{create_invoice, NewInvoiceOptions},
     structs::{IssueMediaRequest, MediaInfo},
 };
 use bp::{
@@ -106,8 +106,12 @@ pub fn create_fake_contract(stock: &mut Stock) -> ContractId {
         supply,
         iface,
         seal,
+        vec![],
         network,
         None,
+        None,
+        None,
+        vec![],
         &mut resolver,
         stock,
     )
@@ -137,6 +141,7 @@ pub fn create_fake_invoice(contract_id: ContractId, seal: &str, stock: &mut Stoc
         seal,
         "regtest",
         params,
+        NewInvoiceOptions::default(),
         stock,
     )
     .expect("create_invoice failed")
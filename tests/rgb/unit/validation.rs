@@ -0,0 +1,45 @@
+#![cfg(not(target_arch = "wasm32"))]
+use crate::rgb::unit::utils::{
+    create_fake_contract, create_fake_invoice, create_fake_psbt, DumbResolve,
+};
+use amplify::{confinement::U32, hex::ToHex};
+use bitmask_core::{
+    rgb::{
+        consignment::NewTransferOptions,
+        transfer::{extract_validation_report, pay_invoice, validate_transfer},
+    },
+    structs::{ConsignmentValidity, TxStatus},
+    util::init_logging,
+};
+use rgb::persistence::Stock;
+use strict_encoding::StrictSerialize;
+
+#[tokio::test]
+async fn allow_report_invalid_consignment() -> anyhow::Result<()> {
+    init_logging("rgb_validation=warn");
+
+    let mut stock = Stock::default();
+    let psbt = create_fake_psbt();
+    let contract_id = create_fake_contract(&mut stock);
+
+    let seal = "tapret1st:ed823b41d8b9309933826b18e4af530363b359f05919c02bbe72f28cec6dec3e:0";
+    let invoice = create_fake_invoice(contract_id, seal, &mut stock);
+
+    let options = NewTransferOptions::default();
+    let (_, transfer) = pay_invoice(invoice.to_string(), psbt.to_string(), options, &mut stock)?;
+    let consignment = transfer.to_strict_serialized::<{ U32 }>()?.to_hex();
+
+    // The fake resolver does not know the witness transaction
+    let mut resolver = DumbResolve {};
+    let (transfer, status) = validate_transfer(consignment, &mut resolver)?;
+    let report = extract_validation_report(&transfer, &status, |_| TxStatus::Mempool);
+
+    assert!(!report.valid);
+    assert_ne!(ConsignmentValidity::Valid, report.validity);
+    assert!(!report.failures.is_empty());
+    assert!(!report.operations.is_empty());
+    assert!(!report.seals.is_empty());
+    assert_eq!(1, report.anchors.len());
+    assert!(!report.anchor_mined);
+    Ok(())
+}
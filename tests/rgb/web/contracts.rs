@@ -102,6 +102,11 @@ async fn allow_issue_and_list_contracts() {
         seal: issue_seal.to_owned(),
         iface: iface.to_string(),
         meta: None,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let issue_req = serde_wasm_bindgen::to_value(&issue_req).expect("");
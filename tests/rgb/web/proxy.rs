@@ -11,13 +11,13 @@ use bitmask_core::{
     debug, info,
     rgb::{prefetch::prefetch_resolver_txs, resolvers::ExplorerResolver},
     structs::{
-        AssetType, BatchRgbTransferResponse, ContractResponse, ContractsResponse,
-        DecryptedWalletData, FullRgbTransferRequest, FundVaultDetails, ImportRequest,
-        InvoiceRequest, InvoiceResponse, IssueMediaRequest, IssueRequest, IssueResponse,
-        MediaItemRequest, MediaRequest, MediaResponse, NextAddressResponse, NextUtxoResponse,
-        PsbtFeeRequest, PublishedPsbtResponse, RgbSaveTransferRequest, RgbTransferRequest,
-        RgbTransferResponse, RgbTransferStatusResponse, SecretString, SignPsbtRequest, WalletData,
-        WatcherRequest, WatcherResponse,
+        AssetType, BatchRgbTransferResponse, CoinSelectionStrategy, ContractResponse,
+        ContractsResponse, DecryptedWalletData, FullRgbTransferRequest, FundVaultDetails,
+        ImportRequest, InvoiceRequest, InvoiceResponse, IssueMediaRequest, IssueRequest,
+        IssueResponse, MediaItemRequest, MediaRequest, MediaResponse, NextAddressResponse,
+        NextUtxoResponse, PsbtFeeRequest, PublishedPsbtResponse, RgbSaveTransferRequest,
+        RgbTransferRequest, RgbTransferResponse, RgbTransferStatusResponse, SealCloseMethod,
+        SecretString, SignPsbtRequest, WalletData, WatcherRequest, WatcherResponse,
     },
     web::{
         bitcoin::{
@@ -167,6 +167,11 @@ async fn import_and_get_consig_from_proxy() {
         seal: issue_seal.to_owned(),
         iface: iface.to_string(),
         meta: None,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let issue_req = serde_wasm_bindgen::to_value(&issue_req).expect("");
@@ -244,6 +249,8 @@ async fn import_and_get_consig_from_proxy() {
             amount: invoice_amount.to_string(),
             seal: receiver_seal,
             params,
+            expiry: None,
+            transports: None,
         };
 
         let invoice_req = serde_wasm_bindgen::to_value(&invoice_req).expect("");
@@ -264,6 +271,9 @@ async fn import_and_get_consig_from_proxy() {
             change_terminal: "/20/1".to_string(),
             fee: PsbtFeeRequest::Value(1000),
             bitcoin_changes: vec![],
+            other_invoices: vec![],
+            coin_selection: CoinSelectionStrategy::default(),
+            close_method: SealCloseMethod::default(),
         };
 
         let full_transfer_req = serde_wasm_bindgen::to_value(&full_transfer_req).expect("");
@@ -383,6 +393,11 @@ async fn create_uda_with_medias() {
         seal: issue_seal.to_owned(),
         iface: iface.to_string(),
         meta: Some(media_req),
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let issue_req = serde_wasm_bindgen::to_value(&issue_req).expect("");
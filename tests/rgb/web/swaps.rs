@@ -11,13 +11,14 @@ use bitmask_core::{
     debug, info,
     rgb::{prefetch::prefetch_resolver_txs, resolvers::ExplorerResolver},
     structs::{
-        AssetType, BatchRgbTransferResponse, ContractResponse, ContractsResponse,
-        DecryptedWalletData, FullRgbTransferRequest, FundVaultDetails, ImportRequest,
-        InvoiceRequest, InvoiceResponse, IssueRequest, IssueResponse, NextAddressResponse,
-        NextUtxoResponse, PsbtFeeRequest, PublishedPsbtResponse, RgbBidRequest, RgbBidResponse,
-        RgbOfferRequest, RgbOfferResponse, RgbSaveTransferRequest, RgbSwapRequest, RgbSwapResponse,
-        RgbTransferRequest, RgbTransferResponse, RgbTransferStatusResponse, SecretString,
-        SignPsbtRequest, SignedPsbtResponse, WalletData, WatcherRequest, WatcherResponse,
+        AssetType, BatchRgbTransferResponse, CoinSelectionStrategy, ContractResponse,
+        ContractsResponse, DecryptedWalletData, FullRgbTransferRequest, FundVaultDetails,
+        ImportRequest, InvoiceRequest, InvoiceResponse, IssueRequest, IssueResponse,
+        NextAddressResponse, NextUtxoResponse, PsbtFeeRequest, PublishedPsbtResponse,
+        RgbBidRequest, RgbBidResponse, RgbOfferRequest, RgbOfferResponse, RgbSaveTransferRequest,
+        RgbSwapRequest, RgbSwapResponse, RgbTransferRequest, RgbTransferResponse,
+        RgbTransferStatusResponse, SealCloseMethod, SecretString, SignPsbtRequest,
+        SignedPsbtResponse, WalletData, WatcherRequest, WatcherResponse,
     },
     web::{
         bitcoin::{
@@ -189,6 +190,11 @@ async fn create_transfer_swap_flow() {
         seal: issue_seal.to_owned(),
         iface: iface.to_string(),
         meta: None,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let issue_req = serde_wasm_bindgen::to_value(&issue_req).expect("");
@@ -279,6 +285,8 @@ async fn create_transfer_swap_flow() {
             bitcoin_changes: vec![],
             expire_at: Some(expire_at),
             presig: false,
+            coin_selection: CoinSelectionStrategy::default(),
+            close_method: SealCloseMethod::default(),
         };
         let sender_swap_req = serde_wasm_bindgen::to_value(&sender_swap_req).expect("");
 
@@ -294,6 +302,7 @@ async fn create_transfer_swap_flow() {
             descriptor: SecretString(receiver_btc_desc),
             change_terminal: "/1/0".to_string(),
             fee: PsbtFeeRequest::Value(1000),
+            close_method: SealCloseMethod::default(),
         };
         let receiver_swap_req = serde_wasm_bindgen::to_value(&receiver_swap_req).expect("");
 
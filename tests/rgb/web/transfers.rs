@@ -14,12 +14,13 @@ use bitmask_core::{
     debug, info,
     rgb::{prefetch::prefetch_resolver_txs, resolvers::ExplorerResolver},
     structs::{
-        AssetType, BatchRgbTransferResponse, ContractResponse, ContractsResponse,
-        DecryptedWalletData, FullRgbTransferRequest, FundVaultDetails, ImportRequest,
-        InvoiceRequest, InvoiceResponse, IssueRequest, IssueResponse, NextAddressResponse,
-        NextUtxoResponse, PsbtFeeRequest, PublishedPsbtResponse, RgbSaveTransferRequest,
-        RgbTransferRequest, RgbTransferResponse, RgbTransferStatusResponse, SecretString,
-        SignPsbtRequest, WalletData, WatcherRequest, WatcherResponse,
+        AssetType, BatchRgbTransferResponse, CoinSelectionStrategy, ContractResponse,
+        ContractsResponse, DecryptedWalletData, FullRgbTransferRequest, FundVaultDetails,
+        ImportRequest, InvoiceRequest, InvoiceResponse, IssueRequest, IssueResponse,
+        NextAddressResponse, NextUtxoResponse, PsbtFeeRequest, PublishedPsbtResponse,
+        RgbSaveTransferRequest, RgbTransferRequest, RgbTransferResponse, RgbTransferStatusResponse,
+        SealCloseMethod, SecretString, SignPsbtRequest, WalletData, WatcherRequest,
+        WatcherResponse,
     },
     web::{
         bitcoin::{
@@ -164,6 +165,11 @@ async fn create_transfer_with_fee_value() {
         seal: issue_seal.to_owned(),
         iface: iface.to_string(),
         meta: None,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let issue_req = serde_wasm_bindgen::to_value(&issue_req).expect("");
@@ -247,6 +253,8 @@ async fn create_transfer_with_fee_value() {
             amount: invoice_amount.to_string(),
             seal: receiver_seal,
             params,
+            expiry: None,
+            transports: None,
         };
 
         let invoice_req = serde_wasm_bindgen::to_value(&invoice_req).expect("");
@@ -267,6 +275,9 @@ async fn create_transfer_with_fee_value() {
             change_terminal: "/20/1".to_string(),
             fee: PsbtFeeRequest::Value(1000),
             bitcoin_changes: vec![],
+            other_invoices: vec![],
+            coin_selection: CoinSelectionStrategy::default(),
+            close_method: SealCloseMethod::default(),
         };
 
         let full_transfer_req = serde_wasm_bindgen::to_value(&full_transfer_req).expect("");
@@ -469,6 +480,11 @@ async fn create_transfer_with_fee_rate() {
         seal: issue_seal.to_owned(),
         iface: iface.to_string(),
         meta: None,
+        inflation: None,
+        allocations: vec![],
+        terms: None,
+        attachment: None,
+        collection: vec![],
     };
 
     let issue_req = serde_wasm_bindgen::to_value(&issue_req).expect("");
@@ -549,6 +565,8 @@ async fn create_transfer_with_fee_rate() {
             amount: invoice_amount.to_string(),
             seal: receiver_seal,
             params,
+            expiry: None,
+            transports: None,
         };
 
         let invoice_req = serde_wasm_bindgen::to_value(&invoice_req).expect("");
@@ -569,6 +587,9 @@ async fn create_transfer_with_fee_rate() {
             change_terminal: "/20/1".to_string(),
             fee: PsbtFeeRequest::FeeRate(1.1),
             bitcoin_changes: vec![],
+            other_invoices: vec![],
+            coin_selection: CoinSelectionStrategy::default(),
+            close_method: SealCloseMethod::default(),
         };
 
         let full_transfer_req = serde_wasm_bindgen::to_value(&full_transfer_req).expect("");